@@ -136,7 +136,8 @@ def parse_args():
     )
     parser.add_argument(
         "--rotation_info",
-        type=list,
+        nargs='+',
+        type=int,
         default=None,
         help="Allow EasyOCR to rotate each text box and return the one with the best confident score. Eligible values are 90, 180 and 270. For example, try [90, 180 ,270] for all possible text orientations.",
     )
@@ -0,0 +1,22 @@
+use std::path::Path;
+
+const CJK_FONT: &str = "assets/fonts/NotoSansSC-Regular.otf";
+
+/// Embeds the bundled CJK font when the `bundled-cjk-font` feature is on and
+/// the font has been fetched (CI does so before building; see
+/// assets/fonts/README.md).  Without it the build still succeeds, relying on
+/// system fonts only.
+fn main() {
+    println!("cargo:rustc-check-cfg=cfg(bundled_cjk_font)");
+    println!("cargo:rerun-if-changed={CJK_FONT}");
+    if std::env::var_os("CARGO_FEATURE_BUNDLED_CJK_FONT").is_none() {
+        return;
+    }
+    if Path::new(CJK_FONT).is_file() {
+        println!("cargo:rustc-cfg=bundled_cjk_font");
+    } else {
+        println!(
+            "cargo:warning={CJK_FONT} is missing; CJK text will only render with a system font"
+        );
+    }
+}
@@ -0,0 +1,179 @@
+use crate::ocr::pip_install_command;
+use std::fmt;
+use std::io;
+use std::sync::Arc;
+
+/// Why a run produced no results.
+#[derive(Debug, Clone)]
+pub enum OcrError {
+    /// The extra arguments setting is not valid shell syntax.
+    InvalidExtraArgs(String),
+    /// Preprocessing failed before EasyOCR was started.
+    Preprocess(String),
+    /// No runnable easyocr; `tried` names what was probed.
+    CommandNotFound { tried: String },
+    /// `python` runs, but has no easyocr module installed.
+    ModuleMissing { python: String },
+    /// The process could not be started.  The error is shared so results
+    /// stay cloneable.
+    SpawnFailed { program: String, source: Arc<io::Error> },
+    /// The process started, but waiting for it failed.
+    WaitFailed { program: String, source: Arc<io::Error> },
+    /// EasyOCR ran and failed; `code` is `None` when killed by a signal.
+    NonZeroExit { code: Option<i32>, stderr: String, stdout: String },
+    /// EasyOCR printed output, but none of it could be read as results.
+    ParseFailed { unparsed: Vec<String> },
+    Timeout { secs: u32 },
+    Cancelled,
+}
+
+impl OcrError {
+    /// The first line of the message, for places with room for one.
+    pub fn summary(&self) -> String {
+        self.to_string().lines().next().unwrap_or_default().to_string()
+    }
+
+    /// The well-known failure behind a failed run, if it is one.  Only the
+    /// CLI's own output is inspected.
+    pub fn kind(&self) -> Option<FailureKind> {
+        match self {
+            OcrError::NonZeroExit { stderr, stdout, .. } => {
+                classify_failure(stderr).or_else(|| classify_failure(stdout))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for OcrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OcrError::InvalidExtraArgs(e) => write!(f, "Extra arguments: {e}"),
+            OcrError::Preprocess(e) => write!(f, "Preprocessing failed: {e}"),
+            OcrError::CommandNotFound { tried } => write!(
+                f,
+                "EasyOCR command not found (tried {tried}).\n\nMake sure EasyOCR is installed:\n  pip install easyocr"
+            ),
+            OcrError::ModuleMissing { python } => write!(
+                f,
+                "'{python}' runs, but the easyocr module is not installed for it.\n\nInstall it with:\n  {}",
+                pip_install_command(python)
+            ),
+            OcrError::SpawnFailed { program, source } => write!(
+                f,
+                "Failed to run '{program}': {source}\n\nMake sure EasyOCR is installed:\n  pip install easyocr"
+            ),
+            OcrError::WaitFailed { program, source } => {
+                write!(f, "Failed to wait for '{program}': {source}")
+            }
+            OcrError::NonZeroExit { code, stderr, stdout } => {
+                match code {
+                    Some(code) => write!(f, "EasyOCR exited with error (status {code}):")?,
+                    None => write!(f, "EasyOCR exited with error:")?,
+                }
+                write!(f, "\n{stderr}\n{stdout}")
+            }
+            OcrError::ParseFailed { unparsed } => write!(
+                f,
+                "EasyOCR printed {} line(s), but none could be read as results.",
+                unparsed.len()
+            ),
+            OcrError::Timeout { secs } => write!(
+                f,
+                "EasyOCR timed out after {secs} seconds.\n\nIncrease the timeout in Settings if the first run is still downloading models."
+            ),
+            OcrError::Cancelled => write!(f, "Cancelled"),
+        }
+    }
+}
+
+impl std::error::Error for OcrError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            OcrError::SpawnFailed { source, .. } | OcrError::WaitFailed { source, .. } => {
+                Some(source.as_ref())
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Well-known EasyOCR failures that have an actionable explanation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FailureKind {
+    GpuOutOfMemory,
+    GpuUnavailable,
+    ModelDownload,
+    UnsupportedLanguage,
+    MissingPythonModule,
+}
+
+/// Recognises common failures in the raw output of a failed run.
+fn classify_failure(error: &str) -> Option<FailureKind> {
+    let e = error.to_lowercase();
+    let any = |needles: &[&str]| needles.iter().any(|n| e.contains(n));
+    if any(&["cuda out of memory", "outofmemoryerror", "cudnn_status_alloc_failed"]) {
+        Some(FailureKind::GpuOutOfMemory)
+    } else if any(&[
+        "torch not compiled with cuda",
+        "found no nvidia driver",
+        "no cuda gpus are available",
+    ]) {
+        Some(FailureKind::GpuUnavailable)
+    } else if any(&[
+        "urlopen error",
+        "retrieval incomplete",
+        "temporary failure in name resolution",
+        "certificate verify failed",
+        "md5 hash mismatch",
+    ]) || (e.contains("no such file") && (e.contains(".pth") || e.contains("model")))
+    {
+        Some(FailureKind::ModelDownload)
+    } else if any(&["is not supported", "invalid language", "is only compatible with english"]) {
+        Some(FailureKind::UnsupportedLanguage)
+    } else if e.contains("no module named") {
+        Some(FailureKind::MissingPythonModule)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{classify_failure, FailureKind, OcrError};
+
+    #[test]
+    fn classify_failure_recognises_common_errors() {
+        assert_eq!(
+            classify_failure("RuntimeError: CUDA out of memory. Tried to allocate 20.00 MiB"),
+            Some(FailureKind::GpuOutOfMemory)
+        );
+        assert_eq!(
+            classify_failure("urllib.error.URLError: <urlopen error [Errno 110] Connection timed out>"),
+            Some(FailureKind::ModelDownload)
+        );
+        assert_eq!(
+            classify_failure("ValueError: ({'english'}, 'is not supported')"),
+            Some(FailureKind::UnsupportedLanguage)
+        );
+        assert_eq!(
+            classify_failure("ModuleNotFoundError: No module named 'torch'"),
+            Some(FailureKind::MissingPythonModule)
+        );
+        assert_eq!(classify_failure("No such file or directory: 'scan.png'"), None);
+    }
+
+    #[test]
+    fn kind_only_looks_at_failed_runs() {
+        let exit = OcrError::NonZeroExit {
+            code: Some(1),
+            stderr: "RuntimeError: CUDA out of memory.".into(),
+            stdout: String::new(),
+        };
+        assert_eq!(exit.kind(), Some(FailureKind::GpuOutOfMemory));
+        assert!(exit.to_string().starts_with("EasyOCR exited with error (status 1):"));
+        // The setting's text mentions a module, but it isn't EasyOCR's output.
+        let args = OcrError::InvalidExtraArgs("no module named x".into());
+        assert_eq!(args.kind(), None);
+    }
+}
@@ -0,0 +1,274 @@
+use serde::Serialize;
+
+/// Language codes accepted by EasyOCR's default recognition models, with an
+/// English display name.  Mirrors `all_lang_list` in `easyocr/config.py`.
+pub const LANGUAGES: &[(&str, &str)] = &[
+    // Latin script
+    ("af", "Afrikaans"),
+    ("az", "Azerbaijani"),
+    ("bs", "Bosnian"),
+    ("cs", "Czech"),
+    ("cy", "Welsh"),
+    ("da", "Danish"),
+    ("de", "German"),
+    ("en", "English"),
+    ("es", "Spanish"),
+    ("et", "Estonian"),
+    ("fr", "French"),
+    ("ga", "Irish"),
+    ("hr", "Croatian"),
+    ("hu", "Hungarian"),
+    ("id", "Indonesian"),
+    ("is", "Icelandic"),
+    ("it", "Italian"),
+    ("ku", "Kurdish"),
+    ("la", "Latin"),
+    ("lt", "Lithuanian"),
+    ("lv", "Latvian"),
+    ("mi", "Maori"),
+    ("ms", "Malay"),
+    ("mt", "Maltese"),
+    ("nl", "Dutch"),
+    ("no", "Norwegian"),
+    ("oc", "Occitan"),
+    ("pi", "Pali"),
+    ("pl", "Polish"),
+    ("pt", "Portuguese"),
+    ("ro", "Romanian"),
+    ("rs_latin", "Serbian (Latin)"),
+    ("sk", "Slovak"),
+    ("sl", "Slovenian"),
+    ("sq", "Albanian"),
+    ("sv", "Swedish"),
+    ("sw", "Swahili"),
+    ("tl", "Tagalog"),
+    ("tr", "Turkish"),
+    ("uz", "Uzbek"),
+    ("vi", "Vietnamese"),
+    // Arabic script
+    ("ar", "Arabic"),
+    ("fa", "Persian (Farsi)"),
+    ("ug", "Uyghur"),
+    ("ur", "Urdu"),
+    // Cyrillic script
+    ("ru", "Russian"),
+    ("rs_cyrillic", "Serbian (Cyrillic)"),
+    ("be", "Belarusian"),
+    ("bg", "Bulgarian"),
+    ("uk", "Ukrainian"),
+    ("mn", "Mongolian"),
+    ("abq", "Abaza"),
+    ("ady", "Adyghe"),
+    ("kbd", "Kabardian"),
+    ("ava", "Avar"),
+    ("dar", "Dargwa"),
+    ("inh", "Ingush"),
+    ("che", "Chechen"),
+    ("lbe", "Lak"),
+    ("lez", "Lezghian"),
+    ("tab", "Tabassaran"),
+    ("tjk", "Tajik"),
+    // Devanagari script
+    ("hi", "Hindi"),
+    ("mr", "Marathi"),
+    ("ne", "Nepali"),
+    ("bh", "Bihari"),
+    ("mai", "Maithili"),
+    ("ang", "Angika"),
+    ("bho", "Bhojpuri"),
+    ("mah", "Magahi"),
+    ("sck", "Nagpuri"),
+    ("new", "Newari"),
+    ("gom", "Goan Konkani"),
+    ("sa", "Sanskrit"),
+    ("bgc", "Haryanvi"),
+    // Bengali script
+    ("bn", "Bengali"),
+    ("as", "Assamese"),
+    ("mni", "Manipuri"),
+    // Languages with a dedicated model
+    ("th", "Thai"),
+    ("ch_sim", "Chinese (Simplified)"),
+    ("ch_tra", "Chinese (Traditional)"),
+    ("ja", "Japanese"),
+    ("ko", "Korean"),
+    ("ta", "Tamil"),
+    ("te", "Telugu"),
+    ("kn", "Kannada"),
+];
+
+/// Languages that have a model of their own and can only be paired with
+/// English, in the order EasyOCR checks them.
+const STANDALONE: &[&str] = &["th", "ch_tra", "ch_sim", "ja", "ko", "ta", "te", "kn"];
+
+/// Script groups whose members share a model; any of them can be combined
+/// with each other and with English.
+const SCRIPT_GROUPS: &[&[&str]] = &[
+    &["bn", "as", "mni"],
+    &["ar", "fa", "ug", "ur"],
+    &[
+        "hi", "mr", "ne", "bh", "mai", "ang", "bho", "mah", "sck", "new", "gom", "sa", "bgc",
+    ],
+    &[
+        "ru", "rs_cyrillic", "be", "bg", "uk", "mn", "abq", "ady", "kbd", "ava", "dar", "inh",
+        "che", "lbe", "lez", "tab", "tjk",
+    ],
+];
+
+/// Why a language list would be rejected by EasyOCR.
+#[derive(Debug, PartialEq)]
+pub enum LanguageProblem {
+    /// Codes EasyOCR does not know.
+    Unknown(Vec<String>),
+    /// `code` cannot be loaded together with `anchor`, which picks the model.
+    Incompatible { code: String, anchor: String },
+}
+
+/// Splits a comma/space separated language list, dropping empty entries.
+/// Unlike `ocr::parse_languages`, an empty input stays empty.
+pub fn split_codes(raw: &str) -> Vec<String> {
+    raw.split([',', '，', ' ', ';', '；'])
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_owned)
+        .collect()
+}
+
+/// Human-readable name for a language code, if it is a known one.
+pub fn name_of(code: &str) -> Option<&'static str> {
+    LANGUAGES.iter().find(|(c, _)| *c == code).map(|(_, n)| *n)
+}
+
+/// Checks a language list the same way EasyOCR picks its recognition model,
+/// so problems can be shown before the run instead of as a Python traceback.
+pub fn validate(codes: &[String]) -> Result<(), LanguageProblem> {
+    let unknown: Vec<String> = codes.iter().filter(|c| name_of(c).is_none()).cloned().collect();
+    if !unknown.is_empty() {
+        return Err(LanguageProblem::Unknown(unknown));
+    }
+
+    let has = |code: &str| codes.iter().any(|c| c == code);
+    let standalone = STANDALONE.iter().find(|c| has(c));
+    let (anchor, allowed): (&str, &[&str]) = if let Some(code) = standalone {
+        (code, std::slice::from_ref(code))
+    } else if let Some(group) = SCRIPT_GROUPS.iter().find(|g| g.iter().any(|c| has(c))) {
+        let anchor = codes.iter().find(|c| group.contains(&c.as_str())).unwrap();
+        (anchor, group)
+    } else {
+        // Everything left is Latin script, which all shares one model.
+        return Ok(());
+    };
+
+    match codes.iter().find(|c| *c != "en" && !allowed.contains(&c.as_str())) {
+        Some(code) => Err(LanguageProblem::Incompatible {
+            code: code.clone(),
+            anchor: anchor.to_string(),
+        }),
+        None => Ok(()),
+    }
+}
+
+/// The writing system a recognised line is mostly in, guessed from its
+/// characters alone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Script {
+    Latin,
+    /// Han, kana and hangul
+    Cjk,
+    /// Numbers with no letters, e.g. prices and dates
+    Digits,
+    /// Any other alphabet: Cyrillic, Arabic, Thai and so on
+    Other,
+}
+
+impl Script {
+    /// Classifies `text` by counting the letters of each script; `None`
+    /// when it holds nothing but spaces and punctuation.  A CJK character
+    /// stands for about a word, so each counts twice against Latin letters.
+    pub fn of(text: &str) -> Option<Script> {
+        let (mut latin, mut cjk, mut other, mut digits) = (0, 0, 0, 0);
+        for c in text.chars() {
+            if is_cjk(c) {
+                cjk += 2;
+            } else if c.is_ascii_digit() {
+                digits += 1;
+            } else if c.is_alphabetic() {
+                if matches!(c, 'A'..='\u{024f}' | '\u{1e00}'..='\u{1eff}') {
+                    latin += 1;
+                } else {
+                    other += 1;
+                }
+            }
+        }
+        if latin + cjk + other == 0 {
+            return (digits > 0).then_some(Script::Digits);
+        }
+        Some(if cjk >= latin && cjk >= other {
+            Script::Cjk
+        } else if latin >= other {
+            Script::Latin
+        } else {
+            Script::Other
+        })
+    }
+}
+
+/// Han, kana and hangul characters, which are read one at a time rather
+/// than as words.
+pub fn is_cjk(c: char) -> bool {
+    matches!(c,
+        '\u{3040}'..='\u{30ff}'     // hiragana, katakana
+        | '\u{3400}'..='\u{4dbf}'   // CJK extension A
+        | '\u{4e00}'..='\u{9fff}'   // CJK unified ideographs
+        | '\u{ac00}'..='\u{d7af}'   // hangul syllables
+        | '\u{f900}'..='\u{faff}')  // CJK compatibility ideographs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{validate, LanguageProblem, Script};
+
+    fn codes(list: &[&str]) -> Vec<String> {
+        list.iter().map(|c| c.to_string()).collect()
+    }
+
+    #[test]
+    fn accepts_supported_combinations() {
+        assert_eq!(validate(&codes(&["ch_sim", "en"])), Ok(()));
+        assert_eq!(validate(&codes(&["fr", "de", "en"])), Ok(()));
+        assert_eq!(validate(&codes(&["ru", "uk", "en"])), Ok(()));
+    }
+
+    #[test]
+    fn flags_unknown_codes() {
+        assert_eq!(
+            validate(&codes(&["english", "en", "zh"])),
+            Err(LanguageProblem::Unknown(codes(&["english", "zh"])))
+        );
+    }
+
+    #[test]
+    fn flags_codes_that_need_another_model() {
+        assert_eq!(
+            validate(&codes(&["en", "ja", "ko"])),
+            Err(LanguageProblem::Incompatible { code: "ko".into(), anchor: "ja".into() })
+        );
+        assert_eq!(
+            validate(&codes(&["ru", "fr"])),
+            Err(LanguageProblem::Incompatible { code: "fr".into(), anchor: "ru".into() })
+        );
+    }
+
+    #[test]
+    fn guesses_the_script_of_a_line() {
+        assert_eq!(Script::of("Invoice total"), Some(Script::Latin));
+        assert_eq!(Script::of("Crème brûlée"), Some(Script::Latin));
+        assert_eq!(Script::of("使用 GPU 加速"), Some(Script::Cjk));
+        assert_eq!(Script::of("カタカナ"), Some(Script::Cjk));
+        assert_eq!(Script::of("Привет, мир"), Some(Script::Other));
+        assert_eq!(Script::of("2024-05-01 ¥1,280"), Some(Script::Digits));
+        assert_eq!(Script::of(" -- "), None);
+        assert_eq!(Script::of(""), None);
+    }
+}
@@ -0,0 +1,25 @@
+//! Runs the EasyOCR command-line tool and parses what it prints, without
+//! any GUI dependencies.
+//!
+//! ```no_run
+//! use easyocr_core::{OcrEngine, Settings};
+//!
+//! let engine = OcrEngine::new(Settings { languages: "en".into(), ..Settings::default() });
+//! for line in engine.run("scan.png".as_ref())? {
+//!     println!("{:.0}%  {}", line.confidence * 100.0, line.text);
+//! }
+//! # Ok::<(), easyocr_core::OcrError>(())
+//! ```
+//!
+//! `Settings::load` reads the same `settings.json` the GUI writes, so a
+//! script can reuse whatever was tuned there.
+
+pub mod error;
+pub mod lang;
+pub mod ocr;
+pub mod preprocess;
+pub mod settings;
+
+pub use error::{FailureKind, OcrError};
+pub use ocr::{OcrEngine, OcrLine, OcrResult};
+pub use settings::Settings;
@@ -0,0 +1,2506 @@
+use crate::error::OcrError;
+use crate::lang::Script;
+use crate::preprocess::Preprocess;
+use crate::settings::{Settings, MAX_BATCH_CONCURRENCY};
+use serde::{Deserialize, Serialize};
+use std::ffi::OsString;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc, Mutex, PoisonError, TryLockError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How often the worker checks whether the child process has exited or the
+/// run has been cancelled.
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Result returned from the OCR worker thread.
+#[derive(Debug)]
+pub struct OcrResult {
+    pub lines: Vec<OcrLine>,
+    /// Non-empty output lines that could not be parsed as results, kept so
+    /// the UI can show them instead of silently dropping them.
+    pub unparsed: Vec<String>,
+    /// What EasyOCR wrote to stderr when a successful run found nothing,
+    /// as a clue to why; `None` otherwise.
+    pub stderr_note: Option<String>,
+    /// Everything EasyOCR printed on stdout, as a sample for diagnosing
+    /// parse problems; empty when the run failed.
+    pub raw: String,
+    pub error: Option<OcrError>,
+}
+
+/// What the parsers recovered from the CLI's stdout.
+struct ParsedOutput {
+    lines: Vec<OcrLine>,
+    unparsed: Vec<String>,
+}
+
+/// Handle to an OCR run executing on a background thread, which sends one
+/// `OcrResult`.  Status lines printed by EasyOCR while it runs (model downloads) arrive on
+/// a separate progress channel, and the worker counts result lines on stdout
+/// as they are printed.
+pub struct OcrJob {
+    rx: mpsc::Receiver<OcrResult>,
+    progress: mpsc::Receiver<String>,
+    lines_seen: Arc<AtomicUsize>,
+    cancel: Arc<AtomicBool>,
+}
+
+impl OcrJob {
+    /// Returns the result once the worker has sent it, without blocking.
+    pub fn try_recv(&self) -> Option<OcrResult> {
+        self.rx.try_recv().ok()
+    }
+
+    /// Returns the most recent status line reported since the last call, if
+    /// any.  Older lines are dropped — only the latest one is worth showing.
+    pub fn latest_progress(&self) -> Option<String> {
+        self.progress.try_iter().last()
+    }
+
+    /// Result lines EasyOCR has printed so far.
+    pub fn lines_so_far(&self) -> usize {
+        self.lines_seen.load(Ordering::Relaxed)
+    }
+
+    /// Asks the worker to kill the easyocr process.  The worker notices
+    /// within one poll interval; its result is discarded.
+    pub fn cancel(&self) {
+        self.cancel.store(true, Ordering::Relaxed);
+    }
+}
+
+/// A single recognised text line with bounding box and confidence.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OcrLine {
+    pub bbox: [[f32; 2]; 4],
+    pub text: String,
+    pub confidence: f32,
+}
+
+impl OcrLine {
+    /// `false` for text-only (`--detail 0`) results, which carry neither a
+    /// box nor a confidence.
+    pub fn has_position(&self) -> bool {
+        self.bbox != [[0.0; 2]; 4]
+    }
+
+    /// A box with no text, as produced by a detect-only run.
+    pub fn is_detection_only(&self) -> bool {
+        self.has_position() && self.text.is_empty()
+    }
+
+    /// Whether the line carries a recognition confidence.
+    pub fn has_confidence(&self) -> bool {
+        self.has_position() && !self.is_detection_only()
+    }
+
+    /// Whether the line passes a display threshold of `min` (0–1).  Text-only
+    /// lines and bare boxes have no confidence to compare, so they always pass.
+    pub fn meets_confidence(&self, min: f32) -> bool {
+        !self.has_confidence() || self.confidence >= min
+    }
+
+    /// The script the text is mostly written in; `None` for bare boxes and
+    /// lines of only punctuation.
+    pub fn script(&self) -> Option<Script> {
+        Script::of(&self.text)
+    }
+}
+
+/// Outcome of looking for a runnable easyocr command.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Resolution {
+    /// `program [prefix_args] ...` runs the EasyOCR CLI.  When the `easyocr`
+    /// script is found directly, `prefix_args` is empty; when only the Python
+    /// module is available it is e.g. `("python3", ["-m", "easyocr.cli"])`.
+    Found { program: String, prefix_args: Vec<String> },
+    /// `python` is a working interpreter, but easyocr isn't installed for it.
+    ModuleMissing { python: String },
+    /// Nothing that looks like EasyOCR or Python could be started.
+    NotFound,
+}
+
+impl Resolution {
+    pub fn found(self) -> Option<(String, Vec<String>)> {
+        match self {
+            Resolution::Found { program, prefix_args } => Some((program, prefix_args)),
+            _ => None,
+        }
+    }
+}
+
+/// Resolves the effective easyocr command.
+///
+/// If `configured_exe` is non-empty, only that path is attempted.
+pub fn resolve_easyocr_cmd(configured_exe: &str) -> Resolution {
+    let found = |program: &str, prefix_args: &[&str]| Resolution::Found {
+        program: program.to_string(),
+        prefix_args: prefix_args.iter().map(|a| a.to_string()).collect(),
+    };
+    let module_args = ["-m", "easyocr.cli"];
+
+    if !configured_exe.is_empty() {
+        // User provided a custom path.
+        // Support both the easyocr script and python executable.
+        if probe_cmd(configured_exe, &[]) {
+            return found(configured_exe, &[]);
+        }
+        if probe_cmd(configured_exe, &module_args) {
+            return found(configured_exe, &module_args);
+        }
+        if is_python(configured_exe) {
+            return Resolution::ModuleMissing { python: configured_exe.to_string() };
+        }
+        return Resolution::NotFound;
+    }
+
+    // 1. Try the `easyocr` script on PATH.
+    if probe_cmd("easyocr", &[]) {
+        return found("easyocr", &[]);
+    }
+
+    // 2. Try via Python module (handles pip installs where the script
+    //    directory is not in the GUI's PATH).
+    let pythons = ["python3", "python"];
+    for python in pythons {
+        if probe_cmd(python, &module_args) {
+            return found(python, &module_args);
+        }
+    }
+
+    match pythons.into_iter().find(|p| is_python(p)) {
+        Some(python) => Resolution::ModuleMissing { python: python.to_string() },
+        None => Resolution::NotFound,
+    }
+}
+
+/// Returns `true` if running `program [extra_args] --help` succeeds.
+fn probe_cmd(program: &str, extra_args: &[&str]) -> bool {
+    std::process::Command::new(program)
+        .args(extra_args)
+        .arg("--help")
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .status()
+        .map(|s| s.success())
+        .unwrap_or(false)
+}
+
+/// The pip command that installs easyocr for a specific interpreter.
+pub fn pip_install_command(python: &str) -> String {
+    format!("{} -m pip install easyocr", shell_quote(python))
+}
+
+/// Returns `true` if `program` is a Python interpreter that starts.
+fn is_python(program: &str) -> bool {
+    std::process::Command::new(program)
+        .args(["-c", "import sys"])
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .status()
+        .map(|s| s.success())
+        .unwrap_or(false)
+}
+
+/// How long a successful resolution is trusted before probing again.
+const RESOLUTION_TTL: Duration = Duration::from_secs(15 * 60);
+
+/// The last successful resolution, so runs don't pay for a Python start-up
+/// just to find the executable again.
+struct CachedResolution {
+    configured_exe: String,
+    resolution: Resolution,
+    at: Instant,
+}
+
+impl CachedResolution {
+    fn get(&self, configured_exe: &str, now: Instant) -> Option<&Resolution> {
+        let fresh = now.saturating_duration_since(self.at) < RESOLUTION_TTL;
+        (fresh && self.configured_exe == configured_exe).then_some(&self.resolution)
+    }
+}
+
+static RESOLUTION_CACHE: Mutex<Option<CachedResolution>> = Mutex::new(None);
+
+/// Like `resolve_easyocr_cmd`, but reuses a recent successful result for the
+/// same configured executable.  Failures are never cached, so installing
+/// EasyOCR is picked up on the next attempt.
+pub fn resolve_easyocr_cmd_cached(configured_exe: &str) -> Resolution {
+    let now = Instant::now();
+    if let Some(hit) = RESOLUTION_CACHE
+        .lock()
+        .ok()
+        .and_then(|c| c.as_ref()?.get(configured_exe, now).cloned())
+    {
+        return hit;
+    }
+    let resolution = resolve_easyocr_cmd(configured_exe);
+    if matches!(resolution, Resolution::Found { .. }) {
+        if let Ok(mut cache) = RESOLUTION_CACHE.lock() {
+            *cache = Some(CachedResolution {
+                configured_exe: configured_exe.to_string(),
+                resolution: resolution.clone(),
+                at: now,
+            });
+        }
+    }
+    resolution
+}
+
+/// The executable last found for `configured_exe`, however long ago,
+/// without probing.
+fn last_resolution(configured_exe: &str) -> Option<Resolution> {
+    let cache = RESOLUTION_CACHE.lock().ok()?;
+    let cached = cache.as_ref()?;
+    (cached.configured_exe == configured_exe).then(|| cached.resolution.clone())
+}
+
+/// Drops the cached resolution so the next lookup probes again.
+pub fn forget_resolution() {
+    if let Ok(mut cache) = RESOLUTION_CACHE.lock() {
+        *cache = None;
+    }
+}
+
+/// Resolves the configured easyocr executable, probing afresh when `refresh`
+/// is set.  The check is performed synchronously but is intended to be
+/// called from a background thread so the UI is never blocked.
+pub fn check_easyocr_available(exe: &str, refresh: bool) -> Resolution {
+    if refresh {
+        forget_resolution();
+    }
+    resolve_easyocr_cmd_cached(exe)
+}
+
+/// Spawns a background thread that checks easyocr availability and sends the
+/// result through the returned receiver.
+pub fn check_easyocr_async(exe: &str, refresh: bool) -> mpsc::Receiver<Resolution> {
+    let (tx, rx) = mpsc::channel();
+    let exe = exe.to_string();
+    thread::spawn(move || {
+        let _ = tx.send(check_easyocr_available(&exe, refresh));
+    });
+    rx
+}
+
+/// Asks the interpreter easyocr runs under whether PyTorch can see a CUDA
+/// device.  Sends `None` when that can't be told, e.g. easyocr or torch is
+/// missing.
+pub fn check_cuda_async(exe: &str) -> mpsc::Receiver<Option<bool>> {
+    let (tx, rx) = mpsc::channel();
+    let exe = exe.to_string();
+    thread::spawn(move || {
+        let available = resolve_easyocr_cmd_cached(&exe).found().and_then(|(program, prefix_args)| {
+            let output = Command::new(python_for(program, &prefix_args))
+                .args(["-c", "import torch; print(torch.cuda.is_available())"])
+                .output()
+                .ok()?;
+            parse_cuda_probe(&String::from_utf8_lossy(&output.stdout))
+        });
+        let _ = tx.send(available);
+    });
+    rx
+}
+
+/// The answer printed by the CUDA probe, which is its last line.
+fn parse_cuda_probe(stdout: &str) -> Option<bool> {
+    match stdout.lines().map(str::trim).rfind(|l| !l.is_empty())? {
+        "True" => Some(true),
+        "False" => Some(false),
+        _ => None,
+    }
+}
+
+/// Versions of what EasyOCR runs on, for the About section and bug reports.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Versions {
+    pub python: Option<String>,
+    pub easyocr: Option<String>,
+}
+
+/// Prints tagged lines, so warnings printed on import can't be mistaken for
+/// a version.  Python's line comes first, so it survives a failed import.
+const VERSION_PROBE: &str = concat!(
+    "import sys; print('python', sys.version.split()[0]); ",
+    "import easyocr; print('easyocr', easyocr.__version__)"
+);
+
+/// Asks the interpreter easyocr runs under (or the one found without it)
+/// for its own and easyocr's version.
+pub fn check_versions_async(exe: &str) -> mpsc::Receiver<Versions> {
+    let (tx, rx) = mpsc::channel();
+    let exe = exe.to_string();
+    thread::spawn(move || {
+        let python = match resolve_easyocr_cmd_cached(&exe) {
+            Resolution::Found { program, prefix_args } => Some(python_for(program, &prefix_args)),
+            Resolution::ModuleMissing { python } => Some(python),
+            Resolution::NotFound => None,
+        };
+        let versions = python
+            .and_then(|python| Command::new(python).args(["-c", VERSION_PROBE]).output().ok())
+            .map(|output| parse_version_probe(&String::from_utf8_lossy(&output.stdout)))
+            .unwrap_or_default();
+        let _ = tx.send(versions);
+    });
+    rx
+}
+
+fn parse_version_probe(stdout: &str) -> Versions {
+    let mut versions = Versions::default();
+    for line in stdout.lines() {
+        if let Some(v) = line.strip_prefix("python ") {
+            versions.python = Some(v.trim().to_string());
+        } else if let Some(v) = line.strip_prefix("easyocr ") {
+            versions.easyocr = Some(v.trim().to_string());
+        }
+    }
+    versions
+}
+
+/// Blocking OCR for scripts and other front ends: runs one image at a time
+/// on the calling thread with a fixed set of settings.
+pub struct OcrEngine {
+    settings: Settings,
+}
+
+impl OcrEngine {
+    pub fn new(settings: Settings) -> Self {
+        Self { settings }
+    }
+
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Recognises the text in `image`.  Output lines that could not be
+    /// parsed are dropped unless nothing else was recognised, which is
+    /// reported as `OcrError::ParseFailed`.
+    pub fn run(&self, image: &Path) -> Result<Vec<OcrLine>, OcrError> {
+        // Nobody listens for progress on this path.
+        let (status, _) = mpsc::channel();
+        let sink = ProgressSink {
+            status,
+            lines_seen: Arc::new(AtomicUsize::new(0)),
+        };
+        let result = run_ocr_sync(image, &self.settings, &AtomicBool::new(false), &sink);
+        match result.error {
+            Some(err) => Err(err),
+            None if result.lines.is_empty() && !result.unparsed.is_empty() => {
+                Err(OcrError::ParseFailed { unparsed: result.unparsed })
+            }
+            None => Ok(result.lines),
+        }
+    }
+}
+
+/// Spawns a background thread that calls the `easyocr` CLI and sends the
+/// result back through the returned job handle.
+pub fn run_ocr_async(image_path: &Path, settings: &Settings) -> OcrJob {
+    let (tx, rx) = mpsc::channel();
+    let (status, progress) = mpsc::channel();
+    let lines_seen = Arc::new(AtomicUsize::new(0));
+    let cancel = Arc::new(AtomicBool::new(false));
+    let image_path = image_path.to_owned();
+    let settings = settings.clone();
+    let worker_cancel = Arc::clone(&cancel);
+    let sink = ProgressSink {
+        status,
+        lines_seen: Arc::clone(&lines_seen),
+    };
+
+    thread::spawn(move || {
+        let result = run_ocr_sync(&image_path, &settings, &worker_cancel, &sink);
+        let _ = tx.send(result);
+    });
+
+    OcrJob {
+        rx,
+        progress,
+        lines_seen,
+        cancel,
+    }
+}
+
+/// The worker's end of an `OcrJob`'s progress reporting.
+#[derive(Clone)]
+struct ProgressSink {
+    status: mpsc::Sender<String>,
+    lines_seen: Arc<AtomicUsize>,
+}
+
+/// The arguments passed to EasyOCR for `image_path`, after the executable and
+/// any `-m easyocr.cli` prefix.  Shared by the runner and "Copy command" so
+/// the two can never disagree.
+pub fn build_ocr_args(settings: &Settings, image_path: &Path) -> Vec<OsString> {
+    let py_bool = |b: bool| if b { "True" } else { "False" };
+
+    // Languages (-l can take multiple values in the EasyOCR CLI).
+    let mut args: Vec<OsString> = vec!["-l".into()];
+    args.extend(parse_languages(&settings.languages).into_iter().map(OsString::from));
+
+    // Image file.
+    push_flag(&mut args, "-f", image_path);
+
+    // GPU.
+    push_flag(&mut args, "--gpu", py_bool(settings.gpu));
+
+    // Workers (0 = one per CPU core; a literal 0 means different things to
+    // different EasyOCR versions).
+    push_flag(&mut args, "--workers", effective_workers(settings.workers).to_string());
+
+    // Decoder.
+    push_flag(&mut args, "--decoder", settings.decoder.as_str());
+
+    // Beam width.
+    push_flag(&mut args, "--beamWidth", settings.beam_width.to_string());
+
+    // Batch size.
+    push_flag(&mut args, "--batch_size", settings.batch_size.to_string());
+
+    // Thresholds.
+    push_flag(&mut args, "--text_threshold", format!("{:.4}", settings.text_threshold));
+    push_flag(&mut args, "--low_text", format!("{:.4}", settings.low_text));
+    push_flag(&mut args, "--link_threshold", format!("{:.4}", settings.link_threshold));
+    push_flag(&mut args, "--contrast_ths", format!("{:.4}", settings.contrast_ths));
+    push_flag(&mut args, "--adjust_contrast", format!("{:.4}", settings.adjust_contrast));
+
+    // Min size.
+    push_flag(&mut args, "--min_size", settings.min_size.to_string());
+
+    // Detector input scaling.
+    push_flag(&mut args, "--canvas_size", settings.canvas_size.to_string());
+    push_flag(&mut args, "--mag_ratio", format!("{:.4}", settings.mag_ratio));
+
+    // Paragraph.
+    push_flag(&mut args, "--paragraph", py_bool(settings.paragraph));
+    push_flag(&mut args, "--x_ths", format!("{:.4}", settings.x_ths));
+    push_flag(&mut args, "--y_ths", format!("{:.4}", settings.y_ths));
+
+    // Quantize.
+    push_flag(&mut args, "--quantize", py_bool(settings.quantize));
+
+    // Quiet: no download progress bars among the results.
+    if settings.quiet {
+        push_flag(&mut args, "--verbose", "False");
+    }
+
+    // Add margin.
+    push_flag(&mut args, "--add_margin", format!("{:.4}", settings.add_margin));
+
+    // Rotation angles (only when at least one valid angle is given).
+    let angles = rotation_angles(settings);
+    if !angles.is_empty() {
+        args.push("--rotation_info".into());
+        args.extend(angles.iter().map(|a| OsString::from(a.to_string())));
+    }
+
+    // Character sets are literal, so commas and spaces are kept.  The `=`
+    // form stops argparse from reading a set like "-+0123" as a flag.
+    for (flag, chars) in [("--allowlist", &settings.allowlist), ("--blocklist", &settings.blocklist)] {
+        if !chars.is_empty() {
+            args.push(format!("{flag}={chars}").into());
+        }
+    }
+
+    // Detail level 1 = boxes and confidences, 0 = text only.
+    push_flag(&mut args, "--detail", if settings.detail { "1" } else { "0" });
+
+    // JSON output avoids hand-parsing Python tuple reprs.  EasyOCR ignores
+    // it at detail 0, so don't ask for it then.
+    if settings.json_output && settings.detail {
+        push_flag(&mut args, "--output_format", "json");
+    }
+
+    // Model storage directory.
+    if !settings.model_storage_directory.is_empty() {
+        push_flag(
+            &mut args,
+            "--model_storage_directory",
+            expand_home_dir(&settings.model_storage_directory),
+        );
+    }
+
+    // User-supplied extras go last.  Nothing deduplicates them against the
+    // flags above, so repeating one is up to argparse (the last value wins
+    // for most options).  Unparseable extras are reported by `run_easyocr`.
+    args.extend(split_args(&settings.extra_args).unwrap_or_default().into_iter().map(OsString::from));
+
+    args
+}
+
+/// The rotation angles to try: the configured ones, plus the sideways turns
+/// that vertical text needs.
+fn rotation_angles(settings: &Settings) -> Vec<i32> {
+    let mut angles = parse_rotation_info(&settings.rotation_info);
+    if settings.vertical_text {
+        // A vertical column read sideways is often recognised better than
+        // the tall box as detected; EasyOCR keeps the most confident try.
+        for angle in [90, 270] {
+            if !angles.contains(&angle) {
+                angles.push(angle);
+            }
+        }
+    }
+    angles
+}
+
+/// Splits `s` into arguments the way a POSIX shell would: whitespace
+/// separates words, single quotes are literal, double quotes allow `\"` and
+/// `\\` escapes, and a backslash outside quotes escapes the next character.
+/// No expansion of any kind is done.
+pub fn split_args(s: &str) -> Result<Vec<String>, String> {
+    let mut args = Vec::new();
+    let mut word: Option<String> = None;
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => args.extend(word.take()),
+            '\'' => {
+                let word = word.get_or_insert_with(String::new);
+                loop {
+                    match chars.next() {
+                        Some('\'') => break,
+                        Some(c) => word.push(c),
+                        None => return Err("unterminated single quote".into()),
+                    }
+                }
+            }
+            '"' => {
+                let word = word.get_or_insert_with(String::new);
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some(c @ ('"' | '\\')) => word.push(c),
+                            Some(c) => {
+                                word.push('\\');
+                                word.push(c);
+                            }
+                            None => return Err("unterminated double quote".into()),
+                        },
+                        Some(c) => word.push(c),
+                        None => return Err("unterminated double quote".into()),
+                    }
+                }
+            }
+            '\\' => match chars.next() {
+                Some(c) => word.get_or_insert_with(String::new).push(c),
+                None => return Err("trailing backslash".into()),
+            },
+            c => word.get_or_insert_with(String::new).push(c),
+        }
+    }
+    args.extend(word);
+    Ok(args)
+}
+
+fn push_flag(args: &mut Vec<OsString>, name: &str, value: impl Into<OsString>) {
+    args.push(name.into());
+    args.push(value.into());
+}
+
+/// The full command line `run_ocr_sync` would execute, quoted so it can be
+/// pasted into the platform's shell: `cmd.exe` on Windows, a POSIX shell
+/// elsewhere.  Uses the executable found by the startup check rather than
+/// probing, since this runs on the UI thread; before one is found it is
+/// the configured one as typed.
+pub fn command_line(settings: &Settings, image_path: &Path) -> String {
+    let resolved = last_resolution(&settings.easyocr_exe).and_then(Resolution::found);
+    let (exe, prefix_args) = resolved.unwrap_or_else(|| {
+        let exe = if settings.easyocr_exe.is_empty() {
+            "easyocr".to_string()
+        } else {
+            settings.easyocr_exe.clone()
+        };
+        (exe, vec![])
+    });
+    let (program, args) = invocation(exe, prefix_args, settings, image_path);
+    shell_command(Shell::HOST, child_env(settings), program, args)
+}
+
+fn shell_command(
+    shell: Shell,
+    env: Vec<(&str, String)>,
+    program: String,
+    args: Vec<OsString>,
+) -> String {
+    env.into_iter()
+        .map(|(name, value)| shell.env_prefix(name, &value))
+        .chain(
+            std::iter::once(OsString::from(program))
+                .chain(args)
+                .map(|a| shell.quote(&a.to_string_lossy())),
+        )
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// The environment variable that pins the run to `settings.gpu_device`.
+/// The CLI's `--gpu` only takes a boolean, so the card is picked by hiding
+/// the others from CUDA; EasyOCR then uses the one it can see.
+pub fn gpu_env(settings: &Settings) -> Option<(&'static str, String)> {
+    let device = settings.gpu_device.trim();
+    let device = device.strip_prefix("cuda:").unwrap_or(device).trim();
+    (settings.gpu && !device.is_empty()).then(|| ("CUDA_VISIBLE_DEVICES", device.to_string()))
+}
+
+/// Hands `Settings::model_download_url` to [`MIRROR_PATCH`].
+pub const MODEL_MIRROR_ENV: &str = "EASYOCR_MODEL_MIRROR";
+
+/// The environment variables a run sets: [`gpu_env`], and the model mirror
+/// when there is one.
+pub fn child_env(settings: &Settings) -> Vec<(&'static str, String)> {
+    let mirror = settings.model_download_url.trim();
+    gpu_env(settings)
+        .into_iter()
+        .chain((!mirror.is_empty()).then(|| (MODEL_MIRROR_ENV, mirror.to_string())))
+        .collect()
+}
+
+/// EasyOCR has no option for where models come from, so with a mirror set
+/// this runs first and rewrites the URLs in its model tables in place.
+const MIRROR_PATCH: &str = r#"import os
+import easyocr.config
+mirror = os.environ["EASYOCR_MODEL_MIRROR"].rstrip("/")
+for models in [easyocr.config.detection_models, *easyocr.config.recognition_models.values()]:
+    for model in models.values():
+        model["url"] = model["url"].replace(
+            "https://github.com/JaidedAI/EasyOCR/releases/download", mirror)
+"#;
+
+/// Released EasyOCR declares `--rotation_info` with `type=list`, which
+/// splits "90" into "9", "0" and leaves any further angle unrecognised.
+/// Redeclared as a list of ints, `--rotation_info 90 270` works with every
+/// version.
+const ROTATION_PATCH: &str = r#"import argparse
+_add_argument = argparse.ArgumentParser.add_argument
+def add_argument(self, *names, **kwargs):
+    if "--rotation_info" in names:
+        kwargs.update(nargs="+", type=int)
+    return _add_argument(self, *names, **kwargs)
+argparse.ArgumentParser.add_argument = add_argument
+"#;
+
+/// Runs the CLI from Python, after [`MIRROR_PATCH`] and [`ROTATION_PATCH`].
+const CLI_SCRIPT: &str = "from easyocr.cli import main\nmain()\n";
+
+/// Whether the CLI has to be run through [`CLI_SCRIPT`] for the patches
+/// it needs.
+fn needs_cli_script(settings: &Settings) -> bool {
+    !settings.model_download_url.trim().is_empty() || !rotation_angles(settings).is_empty()
+}
+
+/// [`CLI_SCRIPT`], preceded by the patches `settings` need.
+fn cli_script(settings: &Settings) -> String {
+    let rotation = if rotation_angles(settings).is_empty() { "" } else { ROTATION_PATCH };
+    with_mirror(settings, &format!("{rotation}{CLI_SCRIPT}"))
+}
+
+/// `script`, preceded by [`MIRROR_PATCH`] when a mirror is set.
+fn with_mirror(settings: &Settings, script: &str) -> String {
+    if settings.model_download_url.trim().is_empty() {
+        script.to_string()
+    } else {
+        format!("{MIRROR_PATCH}{script}")
+    }
+}
+
+/// The program and arguments for a run, given the resolved easyocr command.
+/// The CLI always recognises, so detect-only runs call the Python API in
+/// the interpreter easyocr is installed for; so do runs with a model mirror
+/// or rotation angles, which need patching in before the CLI starts.
+fn invocation(
+    program: String,
+    prefix_args: Vec<String>,
+    settings: &Settings,
+    image_path: &Path,
+) -> (String, Vec<OsString>) {
+    if settings.detect_only {
+        return (python_for(program, &prefix_args), build_detect_args(settings, image_path));
+    }
+    if needs_cli_script(settings) {
+        let script = cli_script(settings);
+        let args = ["-c".into(), script.into()]
+            .into_iter()
+            .chain(build_ocr_args(settings, image_path))
+            .collect();
+        return (python_for(program, &prefix_args), args);
+    }
+    let args = prefix_args
+        .into_iter()
+        .map(OsString::from)
+        .chain(build_ocr_args(settings, image_path))
+        .collect();
+    (program, args)
+}
+
+/// The Python interpreter behind a resolved easyocr command.
+fn python_for(program: String, prefix_args: &[String]) -> String {
+    if prefix_args.is_empty() {
+        script_interpreter(&program).unwrap_or_else(|| DEFAULT_PYTHON.to_string())
+    } else {
+        program
+    }
+}
+
+/// Interpreter tried when the easyocr script's own can't be found.
+const DEFAULT_PYTHON: &str = if cfg!(windows) { "python" } else { "python3" };
+
+/// Prints each detected box as a JSON list of four `[x, y]` corners.
+/// Horizontal boxes come back as `[x_min, x_max, y_min, y_max]`.
+const DETECT_SCRIPT: &str = r#"import json, sys
+import easyocr
+o = json.loads(sys.argv[1])
+reader = easyocr.Reader(o["lang"], gpu=o["gpu"], model_storage_directory=o["model_dir"],
+                        recognizer=False, verbose=False)
+horizontal, free = reader.detect(o["image"], min_size=o["min_size"],
+    text_threshold=o["text_threshold"], low_text=o["low_text"],
+    link_threshold=o["link_threshold"], canvas_size=o["canvas_size"],
+    mag_ratio=o["mag_ratio"], add_margin=o["add_margin"])
+for x0, x1, y0, y1 in horizontal[0]:
+    print(json.dumps([[float(x0), float(y0)], [float(x1), float(y0)],
+                      [float(x1), float(y1)], [float(x0), float(y1)]]))
+for box in free[0]:
+    print(json.dumps([[float(x), float(y)] for x, y in box]))
+"#;
+
+/// `python` arguments for a detect-only run: the script, then the options
+/// it reads as one JSON argument.
+pub fn build_detect_args(settings: &Settings, image_path: &Path) -> Vec<OsString> {
+    let model_dir = (!settings.model_storage_directory.is_empty())
+        .then(|| expand_home_dir(&settings.model_storage_directory));
+    let options = serde_json::json!({
+        "lang": parse_languages(&settings.languages),
+        "gpu": settings.gpu,
+        "model_dir": model_dir,
+        "image": image_path.to_string_lossy(),
+        "min_size": settings.min_size,
+        "text_threshold": settings.text_threshold,
+        "low_text": settings.low_text,
+        "link_threshold": settings.link_threshold,
+        "canvas_size": settings.canvas_size,
+        "mag_ratio": settings.mag_ratio,
+        "add_margin": settings.add_margin,
+    });
+    vec!["-c".into(), with_mirror(settings, DETECT_SCRIPT).into(), options.to_string().into()]
+}
+
+/// Loads a reader once, then answers one JSON request per stdin line with
+/// one JSON reply per stdout line: `{"lines": [[box, text, confidence]]}`,
+/// or `{"error": traceback}` when that image failed.  Exits at end of input.
+const SERVER_SCRIPT: &str = r#"import json, sys, traceback
+import easyocr
+o = json.loads(sys.argv[1])
+reader = easyocr.Reader(o["lang"], gpu=o["gpu"], model_storage_directory=o["model_dir"],
+                        quantize=o["quantize"], verbose=False)
+for request in sys.stdin:
+    request = json.loads(request)
+    try:
+        found = reader.readtext(request["image"], **request["options"])
+        reply = {"lines": [[[[float(x), float(y)] for x, y in r[0]], str(r[1]),
+                            float(r[2]) if len(r) > 2 else 0.0] for r in found]}
+    except Exception:
+        reply = {"error": traceback.format_exc()}
+    print(json.dumps(reply), flush=True)
+"#;
+
+/// The idle [`SERVER_SCRIPT`], shared by every run in the process.  A run
+/// takes it out while it waits for its reply, so the lock is only held
+/// briefly.
+static SERVER: Mutex<Option<OcrServer>> = Mutex::new(None);
+
+/// Held by a run for as long as it uses the server: it reads one image at
+/// a time, so runs take turns.
+static SERVER_TURN: Mutex<()> = Mutex::new(());
+
+/// Bumped by [`stop_server`]; a server started before that is not reused.
+static SERVER_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// A [`SERVER_SCRIPT`] process.  Its output is read line by line on
+/// threads of its own; it is killed when dropped.
+struct OcrServer {
+    /// Everything the reader was built from; a run with different values
+    /// needs a new process.
+    key: String,
+    /// [`SERVER_GENERATION`] when it was started.
+    generation: u64,
+    child: Child,
+    replies: mpsc::Receiver<String>,
+    stderr: mpsc::Receiver<String>,
+}
+
+impl OcrServer {
+    fn start(
+        python: &str,
+        reader: &str,
+        key: String,
+        settings: &Settings,
+    ) -> Result<Self, OcrError> {
+        let mut cmd = Command::new(python);
+        cmd.args(["-c", &with_mirror(settings, SERVER_SCRIPT), reader])
+            .envs(child_env(settings))
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        let mut child = cmd.spawn().map_err(|e| OcrError::SpawnFailed {
+            program: python.to_string(),
+            source: Arc::new(e),
+        })?;
+        let replies = spawn_line_reader(child.stdout.take());
+        let stderr = spawn_line_reader(child.stderr.take());
+        let generation = SERVER_GENERATION.load(Ordering::Relaxed);
+        Ok(Self { key, generation, child, replies, stderr })
+    }
+}
+
+impl Drop for OcrServer {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+/// Stops the persistent server, if one is running, freeing its memory.
+/// Does not block: a server busy with a run is dropped when that run ends,
+/// and the process is reaped on a thread of its own.
+pub fn stop_server() {
+    SERVER_GENERATION.fetch_add(1, Ordering::Relaxed);
+    let idle = match SERVER.try_lock() {
+        Ok(mut slot) => slot.take(),
+        Err(TryLockError::Poisoned(e)) => e.into_inner().take(),
+        Err(TryLockError::WouldBlock) => None,
+    };
+    if let Some(mut server) = idle {
+        let _ = server.child.kill();
+        thread::spawn(move || drop(server));
+    }
+}
+
+/// Whether a run goes to the persistent server.  Detect-only runs have a
+/// script of their own, and extra arguments are CLI flags the Python API
+/// cannot take.  Runs on the server take turns, so a batch is then read one
+/// image at a time whatever `batch_concurrency` says.
+pub fn uses_server(settings: &Settings) -> bool {
+    settings.persistent_server && !settings.detect_only && settings.extra_args.trim().is_empty()
+}
+
+/// Sends every line of `pipe` on the returned channel, which closes at end
+/// of output.
+fn spawn_line_reader<R: Read + Send + 'static>(pipe: Option<R>) -> mpsc::Receiver<String> {
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        let Some(pipe) = pipe else {
+            return;
+        };
+        for line in BufReader::new(pipe).lines().map_while(Result::ok) {
+            if tx.send(line).is_err() {
+                break;
+            }
+        }
+    });
+    rx
+}
+
+/// The `easyocr.Reader` arguments the server is started with.
+fn server_reader_options(settings: &Settings) -> serde_json::Value {
+    let model_dir = (!settings.model_storage_directory.is_empty())
+        .then(|| expand_home_dir(&settings.model_storage_directory));
+    serde_json::json!({
+        "lang": parse_languages(&settings.languages),
+        "gpu": settings.gpu,
+        "model_dir": model_dir,
+        "quantize": settings.quantize,
+    })
+}
+
+/// One request line for the server: `readtext` keyword arguments matching
+/// the flags [`build_ocr_args`] gives the CLI.  Details are always asked
+/// for and dropped afterwards if unwanted.
+fn server_request(settings: &Settings, image_path: &Path) -> serde_json::Value {
+    let chars = |s: &str| (!s.is_empty()).then(|| s.to_string());
+    let angles = rotation_angles(settings);
+    serde_json::json!({
+        "image": image_path.to_string_lossy(),
+        "options": {
+            "decoder": settings.decoder.as_str(),
+            "beamWidth": settings.beam_width,
+            "batch_size": settings.batch_size,
+            "workers": effective_workers(settings.workers),
+            "allowlist": chars(&settings.allowlist),
+            "blocklist": chars(&settings.blocklist),
+            "detail": 1,
+            "rotation_info": (!angles.is_empty()).then_some(angles),
+            "paragraph": settings.paragraph,
+            "min_size": settings.min_size,
+            "contrast_ths": settings.contrast_ths,
+            "adjust_contrast": settings.adjust_contrast,
+            "text_threshold": settings.text_threshold,
+            "low_text": settings.low_text,
+            "link_threshold": settings.link_threshold,
+            "canvas_size": settings.canvas_size,
+            "mag_ratio": settings.mag_ratio,
+            "x_ths": settings.x_ths,
+            "y_ths": settings.y_ths,
+            "add_margin": settings.add_margin,
+        },
+    })
+}
+
+/// A reply line from [`SERVER_SCRIPT`].
+#[derive(Deserialize)]
+struct ServerReply {
+    #[serde(default)]
+    lines: Vec<([[f32; 2]; 4], String, f32)>,
+    error: Option<String>,
+}
+
+/// Reads a server reply: the recognised lines, or the traceback of the
+/// failure.  `None` for anything else Python printed on stdout.
+fn parse_server_reply(line: &str) -> Option<Result<Vec<OcrLine>, String>> {
+    let reply: ServerReply = serde_json::from_str(line.trim()).ok()?;
+    if let Some(error) = reply.error {
+        return Some(Err(error));
+    }
+    let lines = reply
+        .lines
+        .into_iter()
+        .map(|(bbox, text, confidence)| OcrLine { bbox, text, confidence })
+        .collect();
+    Some(Ok(lines))
+}
+
+/// Recognises `image_path` on the persistent server, starting it first if
+/// none is running for these settings.  Cancelling or timing out kills the
+/// server, so the next run loads the model again.
+fn run_on_server(
+    python: &str,
+    image_path: &Path,
+    settings: &Settings,
+    cancel: &AtomicBool,
+    progress: &ProgressSink,
+) -> OcrResult {
+    let failed = |error| OcrResult {
+        lines: vec![],
+        unparsed: vec![],
+        stderr_note: None,
+        raw: String::new(),
+        error: Some(error),
+    };
+    let reader = server_reader_options(settings).to_string();
+    let key = format!("{python}\n{reader}\n{:?}", child_env(settings));
+
+    // Runs take turns, since the server reads one image at a time.  It is
+    // only put back after a complete reply; dropping it kills it.
+    let _turn = SERVER_TURN.lock().unwrap_or_else(PoisonError::into_inner);
+    // A run cancelled while waiting must not start a server in place of the
+    // one the cancel just killed.
+    if cancel.load(Ordering::Relaxed) {
+        return failed(OcrError::Cancelled);
+    }
+    let idle = SERVER.lock().unwrap_or_else(PoisonError::into_inner).take();
+    let generation = SERVER_GENERATION.load(Ordering::Relaxed);
+    let reusable = idle
+        .filter(|s| s.key == key && s.generation == generation)
+        .and_then(|mut s| matches!(s.child.try_wait(), Ok(None)).then_some(s));
+    let mut running = match reusable {
+        Some(running) => running,
+        None => match OcrServer::start(python, &reader, key, settings) {
+            Ok(started) => started,
+            Err(e) => return failed(e),
+        },
+    };
+
+    // Warnings left over from earlier runs are not this run's.
+    running.stderr.try_iter().for_each(drop);
+    let request = server_request(settings, image_path).to_string();
+    // A write to a process that has died fails, which is noticed below when
+    // its output ends.
+    if let Some(stdin) = running.child.stdin.as_mut() {
+        let _ = writeln!(stdin, "{request}").and_then(|()| stdin.flush());
+    }
+
+    let deadline = (settings.timeout_secs > 0)
+        .then(|| Instant::now() + Duration::from_secs(settings.timeout_secs.into()));
+    let mut stderr = String::new();
+    let reply = loop {
+        if cancel.load(Ordering::Relaxed) {
+            return failed(OcrError::Cancelled);
+        }
+        if deadline.is_some_and(|d| Instant::now() >= d) {
+            return failed(OcrError::Timeout {
+                secs: settings.timeout_secs,
+            });
+        }
+        for line in running.stderr.try_iter() {
+            if let Some(status) = progress_status(&line) {
+                let _ = progress.status.send(status);
+            }
+            stderr.push_str(&line);
+            stderr.push('\n');
+        }
+        match running.replies.recv_timeout(POLL_INTERVAL) {
+            Ok(line) => {
+                if let Some(reply) = parse_server_reply(&line) {
+                    break (reply, line);
+                }
+            }
+            Err(mpsc::RecvTimeoutError::Timeout) => {}
+            Err(mpsc::RecvTimeoutError::Disconnected) => {
+                // The server exited, most likely while loading the model.
+                let code = running.child.wait().ok().and_then(|status| status.code());
+                for line in running.stderr.iter() {
+                    stderr.push_str(&line);
+                    stderr.push('\n');
+                }
+                return failed(OcrError::NonZeroExit {
+                    code,
+                    stderr,
+                    stdout: String::new(),
+                });
+            }
+        }
+    };
+    // Unless the server was stopped meanwhile.
+    if running.generation == SERVER_GENERATION.load(Ordering::Relaxed) {
+        *SERVER.lock().unwrap_or_else(PoisonError::into_inner) = Some(running);
+    }
+    let (reply, raw) = reply;
+
+    let mut lines = match reply {
+        Ok(lines) => lines,
+        Err(traceback) => {
+            return failed(OcrError::NonZeroExit {
+                code: None,
+                stderr: stderr + &traceback,
+                stdout: String::new(),
+            })
+        }
+    };
+    progress.lines_seen.fetch_add(lines.len(), Ordering::Relaxed);
+    if !settings.detail {
+        for line in &mut lines {
+            line.bbox = [[0.0; 2]; 4];
+            line.confidence = 0.0;
+        }
+    }
+    sort_lines(&mut lines, settings);
+    OcrResult {
+        stderr_note: empty_run_note(&lines, &stderr),
+        lines,
+        unparsed: vec![],
+        raw,
+        error: None,
+    }
+}
+
+/// The interpreter named by the `#!` line of the easyocr script `program`,
+/// looked up on `PATH` unless it is a path already.  pip writes such a line
+/// on Unix; Windows launchers are executables and yield `None`.
+fn script_interpreter(program: &str) -> Option<String> {
+    let path = Path::new(program);
+    let script = if path.components().count() > 1 {
+        path.to_path_buf()
+    } else {
+        std::env::split_paths(&std::env::var_os("PATH")?)
+            .map(|dir| dir.join(program))
+            .find(|p| p.is_file())?
+    };
+    let mut first_line = String::new();
+    std::io::BufRead::read_line(
+        &mut std::io::BufReader::new(std::fs::File::open(script).ok()?),
+        &mut first_line,
+    )
+    .ok()?;
+    shebang_interpreter(&first_line)
+}
+
+/// `/usr/bin/python3` from `#!/usr/bin/python3`, and `python3` from
+/// `#!/usr/bin/env python3`.
+fn shebang_interpreter(line: &str) -> Option<String> {
+    let mut words = line.strip_prefix("#!")?.split_whitespace();
+    let program = words.next()?;
+    if Path::new(program).file_name().is_some_and(|n| n == "env") {
+        words.find(|w| !w.starts_with('-')).map(str::to_string)
+    } else {
+        Some(program.to_string())
+    }
+}
+
+/// The shell a copied command is meant to be pasted into.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Shell {
+    /// sh, bash, zsh and friends.
+    Posix,
+    /// Windows `cmd.exe`, with arguments split by the usual
+    /// `CommandLineToArgvW` rules.
+    Cmd,
+}
+
+impl Shell {
+    const HOST: Shell = if cfg!(windows) { Shell::Cmd } else { Shell::Posix };
+
+    /// `arg` as one word for this shell, quoted only when needed.
+    fn quote(self, arg: &str) -> String {
+        let safe = match self {
+            Shell::Posix => "-_./,:=+@%",
+            Shell::Cmd => "-_./\\,:=+@",
+        };
+        if !arg.is_empty() && arg.chars().all(|c| c.is_ascii_alphanumeric() || safe.contains(c)) {
+            return arg.to_string();
+        }
+        match self {
+            Shell::Posix => format!("'{}'", arg.replace('\'', r"'\''")),
+            Shell::Cmd => {
+                // Backslashes are literal unless they run into a quote, where
+                // they have to be doubled so the quote isn't escaped.
+                let mut quoted = String::from('"');
+                let mut backslashes = 0;
+                for c in arg.chars() {
+                    if c == '\\' {
+                        backslashes += 1;
+                        continue;
+                    }
+                    let escapes = if c == '"' { backslashes * 2 + 1 } else { backslashes };
+                    quoted.extend(std::iter::repeat_n('\\', escapes));
+                    quoted.push(c);
+                    backslashes = 0;
+                }
+                quoted.extend(std::iter::repeat_n('\\', backslashes * 2));
+                quoted.push('"');
+                quoted
+            }
+        }
+    }
+
+    /// Sets `name` for the command that follows.
+    fn env_prefix(self, name: &str, value: &str) -> String {
+        match self {
+            Shell::Posix => format!("{}={}", name, self.quote(value)),
+            Shell::Cmd => format!("set \"{}={}\" &&", name, value),
+        }
+    }
+}
+
+/// Quotes `arg` for the shell of the platform the GUI runs on.
+fn shell_quote(arg: &str) -> String {
+    Shell::HOST.quote(arg)
+}
+
+fn run_ocr_sync(
+    image_path: &Path,
+    settings: &Settings,
+    cancel: &AtomicBool,
+    progress: &ProgressSink,
+) -> OcrResult {
+    let preprocess = Preprocess::from_settings(settings);
+    if preprocess.is_noop() {
+        return run_easyocr(image_path, settings, cancel, progress);
+    }
+    let processed = match preprocess.prepare_for_ocr(image_path) {
+        Ok(path) => path,
+        Err(e) => {
+            return OcrResult {
+                lines: vec![],
+                unparsed: vec![],
+                stderr_note: None,
+                raw: String::new(),
+                error: Some(OcrError::Preprocess(e)),
+            }
+        }
+    };
+    let mut result = run_easyocr(&processed, settings, cancel, progress);
+    let _ = std::fs::remove_file(&processed);
+    // Report boxes in the original image's coordinates.
+    let scale = preprocess.scale();
+    for point in result.lines.iter_mut().flat_map(|l| l.bbox.iter_mut()) {
+        point[0] /= scale;
+        point[1] /= scale;
+    }
+    result
+}
+
+fn run_easyocr(
+    image_path: &Path,
+    settings: &Settings,
+    cancel: &AtomicBool,
+    progress: &ProgressSink,
+) -> OcrResult {
+    if let Err(e) = split_args(&settings.extra_args) {
+        return OcrResult {
+            lines: vec![],
+            unparsed: vec![],
+            stderr_note: None,
+            raw: String::new(),
+            error: Some(OcrError::InvalidExtraArgs(e)),
+        };
+    }
+
+    // Resolve executable — try direct binary then Python module fallback.
+    let (exe, prefix_args) = match resolve_easyocr_cmd_cached(&settings.easyocr_exe) {
+        Resolution::Found { program, prefix_args } => (program, prefix_args),
+        Resolution::ModuleMissing { python } => {
+            return OcrResult {
+                lines: vec![],
+                unparsed: vec![],
+                stderr_note: None,
+                raw: String::new(),
+                error: Some(OcrError::ModuleMissing { python }),
+            };
+        }
+        Resolution::NotFound => {
+            let tried = if settings.easyocr_exe.is_empty() {
+                "'easyocr' and 'python -m easyocr.cli'".to_string()
+            } else {
+                format!("'{}'", settings.easyocr_exe)
+            };
+            return OcrResult {
+                lines: vec![],
+                unparsed: vec![],
+                stderr_note: None,
+                raw: String::new(),
+                error: Some(OcrError::CommandNotFound { tried }),
+            };
+        }
+    };
+
+    if uses_server(settings) {
+        let python = python_for(exe, &prefix_args);
+        return run_on_server(&python, image_path, settings, cancel, progress);
+    }
+
+    let (exe, args) = invocation(exe, prefix_args, settings, image_path);
+    let mut cmd = Command::new(&exe);
+    cmd.args(args).envs(child_env(settings));
+
+    // Capture stderr for error messages.
+    cmd.stderr(std::process::Stdio::piped());
+    cmd.stdout(std::process::Stdio::piped());
+
+    let mut child = match cmd.spawn() {
+        Ok(c) => c,
+        Err(e) => {
+            return OcrResult {
+                lines: vec![],
+                unparsed: vec![],
+                stderr_note: None,
+                raw: String::new(),
+                error: Some(OcrError::SpawnFailed {
+                    program: exe,
+                    source: Arc::new(e),
+                }),
+            }
+        }
+    };
+
+    // Drain both pipes on their own threads so a chatty child can never block
+    // on a full pipe while we poll for its exit.  EasyOCR logs download
+    // warnings to stderr and draws its progress bar on stdout.
+    let stdout_reader = spawn_pipe_reader(child.stdout.take(), progress.clone(), true);
+    let stderr_reader = spawn_pipe_reader(child.stderr.take(), progress.clone(), false);
+
+    let deadline = (settings.timeout_secs > 0)
+        .then(|| Instant::now() + Duration::from_secs(settings.timeout_secs.into()));
+
+    let status = loop {
+        if cancel.load(Ordering::Relaxed) {
+            let _ = child.kill();
+            let _ = child.wait();
+            return OcrResult {
+                lines: vec![],
+                unparsed: vec![],
+                stderr_note: None,
+                raw: String::new(),
+                error: Some(OcrError::Cancelled),
+            };
+        }
+        if deadline.is_some_and(|d| Instant::now() >= d) {
+            let _ = child.kill();
+            let _ = child.wait();
+            return OcrResult {
+                lines: vec![],
+                unparsed: vec![],
+                stderr_note: None,
+                raw: String::new(),
+                error: Some(OcrError::Timeout {
+                    secs: settings.timeout_secs,
+                }),
+            };
+        }
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) => thread::sleep(POLL_INTERVAL),
+            Err(e) => {
+                let _ = child.kill();
+                return OcrResult {
+                    lines: vec![],
+                    unparsed: vec![],
+                    stderr_note: None,
+                    raw: String::new(),
+                    error: Some(OcrError::WaitFailed {
+                        program: exe,
+                        source: Arc::new(e),
+                    }),
+                };
+            }
+        }
+    };
+
+    let stdout = stdout_reader.join().unwrap_or_default();
+    let stderr = stderr_reader.join().unwrap_or_default();
+
+    if !status.success() {
+        return OcrResult {
+            lines: vec![],
+            unparsed: vec![],
+            stderr_note: None,
+            raw: String::new(),
+            error: Some(OcrError::NonZeroExit {
+                code: status.code(),
+                stderr: String::from_utf8_lossy(&stderr).to_string(),
+                stdout: String::from_utf8_lossy(&stdout).to_string(),
+            }),
+        };
+    }
+
+    let stdout = String::from_utf8_lossy(&stdout).to_string();
+    let parsed = if settings.detect_only {
+        parse_detect_output(&stdout)
+    } else if !settings.detail {
+        parse_easyocr_text_output(&stdout)
+    } else if settings.json_output {
+        parse_easyocr_json_output(&stdout)
+    } else {
+        parse_easyocr_output(&stdout)
+    };
+    let mut lines = parsed.lines;
+    sort_lines(&mut lines, settings);
+
+    OcrResult {
+        stderr_note: empty_run_note(&lines, &String::from_utf8_lossy(&stderr)),
+        lines,
+        unparsed: parsed.unparsed,
+        raw: stdout,
+        error: None,
+    }
+}
+
+/// Puts `lines` in the reading order the settings ask for.
+fn sort_lines(lines: &mut [OcrLine], settings: &Settings) {
+    if settings.vertical_text {
+        sort_vertical_order(lines, settings.row_tolerance);
+    } else if settings.sort_by_position {
+        sort_reading_order(lines, settings.row_tolerance);
+    }
+}
+
+/// Most stderr lines EasyOCR shows in an empty run's note.
+const STDERR_NOTE_LINES: usize = 3;
+
+/// The last few lines of stderr, without download progress, when a run
+/// succeeded but recognised nothing.  Warnings are only worth showing then;
+/// EasyOCR prints deprecation and CUDA notices on every run.
+fn empty_run_note(lines: &[OcrLine], stderr: &str) -> Option<String> {
+    if !lines.is_empty() {
+        return None;
+    }
+    let said: Vec<&str> = output_lines(stderr)
+        .map(str::trim)
+        .filter(|l| !l.is_empty() && progress_status(l).is_none())
+        .collect();
+    let tail = &said[said.len().saturating_sub(STDERR_NOTE_LINES)..];
+    (!tail.is_empty()).then(|| tail.join("\n"))
+}
+
+/// Reorders `lines` top-to-bottom, then left-to-right within a row.  A box
+/// joins the current row when its top edge is no more than `row_tolerance`
+/// median box heights below the top of the row's first box, so slightly
+/// uneven baselines still read as one line.  Text-only lines keep their order.
+pub fn sort_reading_order(lines: &mut [OcrLine], row_tolerance: f32) {
+    if !lines.iter().all(OcrLine::has_position) {
+        return;
+    }
+    let top = |l: &OcrLine| l.bbox.iter().map(|p| p[1]).fold(f32::INFINITY, f32::min);
+    let left = |l: &OcrLine| l.bbox.iter().map(|p| p[0]).fold(f32::INFINITY, f32::min);
+    let height = |l: &OcrLine| {
+        let bottom = l.bbox.iter().map(|p| p[1]).fold(f32::NEG_INFINITY, f32::max);
+        bottom - top(l)
+    };
+
+    let mut heights: Vec<f32> = lines.iter().map(height).collect();
+    heights.sort_by(f32::total_cmp);
+    let tolerance = heights.get(heights.len() / 2).copied().unwrap_or(0.0) * row_tolerance;
+
+    lines.sort_by(|a, b| top(a).total_cmp(&top(b)));
+    let mut start = 0;
+    while start < lines.len() {
+        let row_top = top(&lines[start]);
+        let end = lines[start..]
+            .iter()
+            .position(|l| top(l) - row_top > tolerance)
+            .map_or(lines.len(), |n| start + n);
+        lines[start..end].sort_by(|a, b| left(a).total_cmp(&left(b)));
+        start = end;
+    }
+}
+
+/// Reorders `lines` as traditional vertical text is read: columns from
+/// right to left, each top-to-bottom.  Columns are grouped like the rows of
+/// [`sort_reading_order`], by right edge and median box width.
+pub fn sort_vertical_order(lines: &mut [OcrLine], column_tolerance: f32) {
+    if !lines.iter().all(OcrLine::has_position) {
+        return;
+    }
+    let top = |l: &OcrLine| l.bbox.iter().map(|p| p[1]).fold(f32::INFINITY, f32::min);
+    let right = |l: &OcrLine| l.bbox.iter().map(|p| p[0]).fold(f32::NEG_INFINITY, f32::max);
+    let width = |l: &OcrLine| {
+        let left = l.bbox.iter().map(|p| p[0]).fold(f32::INFINITY, f32::min);
+        right(l) - left
+    };
+
+    let mut widths: Vec<f32> = lines.iter().map(width).collect();
+    widths.sort_by(f32::total_cmp);
+    let tolerance = widths.get(widths.len() / 2).copied().unwrap_or(0.0) * column_tolerance;
+
+    lines.sort_by(|a, b| right(b).total_cmp(&right(a)));
+    let mut start = 0;
+    while start < lines.len() {
+        let column_right = right(&lines[start]);
+        let end = lines[start..]
+            .iter()
+            .position(|l| column_right - right(l) > tolerance)
+            .map_or(lines.len(), |n| start + n);
+        lines[start..end].sort_by(|a, b| top(a).total_cmp(&top(b)));
+        start = end;
+    }
+}
+
+/// Reads a child's pipe to completion on a separate thread, forwarding any
+/// status lines (see [`progress_status`]) as they arrive.  With
+/// `count_lines`, every other non-empty line is counted as a result.  Returns
+/// everything read so the caller can still parse the full output.
+fn spawn_pipe_reader<R: Read + Send + 'static>(
+    pipe: Option<R>,
+    progress: ProgressSink,
+    count_lines: bool,
+) -> thread::JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut buf = Vec::new();
+        let Some(mut pipe) = pipe else {
+            return buf;
+        };
+        let mut chunk = [0u8; 4096];
+        let mut line_start = 0;
+        loop {
+            let n = match pipe.read(&mut chunk) {
+                Ok(0) => break,
+                Ok(n) => n,
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(_) => break,
+            };
+            buf.extend_from_slice(&chunk[..n]);
+            // The download progress bar redraws itself with '\r', so treat it
+            // as a line break too.
+            while let Some(len) = buf[line_start..].iter().position(|&b| b == b'\n' || b == b'\r') {
+                let line = String::from_utf8_lossy(&buf[line_start..line_start + len]);
+                match progress_status(&line) {
+                    Some(status) => {
+                        let _ = progress.status.send(status);
+                    }
+                    None if count_lines && !line.trim().is_empty() => {
+                        progress.lines_seen.fetch_add(1, Ordering::Relaxed);
+                    }
+                    None => {}
+                }
+                line_start += len + 1;
+            }
+        }
+        buf
+    })
+}
+
+/// Turns a line of EasyOCR chatter into a short status-bar message, or
+/// `None` if the line is not worth showing.
+///
+///   Downloading detection model, please wait. This may take ...  → "Downloading detection model, please wait"
+///   Progress: |█████-----| 42.0% Complete                       → "Progress: 42.0%"
+fn progress_status(line: &str) -> Option<String> {
+    let line = line.trim();
+    if let Some(rest) = line.strip_prefix("Progress:") {
+        let percent = rest.split_whitespace().find(|w| w.ends_with('%'))?;
+        return Some(format!("Progress: {}", percent));
+    }
+    if line.contains("ownloading") {
+        let first_sentence = line.split(". ").next().unwrap_or(line);
+        return Some(first_sentence.trim_end_matches('.').to_string());
+    }
+    None
+}
+
+/// The lines of the CLI's output.  Progress bars redraw themselves with
+/// `\r` and no newline, so a result printed next can share their line;
+/// splitting on `\r` as well keeps the two apart.
+fn output_lines(output: &str) -> impl Iterator<Item = &str> {
+    output.split(['\n', '\r'])
+}
+
+/// Parse the standard EasyOCR CLI output (detail=1).
+///
+/// Each line looks like one of:
+///   ([[x1,y1],[x2,y2],[x3,y3],[x4,y4]], 'text', 0.99)        — standard mode
+///   [[[x1,y1],[x2,y2],[x3,y3],[x4,y4]], 'text']               — paragraph mode
+///
+/// Lines that match neither form are returned in `unparsed`, except for the
+/// download chatter recognised by [`progress_status`].
+fn parse_easyocr_output(output: &str) -> ParsedOutput {
+    let mut lines = Vec::new();
+    let mut unparsed = Vec::new();
+
+    for raw_line in output_lines(output) {
+        let raw_line = raw_line.trim();
+        if raw_line.is_empty() {
+            continue;
+        }
+
+        // Try to extract the text and confidence from the tuple representation.
+        // Simplified parser: look for the last comma-separated float at the end.
+        match parse_line(raw_line) {
+            Some(parsed) => lines.push(parsed),
+            None if progress_status(raw_line).is_none() => unparsed.push(raw_line.to_string()),
+            None => {}
+        }
+    }
+
+    ParsedOutput { lines, unparsed }
+}
+
+/// Parse `--detail 0` output, where every line is just the recognised text.
+/// Boxes and confidences are left at zero.
+fn parse_easyocr_text_output(output: &str) -> ParsedOutput {
+    let lines = output_lines(output)
+        .map(str::trim)
+        .filter(|l| !l.is_empty() && progress_status(l).is_none())
+        .map(|text| OcrLine {
+            bbox: [[0.0; 2]; 4],
+            text: text.to_string(),
+            confidence: 0.0,
+        })
+        .collect();
+    ParsedOutput {
+        lines,
+        unparsed: vec![],
+    }
+}
+
+fn parse_line(s: &str) -> Option<OcrLine> {
+    let s = strip_numpy_wrappers(s.trim());
+    let s = s.as_ref();
+
+    // Strip outer delimiters: "(...)" for standard mode or "[...]" for paragraph mode.
+    let s = if let Some(inner) = s.strip_prefix('(').and_then(|s| s.strip_suffix(')')) {
+        inner
+    } else {
+        s.strip_prefix('[').and_then(|s| s.strip_suffix(']'))?
+    };
+
+    // Find the split between bbox and the rest.
+    // The bbox is "[[...]]", then a comma, then the text, then optionally a comma and confidence.
+    let bracket_end = s.find("]]")?;
+    let bbox_str = &s[..bracket_end + 2];
+    let rest = s[bracket_end + 2..].trim().strip_prefix(',')?.trim();
+
+    // Parse bbox: [[x1,y1],[x2,y2],[x3,y3],[x4,y4]]
+    let bbox = parse_bbox(bbox_str)?;
+
+    // rest is "'text', 0.99" (standard) or "'text'" (paragraph, no confidence).
+    // Try to find the last comma — before the confidence value.
+    let (text_part, confidence) = if let Some(last_comma) = rest.rfind(',') {
+        let conf_part = rest[last_comma + 1..].trim();
+        // Only treat as confidence if it actually parses as a float.
+        if let Ok(conf) = conf_part.parse::<f32>() {
+            (rest[..last_comma].trim(), conf)
+        } else {
+            (rest, 0.0)
+        }
+    } else {
+        (rest, 0.0)
+    };
+
+    // Strip quotes from text.
+    let text = text_part
+        .trim_start_matches(['\'', '"'])
+        .trim_end_matches(['\'', '"'])
+        .to_string();
+
+    Some(OcrLine {
+        bbox,
+        text,
+        confidence,
+    })
+}
+
+/// One line of `--output_format json` output.  Paragraph mode omits the
+/// confidence, so it defaults to 0.
+#[derive(Deserialize)]
+struct JsonLine {
+    boxes: [[f32; 2]; 4],
+    text: String,
+    #[serde(default)]
+    confident: f32,
+}
+
+/// Parse EasyOCR CLI output produced with `--output_format json`.
+///
+/// Each result is printed as a single JSON object per line:
+///   {"boxes": [[x1, y1], [x2, y2], [x3, y3], [x4, y4]], "text": "...", "confident": 0.99}
+///
+/// Lines that are not valid result objects (e.g. progress messages) are skipped.
+///
+/// Only lines starting with `{` are candidates; those that fail to
+/// deserialize are returned in `unparsed`.
+fn parse_easyocr_json_output(output: &str) -> ParsedOutput {
+    let mut lines = Vec::new();
+    let mut unparsed = Vec::new();
+    for raw_line in output_lines(output).map(str::trim).filter(|l| l.starts_with('{')) {
+        match serde_json::from_str::<JsonLine>(raw_line) {
+            Ok(j) => lines.push(OcrLine {
+                bbox: j.boxes,
+                text: j.text,
+                confidence: j.confident,
+            }),
+            Err(_) => unparsed.push(raw_line.to_string()),
+        }
+    }
+    ParsedOutput { lines, unparsed }
+}
+
+/// Parses detect-only output: one JSON list of four `[x, y]` corners per
+/// line, each becoming a box with no text.
+fn parse_detect_output(output: &str) -> ParsedOutput {
+    let mut lines = Vec::new();
+    let mut unparsed = Vec::new();
+    for raw_line in output_lines(output).map(str::trim).filter(|l| !l.is_empty()) {
+        match serde_json::from_str::<[[f32; 2]; 4]>(raw_line) {
+            Ok(bbox) => lines.push(OcrLine {
+                bbox,
+                text: String::new(),
+                confidence: 0.0,
+            }),
+            Err(_) => unparsed.push(raw_line.to_string()),
+        }
+    }
+    ParsedOutput { lines, unparsed }
+}
+
+fn parse_bbox(s: &str) -> Option<[[f32; 2]; 4]> {
+    // s looks like [[x1, y1], [x2, y2], [x3, y3], [x4, y4]]
+    let inner = s.strip_prefix("[[")?.strip_suffix("]]")?;
+    // Split on "], ["
+    let points: Vec<&str> = inner.split("], [").collect();
+    if points.len() != 4 {
+        return None;
+    }
+    let mut result = [[0.0f32; 2]; 4];
+    for (i, p) in points.iter().enumerate() {
+        let coords: Vec<&str> = p.split(',').collect();
+        if coords.len() != 2 {
+            return None;
+        }
+        result[i][0] = parse_coord(coords[0])?;
+        result[i][1] = parse_coord(coords[1])?;
+    }
+    Some(result)
+}
+
+/// One bbox coordinate: an integer or float, possibly negative (boxes grown
+/// by `add_margin` can start left of the image) or in scientific notation.
+/// `nan` and `inf` are rejected, as no box can be drawn from them.
+fn parse_coord(s: &str) -> Option<f32> {
+    s.trim().parse::<f32>().ok().filter(|v| v.is_finite())
+}
+
+/// Replaces numpy scalar reprs such as `np.int64(70)` or
+/// `np.float32(0.95)`, which numpy ≥ 2 prints inside result tuples, with
+/// the bare number.  Quoted text is left alone.
+fn strip_numpy_wrappers(s: &str) -> std::borrow::Cow<'_, str> {
+    if !s.contains("np.") {
+        return s.into();
+    }
+    let mut out = String::with_capacity(s.len());
+    let mut quote = None;
+    let mut escaped = false;
+    let mut rest = s;
+    while let Some(c) = rest.chars().next() {
+        if quote.is_none() {
+            if let Some(number) = numpy_scalar(rest) {
+                out.push_str(number.value);
+                rest = &rest[number.len..];
+                continue;
+            }
+        }
+        match (quote, c) {
+            (Some(_), _) if escaped => escaped = false,
+            (Some(_), '\\') => escaped = true,
+            (None, '\'' | '"') => quote = Some(c),
+            (Some(q), _) if c == q => quote = None,
+            _ => {}
+        }
+        out.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+    out.into()
+}
+
+struct NumpyScalar<'a> {
+    value: &'a str,
+    /// Length of the whole `np.type(value)` repr.
+    len: usize,
+}
+
+/// `np.<type>(<number>)` at the start of `s`.
+fn numpy_scalar(s: &str) -> Option<NumpyScalar<'_>> {
+    let after = s.strip_prefix("np.")?;
+    let open = after.find('(')?;
+    if open == 0 || !after[..open].bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_') {
+        return None;
+    }
+    let inner = &after[open + 1..];
+    let close = inner.find(')')?;
+    let value = inner[..close].trim();
+    value.parse::<f64>().ok()?;
+    Some(NumpyScalar { value, len: 3 + open + 1 + close + 1 })
+}
+
+pub fn parse_languages(raw: &str) -> Vec<String> {
+    let langs = crate::lang::split_codes(raw);
+    if langs.is_empty() {
+        vec!["ch_sim".to_string(), "en".to_string()]
+    } else {
+        langs
+    }
+}
+
+/// The worker count actually passed to the CLI: `workers` itself, or the
+/// number of CPU cores when it is 0.
+pub fn effective_workers(workers: u32) -> u32 {
+    if workers > 0 {
+        return workers;
+    }
+    thread::available_parallelism()
+        .map(|n| n.get() as u32)
+        .unwrap_or(1)
+}
+
+/// How many batch images to run at once: `batch_concurrency`, but never
+/// more than there are CPU cores or `MAX_BATCH_CONCURRENCY`.
+pub fn effective_batch_concurrency(batch_concurrency: u32) -> usize {
+    let cores = thread::available_parallelism().map_or(1, |n| n.get());
+    (batch_concurrency.clamp(1, MAX_BATCH_CONCURRENCY) as usize).min(cores)
+}
+
+/// Parse a comma/space separated list of rotation angles, skipping anything
+/// that is not an integer.
+fn parse_rotation_info(raw: &str) -> Vec<i32> {
+    raw.split([',', '，', ' ', ';', '；'])
+        .map(str::trim)
+        .filter_map(|s| s.parse().ok())
+        .collect()
+}
+
+/// Where EasyOCR keeps its models: `model_storage_directory` when set,
+/// else `model` under `$EASYOCR_MODULE_PATH` or `~/.EasyOCR`, as EasyOCR
+/// itself resolves it.
+pub fn effective_model_dir(settings: &Settings) -> PathBuf {
+    if !settings.model_storage_directory.is_empty() {
+        return PathBuf::from(expand_home_dir(&settings.model_storage_directory));
+    }
+    let module_path = std::env::var("EASYOCR_MODULE_PATH")
+        .ok()
+        .filter(|p| !p.is_empty())
+        .unwrap_or_else(|| expand_home_dir("~/.EasyOCR"));
+    Path::new(&module_path).join("model")
+}
+
+pub fn expand_home_dir(path: &str) -> String {
+    if path == "~" {
+        return std::env::var("HOME").unwrap_or_else(|_| path.to_string());
+    }
+    if let Some(rest) = path.strip_prefix("~/") {
+        if let Ok(home) = std::env::var("HOME") {
+            return format!("{home}/{rest}");
+        }
+    }
+    path.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        build_detect_args, build_ocr_args, child_env, effective_batch_concurrency, effective_workers, effective_model_dir, empty_run_note, expand_home_dir, gpu_env, invocation,
+        parse_bbox, parse_cuda_probe, parse_detect_output, parse_version_probe, parse_easyocr_json_output, parse_easyocr_output, parse_easyocr_text_output,
+        parse_languages, parse_line, parse_rotation_info, parse_server_reply, pip_install_command, progress_status,
+        server_request, uses_server,
+        shebang_interpreter, shell_command, shell_quote, Shell, strip_numpy_wrappers, sort_reading_order, sort_vertical_order, split_args, CachedResolution, OcrLine, Resolution, Versions, RESOLUTION_TTL,
+    };
+    use crate::settings::{Settings, MAX_BATCH_CONCURRENCY};
+    use std::ffi::OsString;
+    use std::path::Path;
+    use std::time::Instant;
+
+    #[test]
+    fn parse_languages_uses_default_when_empty() {
+        assert_eq!(parse_languages(""), vec!["ch_sim", "en"]);
+        assert_eq!(parse_languages(" , ; ， "), vec!["ch_sim", "en"]);
+    }
+
+    #[test]
+    fn parse_languages_supports_common_separators() {
+        assert_eq!(parse_languages("ch_sim,en"), vec!["ch_sim", "en"]);
+        assert_eq!(parse_languages("ch_sim，en"), vec!["ch_sim", "en"]);
+        assert_eq!(parse_languages("ch_sim en"), vec!["ch_sim", "en"]);
+    }
+
+    #[test]
+    fn batch_concurrency_is_capped() {
+        assert_eq!(effective_batch_concurrency(0), 1);
+        assert_eq!(effective_batch_concurrency(1), 1);
+        assert!(effective_batch_concurrency(1000) <= MAX_BATCH_CONCURRENCY as usize);
+    }
+
+    #[test]
+    fn parse_rotation_info_skips_invalid_angles() {
+        assert_eq!(parse_rotation_info(""), Vec::<i32>::new());
+        assert_eq!(parse_rotation_info("90, 180,270"), vec![90, 180, 270]);
+        assert_eq!(parse_rotation_info("90,abc,45.5,270"), vec![90, 270]);
+    }
+
+    #[test]
+    fn effective_workers_resolves_auto_to_cpu_count() {
+        assert_eq!(effective_workers(3), 3);
+        let cores = std::thread::available_parallelism().map(|n| n.get() as u32).unwrap_or(1);
+        assert_eq!(effective_workers(0), cores);
+        assert!(effective_workers(0) >= 1);
+    }
+
+    #[test]
+    fn progress_status_picks_download_lines() {
+        assert_eq!(
+            progress_status(
+                "Downloading detection model, please wait. This may take several minutes depending upon your network connection."
+            )
+            .as_deref(),
+            Some("Downloading detection model, please wait")
+        );
+        assert_eq!(
+            progress_status("Progress: |██████----| 61.3% Complete").as_deref(),
+            Some("Progress: 61.3%")
+        );
+        assert_eq!(progress_status("Using CPU. Note: This module is much faster with a GPU."), None);
+        assert_eq!(progress_status("([[1, 2], [3, 4], [5, 6], [7, 8]], 'hi', 0.9)"), None);
+    }
+
+    #[test]
+    fn expand_home_dir_expands_tilde_prefix() {
+        let home = std::env::var("HOME").unwrap_or_default();
+        if home.is_empty() {
+            return;
+        }
+        assert_eq!(expand_home_dir("~"), home);
+        assert_eq!(expand_home_dir("~/models"), format!("{home}/models"));
+        assert_eq!(expand_home_dir("/tmp/models"), "/tmp/models");
+    }
+
+    #[test]
+    fn parse_line_standard_format() {
+        let line = "([[70, 12], [268, 12], [268, 48], [70, 48]], 'Hello World', 0.9543)";
+        let parsed = parse_line(line).expect("should parse standard format");
+        assert_eq!(parsed.text, "Hello World");
+        assert!((parsed.confidence - 0.9543).abs() < 0.001);
+        assert_eq!(parsed.bbox[0], [70.0, 12.0]);
+        assert_eq!(parsed.bbox[2], [268.0, 48.0]);
+    }
+
+    #[test]
+    fn parse_bbox_accepts_floats_negatives_and_exponents() {
+        assert_eq!(
+            parse_bbox("[[70.5, 12.25], [268, 12], [268, 48], [70, 48]]").unwrap()[0],
+            [70.5, 12.25]
+        );
+        assert_eq!(
+            parse_bbox("[[-3, -0.5], [268, 12], [268, 48], [70, 48]]").unwrap()[0],
+            [-3.0, -0.5]
+        );
+        assert_eq!(
+            parse_bbox("[[1e2, 2.5E1], [268, 12], [268, 48], [70, 48]]").unwrap()[0],
+            [100.0, 25.0]
+        );
+    }
+
+    #[test]
+    fn parse_line_unwraps_numpy_scalars() {
+        let plain = parse_line("([[70, 12], [268, 12], [268, 48], [70, 48]], 'Hello', 0.95)").unwrap();
+        let numpy = parse_line(
+            "([[np.int64(70), np.int64(12)], [np.int64(268), np.int64(12)], \
+             [np.int64(268), np.int64(48)], [np.int64(70), np.int64(48)]], 'Hello', np.float64(0.95))",
+        )
+        .unwrap();
+        assert_eq!(numpy.bbox, plain.bbox);
+        assert_eq!(numpy.text, plain.text);
+        assert_eq!(numpy.confidence, plain.confidence);
+    }
+
+    #[test]
+    fn strip_numpy_wrappers_leaves_text_alone() {
+        assert_eq!(strip_numpy_wrappers("[np.float32(-1.5e1), 2]"), "[-1.5e1, 2]");
+        assert_eq!(
+            strip_numpy_wrappers("(np.int32(1), 'call np.int64(7)', np.float32(0.5))"),
+            "(1, 'call np.int64(7)', 0.5)"
+        );
+        assert_eq!(strip_numpy_wrappers("np.array([1])"), "np.array([1])");
+    }
+
+    #[test]
+    fn parse_bbox_rejects_non_finite_values() {
+        assert_eq!(parse_bbox("[[nan, 12], [268, 12], [268, 48], [70, 48]]"), None);
+        assert_eq!(parse_bbox("[[70, inf], [268, 12], [268, 48], [70, 48]]"), None);
+        assert_eq!(parse_bbox("[[70, 12], [268, 12], [268, 48]]"), None);
+    }
+
+    #[test]
+    fn parse_line_paragraph_format() {
+        let line = "[[[70, 12], [268, 12], [268, 48], [70, 48]], 'Hello World']";
+        let parsed = parse_line(line).expect("should parse paragraph format");
+        assert_eq!(parsed.text, "Hello World");
+        assert!((parsed.confidence - 0.0).abs() < 0.001);
+        assert_eq!(parsed.bbox[0], [70.0, 12.0]);
+    }
+
+    #[test]
+    fn parse_easyocr_output_mixed() {
+        let output = "([[10, 20], [100, 20], [100, 50], [10, 50]], 'line one', 0.85)\n\
+                      ([[10, 60], [200, 60], [200, 90], [10, 90]], 'line two', 0.92)\n";
+        let lines = parse_easyocr_output(output).lines;
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].text, "line one");
+        assert_eq!(lines[1].text, "line two");
+    }
+
+    #[test]
+    fn parse_easyocr_output_paragraph_mode() {
+        let output = "[[[10, 20], [200, 20], [200, 90], [10, 90]], 'paragraph text']\n";
+        let lines = parse_easyocr_output(output).lines;
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].text, "paragraph text");
+        assert!((lines[0].confidence - 0.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn parse_text_output_takes_each_line_verbatim() {
+        let output = "Hello, World\n\n  (not a tuple)  \n你好\n";
+        let lines = parse_easyocr_text_output(output).lines;
+        let texts: Vec<&str> = lines.iter().map(|l| l.text.as_str()).collect();
+        assert_eq!(texts, vec!["Hello, World", "(not a tuple)", "你好"]);
+        assert!(lines.iter().all(|l| !l.has_position()));
+    }
+
+    #[test]
+    fn parse_text_output_skips_download_progress() {
+        let output = "Progress: |██████████| 100.0% Complete\nrecognised\n";
+        let lines = parse_easyocr_text_output(output).lines;
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].text, "recognised");
+    }
+
+    #[test]
+    fn parse_line_text_with_comma() {
+        let line = "([[10, 20], [100, 20], [100, 50], [10, 50]], 'hello, world', 0.88)";
+        let parsed = parse_line(line).expect("should handle comma in text");
+        assert_eq!(parsed.text, "hello, world");
+        assert!((parsed.confidence - 0.88).abs() < 0.01);
+    }
+
+    #[test]
+    fn parse_line_paragraph_with_comma() {
+        let line = "[[[10, 20], [100, 20], [100, 50], [10, 50]], 'hello, world']";
+        let parsed = parse_line(line).expect("should handle comma in paragraph text");
+        assert_eq!(parsed.text, "hello, world");
+        assert!((parsed.confidence - 0.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn parse_json_output_standard_and_paragraph() {
+        let output = "{\"boxes\": [[10, 20], [100, 20], [100, 50], [10, 50]], \"text\": \"it's [a], \\\"test\\\"\", \"confident\": 0.87}\n\
+                      {\"boxes\": [[10, 60], [200, 60], [200, 90], [10, 90]], \"text\": \"段落\"}\n";
+        let lines = parse_easyocr_json_output(output).lines;
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].text, "it's [a], \"test\"");
+        assert!((lines[0].confidence - 0.87).abs() < 0.001);
+        assert_eq!(lines[0].bbox[2], [100.0, 50.0]);
+        assert_eq!(lines[1].text, "段落");
+        assert!((lines[1].confidence - 0.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn parse_json_output_skips_non_result_lines() {
+        let output = "Downloading detection model, please wait.\n\
+                      {\"boxes\": [[1, 2], [3, 2], [3, 4], [1, 4]], \"text\": \"ok\", \"confident\": 0.5}\n\
+                      {not json}\n";
+        let parsed = parse_easyocr_json_output(output);
+        assert_eq!(parsed.lines.len(), 1);
+        assert_eq!(parsed.lines[0].text, "ok");
+        assert_eq!(parsed.unparsed, vec!["{not json}"]);
+    }
+
+    #[test]
+    fn parse_easyocr_output_splits_carriage_return_progress() {
+        let output = "Progress: |█---------| 10.0% Complete\rProgress: |█████-----| 50.0% Complete\r\
+                      ([[10, 20], [100, 20], [100, 50], [10, 50]], 'first', 0.85)\r\n\
+                      Progress: |██████████| 100.0% Complete\r\
+                      ([[10, 60], [200, 60], [200, 90], [10, 90]], 'second', 0.92)\n";
+        let parsed = parse_easyocr_output(output);
+        let texts: Vec<&str> = parsed.lines.iter().map(|l| l.text.as_str()).collect();
+        assert_eq!(texts, vec!["first", "second"]);
+        assert!(parsed.unparsed.is_empty());
+    }
+
+    #[test]
+    fn parse_easyocr_output_reports_unparsed_lines() {
+        let output = "([[10, 20], [100, 20], [100, 50], [10, 50]], 'good', 0.85)\n\
+                      ([[10, 20], [100, 20]], 'truncated bbox', 0.5)\n\
+                      Traceback (most recent call last):\n\
+                      Progress: |██████----| 61.3% Complete\n\
+                      ([[10, 60], [200, 60], [200, 90], [10, 90]], 'also good', 0.92)\n";
+        let parsed = parse_easyocr_output(output);
+        let texts: Vec<&str> = parsed.lines.iter().map(|l| l.text.as_str()).collect();
+        assert_eq!(texts, vec!["good", "also good"]);
+        assert_eq!(parsed.unparsed.len(), 2);
+        assert_eq!(parsed.unparsed[1], "Traceback (most recent call last):");
+    }
+
+    #[test]
+    fn shell_quote_only_wraps_when_needed() {
+        let posix = |arg| Shell::Posix.quote(arg);
+        assert_eq!(posix("--gpu"), "--gpu");
+        assert_eq!(posix("/tmp/a b.png"), "'/tmp/a b.png'");
+        assert_eq!(posix("/tmp/it's here.png"), r"'/tmp/it'\''s here.png'");
+        assert_eq!(posix("/tmp/写真.png"), "'/tmp/写真.png'");
+        assert_eq!(posix(""), "''");
+        assert_eq!(shell_quote("--gpu"), "--gpu");
+    }
+
+    #[test]
+    fn cmd_quoting_follows_windows_argument_rules() {
+        let cmd = |arg| Shell::Cmd.quote(arg);
+        assert_eq!(cmd(r"C:\Users\me\a.png"), r"C:\Users\me\a.png");
+        assert_eq!(cmd(r"C:\Users\My Name\a b.png"), r#""C:\Users\My Name\a b.png""#);
+        assert_eq!(cmd(r"C:\My Dir\"), r#""C:\My Dir\\""#);
+        assert_eq!(cmd(r#"say "hi""#), r#""say \"hi\"""#);
+        assert_eq!(cmd(r#"a\"b"#), r#""a\\\"b""#);
+        assert_eq!(cmd("it's"), r#""it's""#);
+        assert_eq!(cmd(""), r#""""#);
+    }
+
+    #[test]
+    fn shell_command_sets_env_per_shell() {
+        let env = || vec![("CUDA_VISIBLE_DEVICES", "1".to_string())];
+        let args = || vec![OsString::from("-f"), OsString::from("a b.png")];
+        assert_eq!(
+            shell_command(Shell::Posix, env(), "easyocr".into(), args()),
+            "CUDA_VISIBLE_DEVICES=1 easyocr -f 'a b.png'"
+        );
+        assert_eq!(
+            shell_command(Shell::Cmd, env(), "easyocr".into(), args()),
+            r#"set "CUDA_VISIBLE_DEVICES=1" && easyocr -f "a b.png""#
+        );
+    }
+
+    #[test]
+    fn cached_resolution_expires_and_tracks_the_configured_exe() {
+        let at = Instant::now();
+        let cached = CachedResolution {
+            configured_exe: String::new(),
+            resolution: Resolution::Found { program: "easyocr".into(), prefix_args: vec![] },
+            at,
+        };
+        assert_eq!(cached.get("", at), Some(&cached.resolution));
+        assert_eq!(cached.get("/usr/bin/python3", at), None);
+        assert_eq!(cached.get("", at + RESOLUTION_TTL), None);
+    }
+
+    #[test]
+    fn text_only_lines_always_meet_the_confidence_threshold() {
+        let boxed = OcrLine { bbox: [[1.0, 1.0]; 4], text: "a".into(), confidence: 0.4 };
+        assert!(boxed.meets_confidence(0.4));
+        assert!(!boxed.meets_confidence(0.5));
+        let text_only = OcrLine { bbox: [[0.0; 2]; 4], text: "b".into(), confidence: 0.0 };
+        assert!(text_only.meets_confidence(0.9));
+    }
+
+    #[test]
+    fn pip_install_command_targets_the_interpreter() {
+        assert_eq!(pip_install_command("python3"), "python3 -m pip install easyocr");
+        assert_eq!(
+            pip_install_command("/opt/my env/bin/python"),
+            "'/opt/my env/bin/python' -m pip install easyocr"
+        );
+    }
+
+    fn args_for(settings: &Settings) -> Vec<String> {
+        build_ocr_args(settings, Path::new("/tmp/in.png"))
+            .iter()
+            .map(|a| a.to_string_lossy().into_owned())
+            .collect()
+    }
+
+    /// The value following `flag`, if the flag is present.
+    fn value_of<'a>(args: &'a [String], flag: &str) -> Option<&'a str> {
+        let i = args.iter().position(|a| a == flag)?;
+        args.get(i + 1).map(String::as_str)
+    }
+
+    #[test]
+    fn build_ocr_args_defaults() {
+        let args = args_for(&Settings::default());
+        assert_eq!(value_of(&args, "-f"), Some("/tmp/in.png"));
+        assert_eq!(value_of(&args, "--gpu"), Some("False"));
+        assert_eq!(value_of(&args, "--decoder"), Some("greedy"));
+        assert_eq!(value_of(&args, "--beamWidth"), Some("5"));
+        assert_eq!(value_of(&args, "--batch_size"), Some("1"));
+        assert_eq!(value_of(&args, "--text_threshold"), Some("0.7000"));
+        assert_eq!(value_of(&args, "--low_text"), Some("0.4000"));
+        assert_eq!(value_of(&args, "--link_threshold"), Some("0.4000"));
+        assert_eq!(value_of(&args, "--contrast_ths"), Some("0.1000"));
+        assert_eq!(value_of(&args, "--adjust_contrast"), Some("0.5000"));
+        assert_eq!(value_of(&args, "--min_size"), Some("20"));
+        assert_eq!(value_of(&args, "--canvas_size"), Some("2560"));
+        assert_eq!(value_of(&args, "--mag_ratio"), Some("1.0000"));
+        assert_eq!(value_of(&args, "--paragraph"), Some("False"));
+        assert_eq!(value_of(&args, "--x_ths"), Some("1.0000"));
+        assert_eq!(value_of(&args, "--y_ths"), Some("0.5000"));
+        assert_eq!(value_of(&args, "--quantize"), Some("True"));
+        assert_eq!(value_of(&args, "--add_margin"), Some("0.1000"));
+        assert_eq!(value_of(&args, "--detail"), Some("1"));
+        assert_eq!(
+            value_of(&args, "--workers"),
+            Some(effective_workers(0).to_string().as_str())
+        );
+        for absent in ["--rotation_info", "--output_format", "--model_storage_directory", "--verbose"] {
+            assert!(!args.iter().any(|a| a == absent), "{absent} should be omitted");
+        }
+    }
+
+    #[test]
+    fn build_ocr_args_reflects_toggles() {
+        let settings = Settings {
+            gpu: true,
+            paragraph: true,
+            json_output: true,
+            workers: 3,
+            rotation_info: "90, 270".into(),
+            quiet: true,
+            ..Settings::default()
+        };
+        let args = args_for(&settings);
+        assert_eq!(value_of(&args, "--gpu"), Some("True"));
+        assert_eq!(value_of(&args, "--verbose"), Some("False"));
+        assert_eq!(value_of(&args, "--paragraph"), Some("True"));
+        assert_eq!(value_of(&args, "--workers"), Some("3"));
+        assert_eq!(value_of(&args, "--output_format"), Some("json"));
+        let i = args.iter().position(|a| a == "--rotation_info").unwrap();
+        assert_eq!(args[i + 1..i + 3], ["90", "270"]);
+
+        // JSON output is meaningless without detail, so it is dropped.
+        let args = args_for(&Settings { detail: false, ..settings });
+        assert_eq!(value_of(&args, "--detail"), Some("0"));
+        assert!(!args.iter().any(|a| a == "--output_format"));
+    }
+
+    #[test]
+    fn build_ocr_args_passes_character_sets_verbatim() {
+        assert!(!args_for(&Settings::default()).iter().any(|a| a.starts_with("--allowlist")));
+        let settings = Settings {
+            allowlist: "-0123, 456".into(),
+            blocklist: "O".into(),
+            ..Settings::default()
+        };
+        let args = args_for(&settings);
+        assert!(args.contains(&"--allowlist=-0123, 456".to_string()));
+        assert!(args.contains(&"--blocklist=O".to_string()));
+    }
+
+    #[test]
+    fn build_ocr_args_appends_extra_args() {
+        let settings = Settings {
+            extra_args: "--foo 1 --bar 'a b'".into(),
+            ..Settings::default()
+        };
+        let args = args_for(&settings);
+        assert_eq!(args[args.len() - 4..], ["--foo", "1", "--bar", "a b"]);
+    }
+
+    #[test]
+    fn split_args_follows_shell_quoting() {
+        assert_eq!(split_args("  ").unwrap(), Vec::<String>::new());
+        assert_eq!(split_args("a  b\tc").unwrap(), ["a", "b", "c"]);
+        assert_eq!(split_args(r#"'it''s' "x \"y\" \n" a\ b ''"#).unwrap(), [
+            "its",
+            r#"x "y" \n"#,
+            "a b",
+            "",
+        ]);
+        assert!(split_args("'open").is_err());
+        assert!(split_args("\"open").is_err());
+        assert!(split_args("end\\").is_err());
+    }
+
+    #[test]
+    fn build_ocr_args_passes_every_language() {
+        let settings = Settings {
+            languages: "ru, uk ,en".into(),
+            ..Settings::default()
+        };
+        let args = args_for(&settings);
+        let i = args.iter().position(|a| a == "-l").unwrap();
+        assert_eq!(args[i + 1..i + 4], ["ru", "uk", "en"]);
+        assert!(args[i + 4].starts_with('-'));
+    }
+
+    #[test]
+    fn effective_model_dir_prefers_the_setting() {
+        let settings = Settings {
+            model_storage_directory: "/srv/models".into(),
+            ..Settings::default()
+        };
+        assert_eq!(effective_model_dir(&settings), Path::new("/srv/models"));
+        assert!(effective_model_dir(&Settings::default()).ends_with("model"));
+    }
+
+    #[test]
+    fn build_ocr_args_expands_model_dir() {
+        let settings = Settings {
+            model_storage_directory: "~/models".into(),
+            ..Settings::default()
+        };
+        let args = build_ocr_args(&settings, Path::new("in.png"));
+        let i = args.iter().position(|a| a == "--model_storage_directory").unwrap();
+        assert_eq!(args[i + 1], OsString::from(expand_home_dir("~/models")));
+        if std::env::var("HOME").is_ok_and(|h| !h.is_empty()) {
+            assert!(!args[i + 1].to_string_lossy().starts_with('~'));
+        }
+    }
+
+    #[test]
+    fn empty_run_note_only_for_runs_without_lines() {
+        let stderr = "Progress: |████| 100.0% Complete\n\
+                      Neither CUDA nor MPS are available - defaulting to CPU.\n\n\
+                      UserWarning: image is too small\n";
+        assert_eq!(
+            empty_run_note(&[], stderr).as_deref(),
+            Some("Neither CUDA nor MPS are available - defaulting to CPU.\nUserWarning: image is too small")
+        );
+        assert_eq!(empty_run_note(&[], "  \n"), None);
+        let found = OcrLine { bbox: [[0.0; 2]; 4], text: "x".into(), confidence: 0.0 };
+        assert_eq!(empty_run_note(&[found], stderr), None);
+    }
+
+    #[test]
+    fn parse_version_probe_reads_tagged_lines() {
+        let versions = parse_version_probe("python 3.11.4\nsome warning\neasyocr 1.7.1\n");
+        assert_eq!(versions.python.as_deref(), Some("3.11.4"));
+        assert_eq!(versions.easyocr.as_deref(), Some("1.7.1"));
+        let versions = parse_version_probe("python 3.9.2\n");
+        assert_eq!(versions, Versions { python: Some("3.9.2".into()), easyocr: None });
+        assert_eq!(parse_version_probe(""), Versions::default());
+    }
+
+    #[test]
+    fn parse_cuda_probe_reads_the_last_line() {
+        assert_eq!(parse_cuda_probe("True\n"), Some(true));
+        assert_eq!(parse_cuda_probe("UserWarning: CUDA initialization\nFalse\n\n"), Some(false));
+        assert_eq!(parse_cuda_probe(""), None);
+        assert_eq!(parse_cuda_probe("Traceback"), None);
+    }
+
+    #[test]
+    fn gpu_env_selects_the_configured_device() {
+        let mut settings = Settings { gpu: true, gpu_device: "cuda:1".into(), ..Settings::default() };
+        assert_eq!(gpu_env(&settings), Some(("CUDA_VISIBLE_DEVICES", "1".to_string())));
+        settings.gpu_device = " 0,2 ".into();
+        assert_eq!(gpu_env(&settings), Some(("CUDA_VISIBLE_DEVICES", "0,2".to_string())));
+        settings.gpu_device.clear();
+        assert_eq!(gpu_env(&settings), None);
+        settings.gpu_device = "1".into();
+        settings.gpu = false;
+        assert_eq!(gpu_env(&settings), None);
+    }
+
+    #[test]
+    fn model_mirror_runs_the_cli_from_python() {
+        let image = Path::new("a.png");
+        let plain = Settings::default();
+        let (program, args) = invocation("easyocr".into(), vec![], &plain, image);
+        assert_eq!(program, "easyocr");
+        assert_eq!(args[0], "-l");
+        assert!(child_env(&plain).is_empty());
+
+        let mirrored = Settings { model_download_url: " https://mirror.example/ ".into(), ..plain };
+        let prefix = vec!["-m".to_string(), "easyocr.cli".to_string()];
+        let (program, args) = invocation("python3".into(), prefix, &mirrored, image);
+        assert_eq!(program, "python3");
+        assert_eq!(args[0], "-c");
+        let script = args[1].to_string_lossy();
+        assert!(script.contains("EASYOCR_MODEL_MIRROR") && script.ends_with("main()\n"));
+        assert_eq!(args[2], "-l");
+        assert_eq!(
+            child_env(&mirrored),
+            [("EASYOCR_MODEL_MIRROR", "https://mirror.example/".to_string())]
+        );
+    }
+
+    #[test]
+    fn rotation_runs_the_cli_from_python() {
+        let image = Path::new("a.png");
+        let rotated = Settings { rotation_info: "90".into(), ..Settings::default() };
+        let prefix = vec!["-m".to_string(), "easyocr.cli".to_string()];
+        let (program, args) = invocation("python3".into(), prefix, &rotated, image);
+        assert_eq!(program, "python3");
+        assert_eq!(args[0], "-c");
+        let script = args[1].to_string_lossy();
+        assert!(script.contains("--rotation_info") && !script.contains("EASYOCR_MODEL_MIRROR"));
+        assert!(script.ends_with("main()\n"));
+        let i = args.iter().position(|a| a == "--rotation_info").unwrap();
+        assert_eq!(args[i + 1], "90");
+    }
+
+    #[test]
+    fn build_detect_args_passes_options_as_json() {
+        let settings = Settings {
+            languages: "ja, en".into(),
+            gpu: false,
+            ..Settings::default()
+        };
+        let args = build_detect_args(&settings, Path::new("in.png"));
+        assert_eq!(args.len(), 3);
+        assert_eq!(args[0], "-c");
+        let options: serde_json::Value =
+            serde_json::from_str(&args[2].to_string_lossy()).unwrap();
+        assert_eq!(options["lang"], serde_json::json!(["ja", "en"]));
+        assert_eq!(options["gpu"], false);
+        assert_eq!(options["image"], "in.png");
+        assert!(options["model_dir"].is_null());
+        assert_eq!(options["canvas_size"], settings.canvas_size);
+    }
+
+    #[test]
+    fn parse_detect_output_reads_bare_boxes() {
+        let out = "Downloading detection model\n\
+                   [[1.0, 2.0], [30.0, 2.0], [30.0, 12.5], [1.0, 12.5]]\n\
+                   [[0, 0], [5, 1], [5, 6], [0, 5]]\n";
+        let parsed = parse_detect_output(out);
+        assert_eq!(parsed.lines.len(), 2);
+        assert!(parsed.lines.iter().all(OcrLine::is_detection_only));
+        assert_eq!(parsed.lines[0].bbox[2], [30.0, 12.5]);
+        assert!(parsed.lines[1].meets_confidence(0.9));
+        assert_eq!(parsed.unparsed, ["Downloading detection model"]);
+    }
+
+    #[test]
+    fn parse_server_reply_reads_lines_and_errors() {
+        let reply = r#"{"lines": [[[[1, 2], [9, 2], [9, 8], [1, 8]], "Hi", 0.75]]}"#;
+        let lines = parse_server_reply(reply).unwrap().unwrap();
+        assert_eq!(lines.len(), 1);
+        assert_eq!((lines[0].text.as_str(), lines[0].confidence), ("Hi", 0.75));
+        assert_eq!(lines[0].bbox[2], [9.0, 8.0]);
+        let failed = parse_server_reply(r#"{"error": "Traceback ...\nValueError"}"#).unwrap();
+        assert!(failed.unwrap_err().contains("ValueError"));
+        assert!(parse_server_reply(r#"{"lines": []}"#).unwrap().unwrap().is_empty());
+        assert!(parse_server_reply("Using CPU. Note: This module is much faster with a GPU.").is_none());
+    }
+
+    #[test]
+    fn server_request_mirrors_cli_flags() {
+        let mut settings = Settings {
+            allowlist: "0123".into(),
+            vertical_text: true,
+            ..Settings::default()
+        };
+        let request = server_request(&settings, Path::new("a.png"));
+        let options = &request["options"];
+        assert_eq!(request["image"], "a.png");
+        assert_eq!(options["allowlist"], "0123");
+        assert!(options["blocklist"].is_null());
+        assert_eq!(options["rotation_info"], serde_json::json!([90, 270]));
+        assert_eq!(options["detail"], 1);
+
+        settings.persistent_server = true;
+        assert!(uses_server(&settings));
+        settings.extra_args = "--download_enabled False".into();
+        assert!(!uses_server(&settings));
+    }
+
+    #[test]
+    fn shebang_interpreter_handles_env() {
+        assert_eq!(shebang_interpreter("#!/usr/bin/python3\n").as_deref(), Some("/usr/bin/python3"));
+        assert_eq!(shebang_interpreter("#!/usr/bin/env python3").as_deref(), Some("python3"));
+        assert_eq!(shebang_interpreter("#!/usr/bin/env -S python3 -u").as_deref(), Some("python3"));
+        assert_eq!(shebang_interpreter("import sys"), None);
+    }
+
+    fn boxed(text: &str, x: f32, y: f32) -> OcrLine {
+        OcrLine {
+            bbox: [[x, y], [x + 50.0, y], [x + 50.0, y + 20.0], [x, y + 20.0]],
+            text: text.into(),
+            confidence: 0.9,
+        }
+    }
+
+    #[test]
+    fn sort_reading_order_groups_uneven_rows() {
+        let mut lines = vec![
+            boxed("d", 200.0, 62.0),
+            boxed("b", 200.0, 8.0),
+            boxed("c", 10.0, 60.0),
+            boxed("a", 10.0, 14.0),
+        ];
+        sort_reading_order(&mut lines, 0.5);
+        let texts: Vec<&str> = lines.iter().map(|l| l.text.as_str()).collect();
+        assert_eq!(texts, ["a", "b", "c", "d"]);
+
+        // With no tolerance, the slightly higher "b" starts its own row.
+        sort_reading_order(&mut lines, 0.0);
+        let texts: Vec<&str> = lines.iter().map(|l| l.text.as_str()).collect();
+        assert_eq!(texts, ["b", "a", "c", "d"]);
+    }
+
+    #[test]
+    fn sort_vertical_order_reads_columns_right_to_left() {
+        let column = |text: &str, x: f32, y: f32| OcrLine {
+            bbox: [[x, y], [x + 20.0, y], [x + 20.0, y + 80.0], [x, y + 80.0]],
+            text: text.into(),
+            confidence: 0.9,
+        };
+        let mut lines = vec![
+            column("c", 10.0, 5.0),
+            column("b", 103.0, 100.0),
+            column("a", 100.0, 10.0),
+        ];
+        sort_vertical_order(&mut lines, 0.5);
+        let texts: Vec<&str> = lines.iter().map(|l| l.text.as_str()).collect();
+        assert_eq!(texts, ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn vertical_text_adds_sideways_rotations() {
+        let settings = Settings {
+            vertical_text: true,
+            rotation_info: "270, 180".into(),
+            ..Settings::default()
+        };
+        let args = args_for(&settings);
+        let i = args.iter().position(|a| a == "--rotation_info").unwrap();
+        assert_eq!(args[i + 1..i + 4], ["270", "180", "90"]);
+    }
+}
@@ -0,0 +1,178 @@
+use crate::settings::Settings;
+use image::{imageops, RgbaImage};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Image clean-up applied before EasyOCR sees the image.  The preview keeps
+/// showing the original unless the user asks for the processed version.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Preprocess {
+    pub grayscale: bool,
+    pub stretch_contrast: bool,
+    pub upscale: bool,
+    pub downscale: bool,
+}
+
+/// Factor used by `Preprocess::upscale`, and divisor used by
+/// `Preprocess::downscale`.
+const UPSCALE: u32 = 2;
+
+/// Above this many pixels CPU recognition takes minutes, so the GUI
+/// suggests downscaling.
+pub const LARGE_IMAGE_PIXELS: u64 = 16_000_000;
+
+/// Share of the darkest and brightest pixels ignored when finding the range
+/// to stretch, so a few specks don't defeat the stretch.
+const STRETCH_CLIP: f32 = 0.01;
+
+impl Preprocess {
+    pub fn from_settings(settings: &Settings) -> Self {
+        Self {
+            grayscale: settings.preprocess_grayscale,
+            stretch_contrast: settings.preprocess_contrast,
+            upscale: settings.preprocess_upscale,
+            downscale: settings.preprocess_downscale,
+        }
+    }
+
+    pub fn is_noop(&self) -> bool {
+        !self.grayscale && !self.stretch_contrast && !self.upscale && !self.downscale
+    }
+
+    /// How much larger the processed image is than the original; bounding
+    /// boxes reported for it are divided by this.
+    pub fn scale(&self) -> f32 {
+        if self.upscale {
+            UPSCALE as f32
+        } else if self.downscale {
+            1.0 / UPSCALE as f32
+        } else {
+            1.0
+        }
+    }
+
+    pub fn apply(&self, mut img: RgbaImage) -> RgbaImage {
+        if self.grayscale {
+            for p in img.pixels_mut() {
+                let l = luma(p.0);
+                p.0 = [l, l, l, p.0[3]];
+            }
+        }
+        if self.stretch_contrast {
+            stretch_contrast(&mut img);
+        }
+        if self.upscale {
+            img = imageops::resize(
+                &img,
+                img.width() * UPSCALE,
+                img.height() * UPSCALE,
+                imageops::FilterType::CatmullRom,
+            );
+        } else if self.downscale {
+            img = imageops::resize(
+                &img,
+                (img.width() / UPSCALE).max(1),
+                (img.height() / UPSCALE).max(1),
+                imageops::FilterType::Triangle,
+            );
+        }
+        img
+    }
+
+    /// Writes the processed version of `path` to a fresh temp file and
+    /// returns its path.  The caller removes it once the run is over.
+    pub fn prepare_for_ocr(&self, path: &Path) -> Result<PathBuf, String> {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let img = image::open(path).map_err(|e| e.to_string())?.to_rgba8();
+        let out = std::env::temp_dir().join(format!(
+            "easyocr_gui_pre_{}_{}.png",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        self.apply(img).save(&out).map_err(|e| e.to_string())?;
+        Ok(out)
+    }
+}
+
+/// Rec. 601 luma of an RGBA pixel.
+fn luma([r, g, b, _]: [u8; 4]) -> u8 {
+    (0.299 * r as f32 + 0.587 * g as f32 + 0.114 * b as f32).round() as u8
+}
+
+/// Linearly maps the luma range between the clipped percentiles onto 0–255,
+/// scaling all three channels alike so colours don't shift.
+fn stretch_contrast(img: &mut RgbaImage) {
+    let mut histogram = [0usize; 256];
+    for p in img.pixels() {
+        histogram[luma(p.0) as usize] += 1;
+    }
+    // Luma of the `n`-th darkest pixel.
+    let level_of = |n: usize| {
+        let mut seen = 0;
+        (0..256)
+            .find(|&level| {
+                seen += histogram[level];
+                seen > n
+            })
+            .unwrap_or(255) as f32
+    };
+    let total = img.pixels().len();
+    let clip = (total as f32 * STRETCH_CLIP) as usize;
+    let (low, high) = (level_of(clip), level_of(total.saturating_sub(clip + 1)));
+    if high - low < 1.0 {
+        return;
+    }
+    let gain = 255.0 / (high - low);
+    for p in img.pixels_mut() {
+        for c in &mut p.0[..3] {
+            *c = ((*c as f32 - low) * gain).clamp(0.0, 255.0) as u8;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Preprocess;
+    use image::{Rgba, RgbaImage};
+
+    #[test]
+    fn stretch_contrast_spans_the_full_range() {
+        let mut img = RgbaImage::new(10, 1);
+        for (x, _, p) in img.enumerate_pixels_mut() {
+            let v = 100 + x as u8 * 5;
+            *p = Rgba([v, v, v, 255]);
+        }
+        let opts = Preprocess { grayscale: false, stretch_contrast: true, upscale: false, downscale: false };
+        let out = opts.apply(img);
+        assert_eq!(out.get_pixel(0, 0).0[0], 0);
+        assert_eq!(out.get_pixel(9, 0).0[0], 255);
+    }
+
+    #[test]
+    fn grayscale_and_upscale() {
+        let img = RgbaImage::from_pixel(3, 2, Rgba([255, 0, 0, 255]));
+        let opts = Preprocess { grayscale: true, stretch_contrast: false, upscale: true, downscale: false };
+        let out = opts.apply(img);
+        assert_eq!(out.dimensions(), (6, 4));
+        let [r, g, b, a] = out.get_pixel(2, 2).0;
+        assert!(r == g && g == b);
+        assert_eq!(a, 255);
+        assert_eq!(opts.scale(), 2.0);
+    }
+
+    #[test]
+    fn upscale_wins_over_downscale() {
+        let img = RgbaImage::new(3, 2);
+        let opts = Preprocess { grayscale: false, stretch_contrast: false, upscale: true, downscale: true };
+        assert_eq!(opts.apply(img).dimensions(), (6, 4));
+        assert_eq!(opts.scale(), 2.0);
+    }
+
+    #[test]
+    fn downscale_halves_each_side() {
+        let img = RgbaImage::new(7, 4);
+        let opts = Preprocess { grayscale: false, stretch_contrast: false, upscale: false, downscale: true };
+        assert_eq!(opts.apply(img).dimensions(), (3, 2));
+        assert_eq!(opts.scale(), 0.5);
+    }
+}
@@ -0,0 +1,838 @@
+use serde::{Deserialize, Serialize};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Once;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum UiLanguage {
+    #[default]
+    Chinese,
+    English,
+    Japanese,
+    Spanish,
+}
+
+impl UiLanguage {
+    pub fn all() -> &'static [UiLanguage] {
+        &[
+            UiLanguage::Chinese,
+            UiLanguage::English,
+            UiLanguage::Japanese,
+            UiLanguage::Spanish,
+        ]
+    }
+
+    /// The language's name in itself, so it can be found from any locale.
+    pub fn native_name(&self) -> &'static str {
+        match self {
+            UiLanguage::Chinese => "中文",
+            UiLanguage::English => "English",
+            UiLanguage::Japanese => "日本語",
+            UiLanguage::Spanish => "Español",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum Theme {
+    #[default]
+    Dark,
+    Light,
+    System,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum Decoder {
+    #[default]
+    Greedy,
+    BeamSearch,
+    WordBeamSearch,
+}
+
+impl Decoder {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Decoder::Greedy => "greedy",
+            Decoder::BeamSearch => "beamsearch",
+            Decoder::WordBeamSearch => "wordbeamsearch",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Decoder::Greedy => "Greedy (Fast)",
+            Decoder::BeamSearch => "Beam Search (Accurate)",
+            Decoder::WordBeamSearch => "Word Beam Search (Most Accurate)",
+        }
+    }
+
+    pub fn all() -> &'static [Decoder] {
+        &[Decoder::Greedy, Decoder::BeamSearch, Decoder::WordBeamSearch]
+    }
+}
+
+/// File format pasted and captured images are written in for EasyOCR.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum TempFormat {
+    /// Lossless, but slow to write for large screenshots
+    #[default]
+    Png,
+    /// Much faster and smaller; fine for most text
+    Jpeg,
+}
+
+impl TempFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            TempFormat::Png => "png",
+            TempFormat::Jpeg => "jpg",
+        }
+    }
+}
+
+/// Main window placement remembered between launches, in points.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct WindowGeometry {
+    /// Outer top-left corner; `None` where the platform doesn't report it
+    /// (e.g. Wayland), in which case the window manager places the window.
+    pub position: Option<[f32; 2]>,
+    /// Inner (client area) size
+    pub size: [f32; 2],
+    pub maximized: bool,
+}
+
+/// How many entries "Open recent" keeps.
+pub const RECENT_FILES_MAX: usize = 10;
+
+/// Upper bound for `batch_concurrency`; past this, model memory rather than
+/// CPU is what runs out.
+pub const MAX_BATCH_CONCURRENCY: u32 = 8;
+
+/// All EasyOCR reader and readtext parameters exposed in the settings UI.
+///
+/// Missing fields fall back to their defaults so that settings files written
+/// by older versions keep loading after new options are added.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// Comma-separated language codes, e.g. "en,ch_sim"
+    pub languages: String,
+    /// Use GPU acceleration
+    pub gpu: bool,
+    /// CUDA device to run on, e.g. "1" or "cuda:1" (empty = default)
+    pub gpu_device: String,
+    /// Number of parallel CPU workers (0 = auto)
+    pub workers: u32,
+    /// Images of a batch run at once.  Each is its own EasyOCR process with
+    /// its own copy of the models, so memory grows with every one
+    pub batch_concurrency: u32,
+    /// Kill the easyocr process after this many seconds (0 = no timeout)
+    pub timeout_secs: u32,
+    /// Decoder algorithm
+    #[serde(deserialize_with = "or_default")]
+    pub decoder: Decoder,
+    /// Beam width for beam-search decoders
+    pub beam_width: u32,
+    /// Batch size for recognition
+    pub batch_size: u32,
+    /// Minimum text box size in pixels
+    pub min_size: u32,
+    /// Longest image side fed to the detector; larger images are scaled down
+    pub canvas_size: u32,
+    /// Image magnification applied before detection
+    pub mag_ratio: f32,
+    /// Text confidence threshold
+    pub text_threshold: f32,
+    /// Text low-bound score
+    pub low_text: f32,
+    /// Link confidence threshold
+    pub link_threshold: f32,
+    /// Contrast threshold — boxes below this get processed twice
+    pub contrast_ths: f32,
+    /// Target contrast for low-contrast boxes
+    pub adjust_contrast: f32,
+    /// Combine results into paragraphs
+    pub paragraph: bool,
+    /// Paragraph mode: merge boxes this many box heights apart horizontally
+    pub x_ths: f32,
+    /// Paragraph mode: merge boxes this many box heights apart vertically
+    pub y_ths: f32,
+    /// Paragraph mode: turn line breaks inside a paragraph into spaces when
+    /// copying, keeping blank lines between paragraphs
+    pub join_paragraph_lines: bool,
+    /// Use dynamic quantization
+    pub quantize: bool,
+    /// Extend bounding boxes by this margin ratio
+    pub add_margin: f32,
+    /// Comma-separated rotation angles to try, e.g. "90,180,270"
+    pub rotation_info: String,
+    /// Only recognise these characters; empty = no restriction
+    pub allowlist: String,
+    /// Never recognise these characters
+    pub blocklist: String,
+    /// Ask the CLI for `--output_format json` instead of Python reprs
+    pub json_output: bool,
+    /// Request positions and confidences (`--detail 1`); off = text only
+    pub detail: bool,
+    /// Only find text regions, skipping recognition
+    pub detect_only: bool,
+    /// Keep one Python process with the model loaded between runs instead
+    /// of starting the CLI for every image
+    pub persistent_server: bool,
+    /// Convert to grayscale before OCR
+    pub preprocess_grayscale: bool,
+    /// Stretch the brightness range to full contrast before OCR
+    pub preprocess_contrast: bool,
+    /// Enlarge the image 2× before OCR
+    pub preprocess_upscale: bool,
+    /// Shrink the image to half size before OCR; ignored while upscaling
+    pub preprocess_downscale: bool,
+    /// Pass `--verbose False`, hiding EasyOCR's download progress bars
+    pub quiet: bool,
+    /// Reorder results top-to-bottom, left-to-right
+    pub sort_by_position: bool,
+    /// Text runs in vertical columns (traditional CJK): also try boxes
+    /// rotated by 90° and 270°, and read columns right-to-left
+    pub vertical_text: bool,
+    /// Boxes whose tops differ by less than this fraction of the median box
+    /// height count as one row when sorting
+    pub row_tolerance: f32,
+    /// Optional custom model storage directory
+    pub model_storage_directory: String,
+    /// Mirror to download models from in place of EasyOCR's GitHub
+    /// releases; it must keep their `<release>/<file>.zip` layout
+    pub model_download_url: String,
+    /// Optional custom easyocr executable path
+    pub easyocr_exe: String,
+    /// Extra CLI arguments appended after the GUI's own, split like a shell
+    pub extra_args: String,
+    /// UI display language
+    #[serde(deserialize_with = "or_default")]
+    pub ui_language: UiLanguage,
+    /// Dark, light or follow the OS
+    #[serde(deserialize_with = "or_default")]
+    pub theme: Theme,
+    /// Directory the open-image and open-folder dialogs start in
+    pub last_open_dir: String,
+    /// Directory the export dialog starts in
+    pub last_export_dir: String,
+    /// Index of the display to capture in `screenshots::Screen::all()`
+    pub screen_index: usize,
+    /// Maximum number of runs kept in the history tab
+    pub history_limit: u32,
+    /// Digits after the decimal point in displayed confidences (0–3)
+    pub confidence_decimals: u8,
+    /// Show each result line's guessed script (Latin, CJK, …) next to it
+    pub show_script_tags: bool,
+    /// Start OCR as soon as an image is opened, pasted, dropped or captured
+    pub auto_run: bool,
+    /// Put the results on the clipboard, in the chosen copy format, as soon
+    /// as a run finishes
+    pub auto_copy: bool,
+    /// Listen for `global_hotkey` while the window is unfocused
+    pub global_hotkey_enabled: bool,
+    /// System-wide shortcut that OCRs a screen region to the clipboard
+    pub global_hotkey: String,
+    /// Where pasted and captured images are kept; empty = temp files removed on exit
+    pub save_captures_dir: String,
+    /// Format pasted and captured images are saved in
+    #[serde(deserialize_with = "or_default")]
+    pub temp_format: TempFormat,
+    /// JPEG quality, 1–100, when `temp_format` is JPEG
+    pub temp_jpeg_quality: u8,
+    /// Recently opened image files, most recent first
+    pub recent_files: Vec<String>,
+    /// Window placement at the last exit; `None` = default size
+    pub window: Option<WindowGeometry>,
+    /// Name of the tab that was open at the last exit
+    #[serde(deserialize_with = "or_default")]
+    pub tab: String,
+    /// The first-run tips have been dismissed
+    pub seen_onboarding: bool,
+    /// Ids of the settings sections folded away
+    pub collapsed_sections: Vec<String>,
+    /// One-click language lists shown above the language picker
+    pub language_shortcuts: Vec<LanguageShortcut>,
+}
+
+/// A named language list, e.g. "中英" for `ch_sim,en`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LanguageShortcut {
+    pub label: String,
+    pub languages: String,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            languages: "ch_sim,en".to_string(),
+            gpu: false,
+            gpu_device: String::new(),
+            workers: 0,
+            batch_concurrency: 1,
+            timeout_secs: 300,
+            decoder: Decoder::Greedy,
+            beam_width: 5,
+            batch_size: 1,
+            min_size: 20,
+            canvas_size: 2560,
+            mag_ratio: 1.0,
+            text_threshold: 0.7,
+            low_text: 0.4,
+            link_threshold: 0.4,
+            contrast_ths: 0.1,
+            adjust_contrast: 0.5,
+            paragraph: false,
+            x_ths: 1.0,
+            y_ths: 0.5,
+            join_paragraph_lines: false,
+            quantize: true,
+            add_margin: 0.1,
+            rotation_info: String::new(),
+            allowlist: String::new(),
+            blocklist: String::new(),
+            json_output: false,
+            detail: true,
+            detect_only: false,
+            persistent_server: false,
+            preprocess_grayscale: false,
+            preprocess_contrast: false,
+            preprocess_upscale: false,
+            preprocess_downscale: false,
+            quiet: false,
+            sort_by_position: false,
+            vertical_text: false,
+            row_tolerance: 0.5,
+            model_storage_directory: String::new(),
+            model_download_url: String::new(),
+            easyocr_exe: String::new(),
+            extra_args: String::new(),
+            ui_language: UiLanguage::Chinese,
+            theme: Theme::Dark,
+            last_open_dir: String::new(),
+            last_export_dir: String::new(),
+            screen_index: 0,
+            history_limit: 50,
+            confidence_decimals: 1,
+            show_script_tags: false,
+            auto_run: false,
+            auto_copy: false,
+            global_hotkey_enabled: false,
+            global_hotkey: "Ctrl+Shift+O".into(),
+            save_captures_dir: String::new(),
+            temp_format: TempFormat::Png,
+            temp_jpeg_quality: 90,
+            recent_files: Vec::new(),
+            window: None,
+            tab: String::new(),
+            seen_onboarding: false,
+            collapsed_sections: Vec::new(),
+            language_shortcuts: Vec::new(),
+        }
+    }
+}
+
+impl Settings {
+    /// Directory holding `settings.json` and the other persisted files.
+    /// The first call moves files left in the pre-`directories` location.
+    pub fn config_dir() -> Option<PathBuf> {
+        static MIGRATE: Once = Once::new();
+        let dir = directories::ProjectDirs::from("", "", APP_DIR)?
+            .config_dir()
+            .to_path_buf();
+        MIGRATE.call_once(|| {
+            if let Some(old) = legacy_config_dir() {
+                migrate_config(&old, &dir);
+            }
+        });
+        Some(dir)
+    }
+
+    pub fn config_path() -> Option<PathBuf> {
+        Self::config_dir().map(|p| p.join("settings.json"))
+    }
+
+    pub fn load() -> Self {
+        Self::config_path()
+            .and_then(|p| std::fs::read_to_string(p).ok())
+            .and_then(|s| Self::from_json(&s).ok())
+            .unwrap_or_default()
+    }
+
+    /// Parses a settings file and [`validate`](Self::validate)s it.
+    pub fn from_json(text: &str) -> serde_json::Result<Self> {
+        let mut settings: Settings = serde_json::from_str(text)?;
+        settings.validate();
+        Ok(settings)
+    }
+
+    /// Moves `path` to the front of `recent_files`, dropping duplicates and
+    /// anything beyond `RECENT_FILES_MAX`.
+    pub fn push_recent(&mut self, path: &str) {
+        self.recent_files.retain(|p| p != path);
+        self.recent_files.insert(0, path.to_string());
+        self.recent_files.truncate(RECENT_FILES_MAX);
+    }
+
+    /// Apply `update` to the in-memory settings and persist the same change
+    /// to the settings file, leaving any other unsaved edits in `self` alone.
+    pub fn remember(&mut self, update: impl Fn(&mut Settings)) {
+        update(self);
+        let mut stored = Self::load();
+        update(&mut stored);
+        let _ = stored.save();
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        write_config_file(Self::config_path(), self)
+    }
+
+    /// Pulls every numeric option into the range the settings tab offers,
+    /// so a hand-edited or shared file can't hand the CLI nonsense.  Enum
+    /// options this version doesn't know were already reset to their
+    /// defaults while reading.
+    pub fn validate(&mut self) {
+        let defaults = Settings::default();
+        let clamp_f32 = |v: f32, min: f32, max: f32, default: f32| {
+            if v.is_nan() {
+                default
+            } else {
+                v.clamp(min, max)
+            }
+        };
+        self.text_threshold = clamp_f32(self.text_threshold, 0.0, 1.0, defaults.text_threshold);
+        self.low_text = clamp_f32(self.low_text, 0.0, 1.0, defaults.low_text);
+        self.link_threshold = clamp_f32(self.link_threshold, 0.0, 1.0, defaults.link_threshold);
+        self.contrast_ths = clamp_f32(self.contrast_ths, 0.0, 1.0, defaults.contrast_ths);
+        self.adjust_contrast = clamp_f32(self.adjust_contrast, 0.0, 1.0, defaults.adjust_contrast);
+        self.mag_ratio = clamp_f32(self.mag_ratio, 0.5, 5.0, defaults.mag_ratio);
+        self.add_margin = clamp_f32(self.add_margin, 0.0, 0.5, defaults.add_margin);
+        self.x_ths = clamp_f32(self.x_ths, 0.0, 5.0, defaults.x_ths);
+        self.y_ths = clamp_f32(self.y_ths, 0.0, 5.0, defaults.y_ths);
+        self.row_tolerance = clamp_f32(self.row_tolerance, 0.0, 2.0, defaults.row_tolerance);
+        self.workers = self.workers.min(64);
+        self.batch_concurrency = self.batch_concurrency.clamp(1, MAX_BATCH_CONCURRENCY);
+        self.timeout_secs = self.timeout_secs.min(3600);
+        self.beam_width = self.beam_width.clamp(1, 50);
+        self.batch_size = self.batch_size.clamp(1, 64);
+        self.min_size = self.min_size.clamp(1, 200);
+        self.canvas_size = self.canvas_size.clamp(256, 8192);
+        self.history_limit = self.history_limit.clamp(1, 1000);
+        self.confidence_decimals = self.confidence_decimals.min(3);
+        self.temp_jpeg_quality = self.temp_jpeg_quality.clamp(1, 100);
+    }
+
+    /// Writes the options to `path` for sharing or backup.  State that only
+    /// makes sense on this machine, like the window placement and recent
+    /// files, is left out.
+    pub fn export(&self, path: &Path) -> io::Result<()> {
+        let mut shared = self.clone();
+        shared.keep_local_state(&Settings::default());
+        write_config_file(Some(path.to_path_buf()), &shared)
+    }
+
+    /// Reads a file written by `export`, validated, keeping the
+    /// machine-local state of `local`.
+    pub fn import(path: &Path, local: &Settings) -> io::Result<Settings> {
+        let text = std::fs::read_to_string(path)?;
+        let mut imported = Settings::from_json(&text)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        imported.keep_local_state(local);
+        Ok(imported)
+    }
+
+    fn keep_local_state(&mut self, from: &Settings) {
+        self.last_open_dir = from.last_open_dir.clone();
+        self.last_export_dir = from.last_export_dir.clone();
+        self.screen_index = from.screen_index;
+        self.recent_files = from.recent_files.clone();
+        self.window = from.window;
+        self.tab = from.tab.clone();
+        self.seen_onboarding = from.seen_onboarding;
+        self.collapsed_sections = from.collapsed_sections.clone();
+    }
+}
+
+/// Reads an enum option, falling back to its default for a variant this
+/// version doesn't know, so one bad value doesn't discard the whole file.
+fn or_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: Deserialize<'de> + Default,
+{
+    let value = serde_json::Value::deserialize(deserializer)?;
+    Ok(T::deserialize(value).unwrap_or_default())
+}
+
+/// A subset of `Settings` applied together by a preset.  `None` fields are
+/// left untouched.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PresetFields {
+    pub decoder: Option<Decoder>,
+    pub beam_width: Option<u32>,
+    pub min_size: Option<u32>,
+    pub text_threshold: Option<f32>,
+    pub low_text: Option<f32>,
+    pub link_threshold: Option<f32>,
+    pub contrast_ths: Option<f32>,
+    pub adjust_contrast: Option<f32>,
+    pub add_margin: Option<f32>,
+}
+
+impl PresetFields {
+    /// Capture every preset-able field of `settings`.
+    pub fn from_settings(settings: &Settings) -> Self {
+        Self {
+            decoder: Some(settings.decoder.clone()),
+            beam_width: Some(settings.beam_width),
+            min_size: Some(settings.min_size),
+            text_threshold: Some(settings.text_threshold),
+            low_text: Some(settings.low_text),
+            link_threshold: Some(settings.link_threshold),
+            contrast_ths: Some(settings.contrast_ths),
+            adjust_contrast: Some(settings.adjust_contrast),
+            add_margin: Some(settings.add_margin),
+        }
+    }
+
+    /// Copies the set fields into `settings`, then validates them: user
+    /// presets come from a hand-editable `presets.json`.
+    pub fn apply_to(&self, settings: &mut Settings) {
+        if let Some(v) = &self.decoder {
+            settings.decoder = v.clone();
+        }
+        if let Some(v) = self.beam_width {
+            settings.beam_width = v;
+        }
+        if let Some(v) = self.min_size {
+            settings.min_size = v;
+        }
+        if let Some(v) = self.text_threshold {
+            settings.text_threshold = v;
+        }
+        if let Some(v) = self.low_text {
+            settings.low_text = v;
+        }
+        if let Some(v) = self.link_threshold {
+            settings.link_threshold = v;
+        }
+        if let Some(v) = self.contrast_ths {
+            settings.contrast_ths = v;
+        }
+        if let Some(v) = self.adjust_contrast {
+            settings.adjust_contrast = v;
+        }
+        if let Some(v) = self.add_margin {
+            settings.add_margin = v;
+        }
+        settings.validate();
+    }
+}
+
+/// Presets shipped with the app; labels live in the i18n tables.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BuiltinPreset {
+    Default,
+    DenseDocument,
+    SceneText,
+    Handwriting,
+}
+
+impl BuiltinPreset {
+    pub fn all() -> &'static [BuiltinPreset] {
+        &[
+            BuiltinPreset::Default,
+            BuiltinPreset::DenseDocument,
+            BuiltinPreset::SceneText,
+            BuiltinPreset::Handwriting,
+        ]
+    }
+
+    pub fn fields(&self) -> PresetFields {
+        match self {
+            BuiltinPreset::Default => PresetFields::from_settings(&Settings::default()),
+            // Small, tightly packed print: keep faint strokes and small boxes.
+            BuiltinPreset::DenseDocument => PresetFields {
+                decoder: Some(Decoder::BeamSearch),
+                min_size: Some(10),
+                text_threshold: Some(0.6),
+                low_text: Some(0.3),
+                link_threshold: Some(0.3),
+                add_margin: Some(0.05),
+                ..Default::default()
+            },
+            // Photos of signs and labels: uneven lighting, generous margins.
+            BuiltinPreset::SceneText => PresetFields {
+                decoder: Some(Decoder::Greedy),
+                min_size: Some(20),
+                text_threshold: Some(0.7),
+                low_text: Some(0.4),
+                link_threshold: Some(0.4),
+                contrast_ths: Some(0.3),
+                adjust_contrast: Some(0.7),
+                add_margin: Some(0.15),
+                ..Default::default()
+            },
+            // Irregular strokes: lower thresholds and a wider beam.
+            BuiltinPreset::Handwriting => PresetFields {
+                decoder: Some(Decoder::WordBeamSearch),
+                beam_width: Some(10),
+                min_size: Some(10),
+                text_threshold: Some(0.5),
+                low_text: Some(0.3),
+                link_threshold: Some(0.2),
+                contrast_ths: Some(0.2),
+                adjust_contrast: Some(0.6),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// A user-defined preset, saved in `presets.json`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Preset {
+    pub name: String,
+    pub fields: PresetFields,
+}
+
+impl Preset {
+    pub fn apply_to(&self, settings: &mut Settings) {
+        self.fields.apply_to(settings);
+    }
+
+    fn path() -> Option<PathBuf> {
+        Settings::config_dir().map(|p| p.join("presets.json"))
+    }
+
+    pub fn load_user() -> Vec<Preset> {
+        Self::path()
+            .and_then(|p| std::fs::read_to_string(p).ok())
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save_user(presets: &[Preset]) -> io::Result<()> {
+        write_config_file(Self::path(), presets)
+    }
+}
+
+const APP_DIR: &str = "easyocr-gui";
+
+/// Writes `value` as pretty JSON to `path`, creating the config dir first.
+/// `path` is `None` when the platform has no config dir.
+fn write_config_file(path: Option<PathBuf>, value: &(impl Serialize + ?Sized)) -> io::Result<()> {
+    let path = path.ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "cannot determine config dir"))?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&path, serde_json::to_string_pretty(value)?)
+}
+
+/// Files that live in the config dir and move along with it.
+const CONFIG_FILES: &[&str] = &["settings.json", "presets.json", "history.json"];
+
+/// Moves each of `CONFIG_FILES` from `old` to `new`, leaving alone any that
+/// already exist in `new`.  Failures are ignored: the old files stay put and
+/// the app starts from defaults, as it would have before.
+fn migrate_config(old: &Path, new: &Path) {
+    if old == new || !old.join(CONFIG_FILES[0]).is_file() {
+        return;
+    }
+    if std::fs::create_dir_all(new).is_err() {
+        return;
+    }
+    for name in CONFIG_FILES {
+        let (from, to) = (old.join(name), new.join(name));
+        if from.is_file() && !to.exists() && std::fs::rename(&from, &to).is_err() {
+            // `rename` can't cross filesystems; fall back to copying.
+            if std::fs::copy(&from, &to).is_ok() {
+                let _ = std::fs::remove_file(&from);
+            }
+        }
+    }
+    let _ = std::fs::remove_dir(old);
+}
+
+/// Where the config dir lived before switching to `directories`.
+fn legacy_config_dir() -> Option<PathBuf> {
+    legacy_dirs_config().map(|p| p.join(APP_DIR))
+}
+
+fn legacy_dirs_config() -> Option<PathBuf> {
+    #[cfg(target_os = "windows")]
+    {
+        std::env::var("APPDATA").ok().map(PathBuf::from)
+    }
+    #[cfg(target_os = "macos")]
+    {
+        std::env::var("HOME")
+            .ok()
+            .map(|h| PathBuf::from(h).join("Library").join("Application Support"))
+    }
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    {
+        std::env::var("XDG_CONFIG_HOME")
+            .ok()
+            .map(PathBuf::from)
+            .or_else(|| std::env::var("HOME").ok().map(|h| PathBuf::from(h).join(".config")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{migrate_config, BuiltinPreset, Decoder, PresetFields, Settings, RECENT_FILES_MAX};
+
+    #[test]
+    fn validate_pulls_values_into_range() {
+        let mut settings = Settings {
+            text_threshold: 1.7,
+            low_text: -0.2,
+            link_threshold: f32::NAN,
+            mag_ratio: 40.0,
+            canvas_size: 10,
+            beam_width: 0,
+            history_limit: 0,
+            ..Settings::default()
+        };
+        settings.validate();
+        assert_eq!(settings.text_threshold, 1.0);
+        assert_eq!(settings.low_text, 0.0);
+        assert_eq!(settings.link_threshold, Settings::default().link_threshold);
+        assert_eq!(settings.mag_ratio, 5.0);
+        assert_eq!(settings.canvas_size, 256);
+        assert_eq!(settings.beam_width, 1);
+        assert_eq!(settings.history_limit, 1);
+    }
+
+    #[test]
+    fn from_json_clamps_out_of_range_values() {
+        let settings = Settings::from_json(
+            r#"{"text_threshold": 5.0, "beam_width": 0, "batch_concurrency": 99,
+                "temp_jpeg_quality": 0, "confidence_decimals": 9, "languages": "en"}"#,
+        )
+        .unwrap();
+        assert_eq!(settings.text_threshold, 1.0);
+        assert_eq!(settings.beam_width, 1);
+        assert_eq!(settings.batch_concurrency, super::MAX_BATCH_CONCURRENCY);
+        assert_eq!(settings.temp_jpeg_quality, 1);
+        assert_eq!(settings.confidence_decimals, 3);
+        assert_eq!(settings.languages, "en");
+    }
+
+    #[test]
+    fn from_json_resets_unknown_enum_values_only() {
+        let settings = Settings::from_json(
+            r#"{"decoder": "telepathy", "theme": 3, "tab": "Settings", "languages": "fr"}"#,
+        )
+        .unwrap();
+        assert_eq!(settings.decoder, Decoder::Greedy);
+        assert_eq!(settings.theme, super::Theme::Dark);
+        assert_eq!(settings.tab, "Settings");
+        assert_eq!(settings.languages, "fr");
+    }
+
+    #[test]
+    fn export_and_import_keep_local_state_apart() {
+        let path = std::env::temp_dir().join(format!("settings_export_{}.json", std::process::id()));
+        let tuned = Settings {
+            languages: "ja,en".into(),
+            text_threshold: 0.55,
+            recent_files: vec!["/home/me/secret.png".into()],
+            ..Settings::default()
+        };
+        tuned.export(&path).unwrap();
+        let written = std::fs::read_to_string(&path).unwrap();
+        assert!(!written.contains("secret.png"));
+
+        let local = Settings {
+            recent_files: vec!["/mine.png".into()],
+            seen_onboarding: true,
+            ..Settings::default()
+        };
+        let imported = Settings::import(&path, &local).unwrap();
+        assert_eq!(imported.languages, "ja,en");
+        assert_eq!(imported.text_threshold, 0.55);
+        assert_eq!(imported.recent_files, ["/mine.png"]);
+        assert!(imported.seen_onboarding);
+
+        std::fs::write(&path, "not json").unwrap();
+        let err = Settings::import(&path, &local).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn preset_only_touches_set_fields() {
+        let mut settings = Settings {
+            paragraph: true,
+            ..Settings::default()
+        };
+        let fields = PresetFields {
+            text_threshold: Some(0.25),
+            ..Default::default()
+        };
+        fields.apply_to(&mut settings);
+        assert_eq!(settings.text_threshold, 0.25);
+        assert_eq!(settings.low_text, Settings::default().low_text);
+        assert!(settings.paragraph);
+    }
+
+    #[test]
+    fn preset_values_are_validated() {
+        let mut settings = Settings::default();
+        let fields = PresetFields {
+            text_threshold: Some(7.0),
+            min_size: Some(0),
+            ..Default::default()
+        };
+        fields.apply_to(&mut settings);
+        assert_eq!(settings.text_threshold, 1.0);
+        assert_eq!(settings.min_size, 1);
+    }
+
+    #[test]
+    fn recent_files_are_deduped_and_capped() {
+        let mut settings = Settings::default();
+        for i in 0..RECENT_FILES_MAX + 2 {
+            settings.push_recent(&format!("/img/{i}.png"));
+        }
+        settings.push_recent("/img/5.png");
+        assert_eq!(settings.recent_files.len(), RECENT_FILES_MAX);
+        assert_eq!(settings.recent_files[0], "/img/5.png");
+        assert_eq!(settings.recent_files.iter().filter(|p| *p == "/img/5.png").count(), 1);
+        assert!(!settings.recent_files.contains(&"/img/0.png".to_string()));
+    }
+
+    #[test]
+    fn migrate_config_moves_files_without_overwriting() {
+        let root = std::env::temp_dir().join(format!("settings_migrate_{}", std::process::id()));
+        let (old, new) = (root.join("old"), root.join("new"));
+        std::fs::create_dir_all(&old).unwrap();
+        std::fs::create_dir_all(&new).unwrap();
+        std::fs::write(old.join("settings.json"), "old settings").unwrap();
+        std::fs::write(old.join("history.json"), "old history").unwrap();
+        std::fs::write(new.join("history.json"), "new history").unwrap();
+
+        migrate_config(&old, &new);
+        assert_eq!(std::fs::read_to_string(new.join("settings.json")).unwrap(), "old settings");
+        assert_eq!(std::fs::read_to_string(new.join("history.json")).unwrap(), "new history");
+        assert!(!old.join("settings.json").exists());
+        // The clashing file is kept, so the old dir isn't removed either.
+        assert!(old.join("history.json").exists());
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn default_preset_restores_defaults() {
+        let mut settings = Settings::default();
+        BuiltinPreset::Handwriting.fields().apply_to(&mut settings);
+        assert_eq!(settings.decoder, Decoder::WordBeamSearch);
+        BuiltinPreset::Default.fields().apply_to(&mut settings);
+        assert_eq!(
+            PresetFields::from_settings(&settings),
+            PresetFields::from_settings(&Settings::default())
+        );
+    }
+}
@@ -1,13 +1,39 @@
+use crate::camera::Camera;
+use crate::download;
+use crate::export::ExportFormat;
+use crate::history::{self, History};
+use crate::hotkey::GlobalHotkey;
 use crate::i18n;
-use crate::ocr::{self, OcrResult};
-use crate::settings::{Decoder, Settings, UiLanguage};
+use easyocr_core::lang::{self, is_cjk, LanguageProblem, Script};
+use easyocr_core::ocr::{self, OcrJob, OcrLine, OcrResult};
+use easyocr_core::FailureKind;
+use crate::pages::{self, PageKind};
+use easyocr_core::preprocess::{Preprocess, LARGE_IMAGE_PIXELS};
+use easyocr_core::settings::{
+    BuiltinPreset, Decoder, LanguageShortcut, Preset, PresetFields, Settings, TempFormat,
+    Theme, UiLanguage, WindowGeometry, MAX_BATCH_CONCURRENCY,
+};
+use crate::theme::{self, Palette};
 use egui::{
-    Color32, ColorImage, FontId, RichText, Rounding, Stroke, TextureHandle, Vec2,
+    Color32, ColorImage, FontId, Key, KeyboardShortcut, Modifiers, RichText, Rounding, Stroke,
+    TextureHandle, Vec2,
 };
-use std::path::PathBuf;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::mpsc::Receiver;
+use std::time::{Duration, Instant};
 
-#[derive(PartialEq, Clone, Copy)]
+/// File extensions offered by the open dialog and picked up by folder batches.
+const IMAGE_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "bmp", "gif", "tif", "tiff", "webp"];
+
+// `COMMAND` is Cmd on macOS and Ctrl everywhere else.
+const SHORTCUT_OPEN: KeyboardShortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::O);
+const SHORTCUT_PASTE: KeyboardShortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::V);
+const SHORTCUT_RUN: KeyboardShortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::Enter);
+const SHORTCUT_COPY: KeyboardShortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::C);
+
+#[derive(PartialEq, Clone)]
 enum SetupStatus {
     /// Background check is in progress.
     Checking,
@@ -15,43 +41,323 @@ enum SetupStatus {
     Ready,
     /// easyocr CLI could not be found.
     Missing,
+    /// A Python interpreter runs, but easyocr isn't installed for it.
+    ModuleMissing { python: String },
 }
 
 #[derive(PartialEq, Clone, Copy)]
 enum Tab {
     Ocr,
+    History,
     Settings,
 }
 
+impl Tab {
+    /// How `Settings::tab` remembers the tab.
+    fn name(self) -> &'static str {
+        match self {
+            Tab::Ocr => "Ocr",
+            Tab::History => "History",
+            Tab::Settings => "Settings",
+        }
+    }
+
+    /// The tab remembered as `name`; the OCR tab for anything else.
+    fn from_name(name: &str) -> Self {
+        [Tab::History, Tab::Settings]
+            .into_iter()
+            .find(|tab| tab.name() == name)
+            .unwrap_or(Tab::Ocr)
+    }
+}
+
 /// Holds an image that has been loaded and is ready for display + OCR.
+/// Where the image in the preview came from.
+#[derive(Clone, Copy)]
+enum ImageSource {
+    /// Opened from disk; size and format are read from the file.
+    File {
+        bytes: Option<u64>,
+        format: Option<image::ImageFormat>,
+    },
+    Clipboard,
+    /// Captured from the display with this index (0-based).
+    Screen(usize),
+    Camera,
+}
+
+/// The open camera and its latest frame, shown in place of the image.
+struct CameraView {
+    camera: Camera,
+    frame: Option<(image::RgbaImage, TextureHandle)>,
+}
+
 struct LoadedImage {
     path: PathBuf,
+    source: ImageSource,
     texture: TextureHandle,
     width: u32,
     height: u32,
+    /// Preview magnification relative to fit-to-panel (1.0 = fit).
+    zoom: f32,
+    /// Offset of the image centre from the panel centre, in screen points.
+    pan: Vec2,
+    /// Preview of what EasyOCR is sent, built on demand for these options.
+    processed: Option<(Preprocess, TextureHandle)>,
+    /// Show `processed` instead of the original.
+    show_processed: bool,
+    /// Region drawn with Shift+drag, in image pixels; Copy and Export keep
+    /// only the lines inside it.
+    selection: Option<egui::Rect>,
+    /// A Shift+drag for `selection` is in progress.
+    selecting: bool,
+}
+
+/// A captured screen waiting for the user to drag out the region to OCR.
+struct RegionSelect {
+    rgba: Vec<u8>,
+    width: usize,
+    height: usize,
+    texture: TextureHandle,
+    /// Index of the captured display.
+    screen: usize,
+    /// Drag start and latest pointer position, in screen points.
+    drag: Option<(egui::Pos2, egui::Pos2)>,
+}
+
+/// Profile for the B side of a compare run: the current settings with a
+/// preset applied on top.
+#[derive(Debug, Clone, PartialEq)]
+enum ComparePreset {
+    Builtin(BuiltinPreset),
+    /// A user preset, by name.
+    User(String),
+}
+
+/// What a path typed into the fallback path dialog is for.  Paths in the
+/// settings tab have text fields of their own and need no fallback.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum PathTarget {
+    OpenFile,
+    OpenFolder,
+    ExportSettings,
+    ImportSettings,
+    Export(ExportFormat),
+}
+
+/// How result lines are turned into text for the clipboard.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CopyFormat {
+    TextOnly,
+    /// `text (NN.N%)`, as in the plain-text view.
+    WithConfidence,
+    /// `text<TAB>confidence`, for pasting into spreadsheets.
+    Tsv,
+}
+
+impl CopyFormat {
+    const ALL: [CopyFormat; 3] = [CopyFormat::TextOnly, CopyFormat::WithConfidence, CopyFormat::Tsv];
+
+    fn line(self, line: &OcrLine, decimals: u8) -> String {
+        match self {
+            CopyFormat::TextOnly => line.text.clone(),
+            CopyFormat::WithConfidence => format!("{} ({})", line.text, percent(line.confidence, decimals)),
+            CopyFormat::Tsv => {
+                // Tabs or newlines in the text would shift the columns.
+                let text = line.text.replace(['\t', '\n', '\r'], " ");
+                if line.has_confidence() {
+                    format!("{}\t{:.3}", text, line.confidence)
+                } else {
+                    format!("{}\t", text)
+                }
+            }
+        }
+    }
+}
+
+/// What the plain-text view was rendered from; its text is rebuilt when
+/// any of this changes.
+#[derive(Clone, Copy, PartialEq)]
+struct PlainTextKey {
+    /// `EasyOcrApp::results_version`
+    version: u64,
+    min_confidence: f32,
+    selection: Option<egui::Rect>,
+    join_paragraphs: bool,
+    decimals: u8,
+}
+
+/// Files being split into pages on a background thread.
+enum PageSplit {
+    /// A file being opened.
+    Open(PathBuf, Receiver<pages::Split>),
+    /// Files about to be queued, with documents replaced by their pages;
+    /// `preview` shows the first one meanwhile, as for dropped files.
+    Batch { paths: Receiver<Vec<PathBuf>>, preview: bool },
+}
+
+/// One column of a compare run.
+struct CompareSide {
+    label: String,
+    job: Option<OcrJob>,
+    result: Option<OcrResult>,
+}
+
+enum RegionOutcome {
+    Pending,
+    Cancelled,
+    /// Selected rectangle in image pixels: `[x, y, w, h]`.
+    Selected([usize; 4]),
+}
+
+/// A line under the pointer, by index into the lines shown on the image.
+#[derive(Clone, Copy, PartialEq)]
+struct HoveredLine {
+    index: usize,
+    /// Hovered as a box in the image rather than as a results row
+    on_image: bool,
+}
+
+/// An image in the batch queue.
+struct QueueItem {
+    path: PathBuf,
+    status: QueueStatus,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum QueueStatus {
+    Pending,
+    Running,
+    /// Finished with this many lines.
+    Done(usize),
+    Failed,
 }
 
 enum OcrState {
     Idle,
-    Running(Receiver<OcrResult>),
+    Running(OcrJob),
+    /// Both sides of a compare run; the jobs live in `compare_sides`.
+    Compare,
+    /// A batch: one job per queue item that is `QueueStatus::Running`, up
+    /// to `batch_concurrency` at once.
+    Batch(Vec<(PathBuf, OcrJob)>),
     Done,
-    Error(String),
+    /// `kind` is set for well-known EasyOCR failures.
+    Error {
+        message: String,
+        kind: Option<FailureKind>,
+    },
 }
 
 pub struct EasyOcrApp {
     tab: Tab,
     image: Option<LoadedImage>,
+    // Page images of the open multi-page TIFF or PDF, or frames of an
+    // animation; empty for plain images
+    pages: Vec<PathBuf>,
+    page_kind: PageKind,
+    // Files being split into pages in the background
+    page_split: Option<PageSplit>,
     ocr_state: OcrState,
-    ocr_result_text: String,
+    // Latest status line EasyOCR printed during the current run
+    ocr_progress: Option<String>,
+    // Structured results of the last single-image run; the Copy text and
+    // every view are derived from these
+    ocr_results: Vec<OcrLine>,
+    // Output lines of the last run that could not be parsed
+    unparsed_lines: Vec<String>,
+    // Everything the last single-image run printed on stdout
+    raw_output: String,
+    // Per-image results of the last folder batch, in completion order
+    batch_results: Vec<(PathBuf, OcrResult)>,
+    // Images of the current or last batch; pending ones run front to back
+    // and can still be reordered or removed
+    queue: Vec<QueueItem>,
+    // Run the image with the current settings and `compare_preset` side by side
+    compare: bool,
+    compare_preset: ComparePreset,
+    // Columns of the last compare run; empty outside compare mode
+    compare_sides: Vec<CompareSide>,
+    // Show results as one text blob instead of editable per-line rows
+    show_plain_text: bool,
+    // Bumped whenever the result lines, or the rows left out, change
+    results_version: u64,
+    // The plain-text view's text and what it was rendered from; edits to
+    // it last until the results change
+    plain_text: Option<(PlainTextKey, String)>,
+    // The line under the pointer last frame, highlighted in both the image
+    // and the results rows
+    hovered_line: Option<HoveredLine>,
+    // Index of the box hovered in the image that the results were last
+    // scrolled to, so they scroll once per box and not on every frame
+    scrolled_to_line: Option<usize>,
+    // Result row picked with the arrow keys, and the rows Space left out of
+    // Copy and Export; both index `ocr_results`
+    focused_line: Option<usize>,
+    excluded_lines: HashSet<usize>,
+    // Lines below this confidence are hidden from the view, Copy and Export
+    min_confidence: f32,
+    // Fullscreen region picker, open while the user selects part of a screenshot
+    region_select: Option<RegionSelect>,
+    // One label per connected display, refreshed on every capture
+    screen_labels: Vec<String>,
+    // Past runs shown in the history tab
+    history: History,
+    // User-defined presets and the name typed for the next one
+    user_presets: Vec<Preset>,
+    preset_name_input: String,
+    shortcut_name_input: String,
+    // Filter typed into the language picker
+    language_search: String,
     status_message: String,
+    // When the current (or last) run started, for the status-bar timer
+    ocr_started: Option<Instant>,
     settings: Settings,
     settings_save_msg: Option<(String, bool)>, // (message, is_error)
+    // The settings before the last Reset, while it can still be undone
+    reset_undo: Option<(Settings, Instant)>,
+    // Model directory shown in the Paths section, when its size was last
+    // measured and the size, `None` if it doesn't exist yet
+    model_dir_usage: Option<(PathBuf, Instant, Option<u64>)>,
     // Copy-to-clipboard confirmation timer
     copied_timer: f32,
+    // What the Copy button puts on the clipboard
+    copy_format: CopyFormat,
+    // When the last paste was handled, and a hash of the image it loaded
+    last_paste: Option<Instant>,
+    pasted_hash: Option<u64>,
     // Setup / dependency check state
     setup_status: SetupStatus,
-    setup_rx: Option<Receiver<bool>>,
+    setup_rx: Option<Receiver<ocr::Resolution>>,
+    // Whether PyTorch sees a CUDA device, probed once easyocr is found;
+    // `None` while checking or if it couldn't be told
+    cuda_available: Option<bool>,
+    cuda_rx: Option<Receiver<Option<bool>>>,
+    // Python and EasyOCR versions for the About section, probed the first
+    // time it is shown
+    versions: Option<ocr::Versions>,
+    versions_rx: Option<Receiver<ocr::Versions>>,
     show_setup_dialog: bool,
+    // URL typed into the "From URL" dialog, `Some` while it is open, and the
+    // download in flight
+    url_input: Option<String>,
+    url_download: Option<Receiver<Result<PathBuf, String>>>,
+    // A native file dialog that came back empty, and when, while typing the
+    // path in instead is offered; then the path typed, `Some` while that
+    // dialog is open
+    path_fallback: Option<(PathTarget, Instant)>,
+    path_input: Option<(PathTarget, String)>,
+    // Live camera preview, `Some` while the camera is open
+    camera: Option<CameraView>,
+    // System-wide shortcut, created once it is first enabled; the spec last
+    // applied to it and why that failed
+    global_hotkey: Option<GlobalHotkey>,
+    hotkey_spec: Option<String>,
+    hotkey_error: Option<String>,
+    // A hotkey capture is under way: select, run, then copy the result
+    hotkey_flow: bool,
+    // Latest window placement, saved on exit
+    window_geometry: Option<WindowGeometry>,
 }
 
 impl EasyOcrApp {
@@ -59,20 +365,69 @@ impl EasyOcrApp {
         let settings = Settings::load();
         // Start a background check for the easyocr CLI so the window opens
         // immediately without any freeze.
-        let setup_rx = ocr::check_easyocr_async(&settings.easyocr_exe);
+        let setup_rx = ocr::check_easyocr_async(&settings.easyocr_exe, false);
         let s = i18n::get(&settings.ui_language);
+        let screen_labels = screenshots::Screen::all()
+            .map(|screens| screen_labels(s, &screens))
+            .unwrap_or_default();
         Self {
-            tab: Tab::Ocr,
+            tab: Tab::from_name(&settings.tab),
             image: None,
+            pages: Vec::new(),
+            page_kind: PageKind::Document,
+            page_split: None,
             ocr_state: OcrState::Idle,
-            ocr_result_text: String::new(),
+            ocr_progress: None,
+            ocr_results: Vec::new(),
+            unparsed_lines: Vec::new(),
+            raw_output: String::new(),
+            batch_results: Vec::new(),
+            queue: Vec::new(),
+            compare: false,
+            compare_preset: ComparePreset::Builtin(BuiltinPreset::Default),
+            compare_sides: Vec::new(),
+            show_plain_text: false,
+            results_version: 0,
+            plain_text: None,
+            hovered_line: None,
+            scrolled_to_line: None,
+            focused_line: None,
+            excluded_lines: HashSet::new(),
+            min_confidence: 0.0,
+            region_select: None,
+            screen_labels,
+            history: History::load(),
+            user_presets: Preset::load_user(),
+            preset_name_input: String::new(),
+            shortcut_name_input: String::new(),
+            language_search: String::new(),
             status_message: s.status_load_image.into(),
+            ocr_started: None,
             settings,
             settings_save_msg: None,
+            reset_undo: None,
+            model_dir_usage: None,
             copied_timer: 0.0,
+            copy_format: CopyFormat::WithConfidence,
+            last_paste: None,
+            pasted_hash: None,
             setup_status: SetupStatus::Checking,
             setup_rx: Some(setup_rx),
+            cuda_available: None,
+            cuda_rx: None,
+            versions: None,
+            versions_rx: None,
             show_setup_dialog: false,
+            url_input: None,
+            url_download: None,
+            path_fallback: None,
+            path_input: None,
+            camera: None,
+            global_hotkey: None,
+            hotkey_spec: None,
+            hotkey_error: None,
+            hotkey_flow: false,
+            window_geometry: None,
         }
     }
 
@@ -81,29 +436,150 @@ impl EasyOcrApp {
         i18n::get(&self.settings.ui_language)
     }
 
+    /// `true` while a single run or a batch is in progress.
+    fn is_running(&self) -> bool {
+        matches!(
+            self.ocr_state,
+            OcrState::Running(_) | OcrState::Batch(_) | OcrState::Compare
+        )
+    }
+
     // ── image loading helpers ────────────────────────────────────────────────
 
+    /// Opens an image, or a document or animation once it has been split
+    /// into pages in the background.
     fn load_image_from_path(&mut self, path: PathBuf, ctx: &egui::Context) {
-        match load_color_image_from_path(&path) {
-            Ok(color_image) => {
-                let w = color_image.size[0] as u32;
-                let h = color_image.size[1] as u32;
-                let texture = ctx.load_texture(
-                    "ocr_image",
-                    color_image,
-                    egui::TextureOptions::LINEAR,
-                );
-                self.image = Some(LoadedImage { path: path.clone(), texture, width: w, height: h });
-                self.ocr_state = OcrState::Idle;
-                self.ocr_result_text.clear();
+        if pages::may_split(&path) {
+            let name = path.file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default();
+            self.status_message = self.s().status_reading_pages.replacen("{}", &name, 1);
+            self.page_split = Some(PageSplit::Open(path.clone(), pages::split_pages_async(path)));
+            return;
+        }
+        self.show_pages(path, Ok(None), ctx);
+    }
+
+    /// Shows `path`, or the first of the pages it was split into.
+    fn show_pages(&mut self, path: PathBuf, pages: pages::Split, ctx: &egui::Context) {
+        let pages = match pages {
+            Ok(pages) => pages,
+            Err(e) => {
+                self.status_message = self.s().status_failed_load_image.replacen("{}", &e, 1);
+                return;
+            }
+        };
+        let first = pages.as_ref().map_or(&path, |(_, p)| &p[0]).clone();
+        if !self.show_image_from_path(first, ctx) {
+            pages::remove_pages(pages.as_ref().map_or(&[][..], |(_, p)| &p[..]));
+            return;
+        }
+        if let Some((kind, pages)) = pages {
+            let name = path.file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let loaded = match kind {
+                PageKind::Document => self.s().status_document_loaded,
+                PageKind::Frame => self.s().status_animation_loaded,
+            };
+            self.status_message = loaded
+                .replacen("{}", &name, 1)
+                .replacen("{}", &pages.len().to_string(), 1);
+            self.pages = pages;
+            self.page_kind = kind;
+        }
+        // Pasted and captured images live in the temp dir; they can't be
+        // reopened meaningfully later.
+        if !path.starts_with(std::env::temp_dir()) {
+            let entry = path.to_string_lossy().to_string();
+            self.settings.remember(|s| s.push_recent(&entry));
+        }
+        self.maybe_auto_run();
+    }
+
+    /// Loads an image into the preview without auto-running OCR on it.
+    /// Returns `false` when it could not be decoded.
+    fn show_image_from_path(&mut self, path: PathBuf, ctx: &egui::Context) -> bool {
+        match decode_image(&path, ctx) {
+            Ok(loaded) => {
+                self.image = Some(loaded);
+                pages::remove_pages(&std::mem::take(&mut self.pages));
+                self.clear_results();
                 let name = path.file_name()
                     .map(|n| n.to_string_lossy().to_string())
                     .unwrap_or_default();
                 self.status_message = self.s().status_image_loaded.replacen("{}", &name, 1);
+                true
             }
             Err(e) => {
                 self.status_message = self.s().status_failed_load_image.replacen("{}", &e, 1);
+                false
+            }
+        }
+    }
+
+    /// Picks up files split into pages in the background.
+    fn poll_page_split(&mut self, ctx: &egui::Context) {
+        use std::sync::mpsc::TryRecvError;
+        match self.page_split.take() {
+            Some(PageSplit::Open(path, rx)) => match rx.try_recv() {
+                Ok(pages) => self.show_pages(path, pages, ctx),
+                Err(TryRecvError::Empty) => {
+                    self.page_split = Some(PageSplit::Open(path, rx));
+                    ctx.request_repaint();
+                }
+                Err(TryRecvError::Disconnected) => {}
+            },
+            Some(PageSplit::Batch { paths: rx, preview }) => match rx.try_recv() {
+                Ok(paths) => self.queue_batch(paths, preview, ctx),
+                Err(TryRecvError::Empty) => {
+                    self.page_split = Some(PageSplit::Batch { paths: rx, preview });
+                    ctx.request_repaint();
+                }
+                Err(TryRecvError::Disconnected) => {}
+            },
+            None => {}
+        }
+    }
+
+    /// Drops the results of the previous image.  A run still in flight is
+    /// cancelled so its process doesn't keep going in the background.
+    fn clear_results(&mut self) {
+        self.cancel_jobs();
+        self.results_changed();
+        self.ocr_state = OcrState::Idle;
+        self.ocr_results.clear();
+        self.forget_line_marks();
+        self.unparsed_lines.clear();
+        self.raw_output.clear();
+        self.batch_results.clear();
+        self.queue.clear();
+        self.compare_sides.clear();
+    }
+
+    /// Signals every worker of the current run to stop.
+    fn cancel_jobs(&self) {
+        match &self.ocr_state {
+            OcrState::Running(job) => job.cancel(),
+            OcrState::Batch(jobs) => {
+                for (_, job) in jobs {
+                    job.cancel();
+                }
             }
+            OcrState::Compare => {
+                for job in self.compare_sides.iter().filter_map(|side| side.job.as_ref()) {
+                    job.cancel();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Runs OCR on a freshly loaded image when auto-run is on.  Loading has
+    /// already cancelled any earlier run through `clear_results`.
+    fn maybe_auto_run(&mut self) {
+        if self.settings.auto_run {
+            self.action_run_ocr();
         }
     }
 
@@ -114,47 +590,298 @@ impl EasyOcrApp {
         height: usize,
         ctx: &egui::Context,
         label: &str,
+        source: ImageSource,
     ) {
-        // Save to a temp PNG so that the easyocr CLI can read it.
-        let tmp_path = std::env::temp_dir().join("easyocr_gui_tmp.png");
-        if let Err(e) = save_rgba_as_png(&rgba, width as u32, height as u32, &tmp_path) {
-            self.status_message = self.s().status_cant_save_tmp.replacen("{}", &e, 1);
-            return;
-        }
+        // Save to a file so that the easyocr CLI can read it.
+        let format = self.settings.temp_format;
+        let quality = self.settings.temp_jpeg_quality;
+        let saved = capture_path(&self.settings.save_captures_dir, format).and_then(|path| {
+            save_capture(&rgba, width as u32, height as u32, &path, format, quality).map(|()| path)
+        });
+        let image_path = match saved {
+            Ok(path) => path,
+            Err(e) => {
+                self.status_message = self.s().status_cant_save_tmp.replacen("{}", &e, 1);
+                return;
+            }
+        };
 
         let color_image = ColorImage::from_rgba_unmultiplied([width, height], &rgba);
         let texture = ctx.load_texture("ocr_image", color_image, egui::TextureOptions::LINEAR);
         self.image = Some(LoadedImage {
-            path: tmp_path,
+            path: image_path,
+            source,
             texture,
             width: width as u32,
             height: height as u32,
+            zoom: 1.0,
+            pan: Vec2::ZERO,
+            processed: None,
+            show_processed: false,
+            selection: None,
+            selecting: false,
         });
-        self.ocr_state = OcrState::Idle;
-        self.ocr_result_text.clear();
+        pages::remove_pages(&std::mem::take(&mut self.pages));
+        self.clear_results();
         self.status_message = self.s().status_image_loaded.replacen("{}", label, 1);
+        self.maybe_auto_run();
     }
 
     // ── actions ──────────────────────────────────────────────────────────────
 
     fn action_open_file(&mut self, ctx: &egui::Context) {
-        if let Some(path) = rfd::FileDialog::new()
-            .add_filter("Images", &["png", "jpg", "jpeg", "bmp", "gif", "tiff", "webp"])
-            .pick_file()
-        {
-            self.load_image_from_path(path, ctx);
+        let dialog = file_dialog(&self.settings.last_open_dir)
+            .add_filter("Images", &[IMAGE_EXTENSIONS, pages::DOCUMENT_EXTENSIONS].concat());
+        if let Some(path) = self.pick_path(PathTarget::OpenFile, || dialog.pick_file()) {
+            self.open_file(path, ctx);
+        }
+    }
+
+    fn open_file(&mut self, path: PathBuf, ctx: &egui::Context) {
+        if let Some(parent) = path.parent() {
+            let dir = parent.to_string_lossy().to_string();
+            self.settings.remember(|s| s.last_open_dir = dir.clone());
+        }
+        self.load_image_from_path(path, ctx);
+    }
+
+    /// Shows a native file dialog through `show`.  Without a desktop portal
+    /// (or any display) rfd gives up and returns `None` just as a cancel
+    /// does, so whenever no path comes back, typing one in is offered for a
+    /// few seconds.
+    fn pick_path(
+        &mut self,
+        target: PathTarget,
+        show: impl FnOnce() -> Option<PathBuf>,
+    ) -> Option<PathBuf> {
+        let picked = show();
+        self.path_fallback = picked.is_none().then(|| (target, Instant::now()));
+        picked
+    }
+
+    /// Does what the native dialog for `target` would have with `path`.
+    fn use_path(&mut self, target: PathTarget, path: PathBuf, ctx: &egui::Context) {
+        match target {
+            PathTarget::OpenFile => self.open_file(path, ctx),
+            PathTarget::OpenFolder => self.open_folder(path, ctx),
+            PathTarget::ExportSettings => self.export_settings_to(path),
+            PathTarget::ImportSettings => self.import_settings_from(path),
+            PathTarget::Export(format) => self.export_to(format, path),
+        }
+    }
+
+    fn action_open_recent(&mut self, entry: String, ctx: &egui::Context) {
+        let path = PathBuf::from(&entry);
+        if !path.is_file() {
+            self.status_message = self.s().status_recent_missing.replacen("{}", &entry, 1);
+            self.settings.remember(|s| s.recent_files.retain(|p| *p != entry));
+            return;
+        }
+        self.load_image_from_path(path, ctx);
+    }
+
+    /// Previews another page of the open document, keeping the results.
+    fn show_page(&mut self, index: usize, ctx: &egui::Context) {
+        let Some(path) = self.pages.get(index) else {
+            return;
+        };
+        match decode_image(path, ctx) {
+            Ok(loaded) => {
+                self.image = Some(loaded);
+                // The last frame's boxes don't belong on this one.
+                if self.page_kind == PageKind::Frame {
+                    self.clear_results();
+                }
+            }
+            Err(e) => {
+                self.status_message = self.s().status_failed_load_image.replacen("{}", &e, 1)
+            }
+        }
+    }
+
+    /// Re-reads the shown file, e.g. after it was edited elsewhere, keeping
+    /// the zoom and pan.  Results stay until the next run.
+    fn action_reload_image(&mut self, ctx: &egui::Context) {
+        let Some(old) = &self.image else {
+            return;
+        };
+        match decode_image(&old.path, ctx) {
+            Ok(loaded) => {
+                let name = loaded.path.file_name()
+                    .map(|n| n.to_string_lossy().to_string())
+                    .unwrap_or_default();
+                let (zoom, pan, show_processed) = (old.zoom, old.pan, old.show_processed);
+                let selection = old.selection;
+                // An edited capture is still a capture.
+                let source = match &old.source {
+                    ImageSource::File { .. } => loaded.source,
+                    other => *other,
+                };
+                self.image =
+                    Some(LoadedImage { zoom, pan, show_processed, source, selection, ..loaded });
+                self.status_message = self.s().status_image_reloaded.replacen("{}", &name, 1);
+            }
+            Err(e) => {
+                self.status_message = self.s().status_failed_load_image.replacen("{}", &e, 1)
+            }
+        }
+    }
+
+    /// Opens the image file in the system's default app for it, to be
+    /// edited there and reloaded.  Pasted and captured images are already
+    /// saved to a file by the time they are shown.
+    fn action_open_in_editor(&mut self) {
+        let Some(loaded) = &self.image else {
+            return;
+        };
+        let name = loaded.path.file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        self.status_message = match open_in_default_app(&loaded.path) {
+            Ok(()) => self.s().status_opened_in_editor.replacen("{}", &name, 1),
+            Err(e) => self.s().status_cant_open_editor.replacen("{}", &e.to_string(), 1),
+        };
+    }
+
+    fn action_open_folder(&mut self, ctx: &egui::Context) {
+        let dialog = file_dialog(&self.settings.last_open_dir);
+        if let Some(dir) = self.pick_path(PathTarget::OpenFolder, || dialog.pick_folder()) {
+            self.open_folder(dir, ctx);
+        }
+    }
+
+    fn open_folder(&mut self, dir: PathBuf, ctx: &egui::Context) {
+        let dir_str = dir.to_string_lossy().to_string();
+        self.settings.remember(|s| s.last_open_dir = dir_str.clone());
+        let paths = list_images_in_dir(&dir);
+        if paths.is_empty() {
+            self.status_message = self.s().status_no_images_in_folder.into();
+            return;
+        }
+        self.split_and_queue(paths, false, ctx);
+    }
+
+    /// Handles files dropped onto the window.  A single file is just opened;
+    /// several are previewed from the first and queued as a batch.
+    fn action_drop_files(&mut self, paths: Vec<PathBuf>, ctx: &egui::Context) {
+        let images: Vec<PathBuf> =
+            paths.iter().filter(|p| has_image_extension(p)).cloned().collect();
+        match images.len() {
+            // Let the loader report why a lone unsupported file can't be opened.
+            0 if paths.len() == 1 => self.load_image_from_path(paths[0].clone(), ctx),
+            0 => self.status_message = self.s().status_no_images_dropped.into(),
+            1 => self.load_image_from_path(images[0].clone(), ctx),
+            _ if self.is_running() => self.status_message = self.s().status_busy.into(),
+            _ => self.split_and_queue(images, true, ctx),
+        }
+    }
+
+    /// Queues `paths` as a batch, splitting any documents among them into
+    /// their pages in the background first.
+    fn split_and_queue(&mut self, paths: Vec<PathBuf>, preview: bool, ctx: &egui::Context) {
+        if paths.iter().any(|p| pages::is_document(p)) {
+            self.status_message = self.s().status_reading_documents.into();
+            let paths = pages::expand_documents_async(paths);
+            self.page_split = Some(PageSplit::Batch { paths, preview });
+        } else {
+            self.queue_batch(paths, preview, ctx);
+        }
+    }
+
+    fn queue_batch(&mut self, paths: Vec<PathBuf>, preview: bool, ctx: &egui::Context) {
+        if self.is_running() {
+            self.status_message = self.s().status_busy.into();
+            return;
+        }
+        if let Some(first) = paths.first().filter(|_| preview) {
+            self.show_image_from_path(first.clone(), ctx);
+        }
+        self.start_batch(paths);
+    }
+
+    fn start_batch(&mut self, paths: Vec<PathBuf>) {
+        if !self.languages_ok() {
+            return;
+        }
+        self.ocr_progress = None;
+        self.ocr_started = Some(Instant::now());
+        self.ocr_results.clear();
+        self.forget_line_marks();
+        self.unparsed_lines.clear();
+        self.raw_output.clear();
+        self.batch_results.clear();
+        self.queue = paths
+            .into_iter()
+            .map(|path| QueueItem { path, status: QueueStatus::Pending })
+            .collect();
+        self.run_next_queued();
+    }
+
+    /// Starts pending images of the queue, front first, until
+    /// `batch_concurrency` are running, or wraps the batch up when nothing
+    /// is left.
+    fn run_next_queued(&mut self) {
+        let limit = ocr::effective_batch_concurrency(self.settings.batch_concurrency);
+        let mut jobs = match std::mem::replace(&mut self.ocr_state, OcrState::Idle) {
+            OcrState::Batch(jobs) => jobs,
+            _ => Vec::new(),
+        };
+        while jobs.len() < limit {
+            let Some(item) = self.queue.iter_mut().find(|i| i.status == QueueStatus::Pending) else {
+                break;
+            };
+            item.status = QueueStatus::Running;
+            jobs.push((item.path.clone(), ocr::run_ocr_async(&item.path, &self.settings)));
+        }
+        let (done, total) = self.queue_progress();
+        if jobs.is_empty() {
+            let failed = self.queue.iter().filter(|i| i.status == QueueStatus::Failed).count();
+            self.status_message = self
+                .s()
+                .status_batch_complete
+                .replacen("{}", &done.to_string(), 1)
+                .replacen("{}", &failed.to_string(), 1);
+            self.ocr_state = OcrState::Done;
+            return;
         }
+        self.ocr_state = OcrState::Batch(jobs);
+        self.status_message = batch_progress(self.s(), done, total);
+    }
+
+    /// Finished and total images in the queue.
+    fn queue_progress(&self) -> (usize, usize) {
+        let finished = self
+            .queue
+            .iter()
+            .filter(|i| matches!(i.status, QueueStatus::Done(_) | QueueStatus::Failed))
+            .count();
+        (finished, self.queue.len())
     }
 
     fn action_paste_clipboard(&mut self, ctx: &egui::Context) {
+        // Holding or mashing the shortcut shouldn't re-read the clipboard and
+        // rewrite the temp PNG for every key repeat.
+        let now = Instant::now();
+        if self.last_paste.is_some_and(|at| now.duration_since(at) < PASTE_DEBOUNCE) {
+            return;
+        }
+        self.last_paste = Some(now);
         match arboard::Clipboard::new() {
             Ok(mut clipboard) => match clipboard.get_image() {
                 Ok(img) => {
                     let w = img.width;
                     let h = img.height;
                     let bytes: Vec<u8> = img.bytes.into_owned();
+                    let hash = rgba_hash(&bytes, w, h);
+                    let showing_paste =
+                        matches!(self.image.as_ref().map(|l| l.source), Some(ImageSource::Clipboard));
+                    if showing_paste && self.pasted_hash == Some(hash) {
+                        self.status_message = self.s().status_clipboard_unchanged.into();
+                        return;
+                    }
                     let label = self.s().btn_paste_image;
-                    self.load_image_from_rgba(bytes, w, h, ctx, label);
+                    self.load_image_from_rgba(bytes, w, h, ctx, label, ImageSource::Clipboard);
+                    self.pasted_hash = Some(hash);
                 }
                 Err(_) => {
                     self.status_message = self.s().status_no_image_clipboard.into();
@@ -167,107 +894,865 @@ impl EasyOcrApp {
     }
 
     fn action_screenshot(&mut self, ctx: &egui::Context) {
+        if let Some((rgba, w, h, screen)) = self.capture_screen() {
+            let label = self.s().btn_screenshot;
+            self.load_image_from_rgba(rgba, w, h, ctx, label, ImageSource::Screen(screen));
+        }
+    }
+
+    /// Capture the screen and open the region picker over it.
+    fn action_screenshot_region(&mut self, ctx: &egui::Context) {
+        if let Some((rgba, width, height, screen)) = self.capture_screen() {
+            let color_image = ColorImage::from_rgba_unmultiplied([width, height], &rgba);
+            let texture =
+                ctx.load_texture("region_select", color_image, egui::TextureOptions::LINEAR);
+            self.region_select = Some(RegionSelect {
+                rgba,
+                width,
+                height,
+                texture,
+                screen,
+                drag: None,
+            });
+        }
+    }
+
+    /// Capture the selected screen as RGBA, reporting failures in the status
+    /// bar.  Falls back to the first screen if the saved index is gone.
+    fn capture_screen(&mut self) -> Option<(Vec<u8>, usize, usize, usize)> {
         match screenshots::Screen::all() {
             Ok(screens) => {
-                if screens.is_empty() {
+                self.screen_labels = screen_labels(self.s(), &screens);
+                let index = if self.settings.screen_index < screens.len() {
+                    self.settings.screen_index
+                } else {
+                    0
+                };
+                let Some(screen) = screens.get(index) else {
                     self.status_message = self.s().status_no_screens.into();
-                    return;
-                }
-                let screen = &screens[0];
+                    return None;
+                };
                 match screen.capture() {
                     Ok(img) => {
                         let w = img.width() as usize;
                         let h = img.height() as usize;
-                        let rgba: Vec<u8> = img.into_raw();
-                        let label = self.s().btn_screenshot;
-                        self.load_image_from_rgba(rgba, w, h, ctx, label);
+                        Some((img.into_raw(), w, h, index))
                     }
                     Err(e) => {
                         self.status_message = self.s().status_screenshot_failed.replacen("{}", &e.to_string(), 1);
+                        None
                     }
                 }
             }
             Err(e) => {
                 self.status_message = self.s().status_cant_enum_screens.replacen("{}", &e.to_string(), 1);
+                None
+            }
+        }
+    }
+
+    /// Refuses to start a run whose language list EasyOCR would reject,
+    /// showing why in the results pane instead.
+    fn languages_ok(&mut self) -> bool {
+        match lang::validate(&ocr::parse_languages(&self.settings.languages)) {
+            Ok(()) => true,
+            Err(problem) => {
+                let msg = language_problem_text(self.s(), &problem);
+                self.status_message = self.s().status_ocr_failed.replacen("{}", &msg, 1);
+                self.ocr_state = OcrState::Error { message: msg, kind: None };
+                false
             }
         }
     }
 
+    /// Size in megapixels of the loaded image when it is big enough to make
+    /// a run slow and nothing already shrinks it.
+    fn large_image_megapixels(&self) -> Option<f64> {
+        let loaded = self.image.as_ref()?;
+        let pixels = u64::from(loaded.width) * u64::from(loaded.height);
+        let shrunk = self.settings.preprocess_downscale && !self.settings.preprocess_upscale;
+        (pixels > LARGE_IMAGE_PIXELS && !shrunk && !self.is_running())
+            .then(|| pixels as f64 / 1_000_000.0)
+    }
+
     fn action_run_ocr(&mut self) {
+        if self.pages.len() > 1 && self.page_kind == PageKind::Document {
+            self.start_batch(self.pages.clone());
+            return;
+        }
+        if !self.languages_ok() {
+            return;
+        }
+        // Temp and network files can vanish after loading; say so here
+        // instead of letting Python fail with a traceback.
+        if let Some(loaded) = self.image.as_ref().filter(|img| !img.path.exists()) {
+            self.status_message =
+                self.s().status_image_gone.replacen("{}", &loaded.path.display().to_string(), 1);
+            return;
+        }
+        self.ocr_started = Some(Instant::now());
+        if self.compare {
+            self.start_compare();
+            return;
+        }
         if let Some(loaded) = &self.image {
             self.ocr_state =
                 OcrState::Running(ocr::run_ocr_async(&loaded.path, &self.settings));
+            self.ocr_progress = None;
             self.status_message = self.s().status_running_ocr.into();
-            self.ocr_result_text.clear();
+            self.ocr_results.clear();
+            self.forget_line_marks();
+            self.unparsed_lines.clear();
+            self.raw_output.clear();
+            self.batch_results.clear();
         }
     }
 
-    fn action_copy_results(&mut self, ctx: &egui::Context) {
-        if !self.ocr_result_text.is_empty() {
-            ctx.output_mut(|o| o.copied_text = self.ocr_result_text.clone());
-            self.copied_timer = 2.0; // show "Copied!" for 2 seconds
+    /// Runs the image twice at once: with the current settings and with
+    /// `compare_preset` applied to them.
+    fn start_compare(&mut self) {
+        let Some(loaded) = &self.image else {
+            return;
+        };
+        let s = self.s();
+        let mut other = self.settings.clone();
+        let other_label = match &self.compare_preset {
+            ComparePreset::Builtin(preset) => {
+                preset.fields().apply_to(&mut other);
+                builtin_preset_label(s, *preset).to_string()
+            }
+            ComparePreset::User(name) => {
+                if let Some(preset) = self.user_presets.iter().find(|p| p.name == *name) {
+                    preset.apply_to(&mut other);
+                }
+                name.clone()
+            }
+        };
+        let path = loaded.path.clone();
+        self.clear_results();
+        self.compare_sides = [(s.lbl_compare_current.to_string(), &self.settings), (other_label, &other)]
+            .into_iter()
+            .map(|(label, settings)| CompareSide {
+                label,
+                job: Some(ocr::run_ocr_async(&path, settings)),
+                result: None,
+            })
+            .collect();
+        self.ocr_state = OcrState::Compare;
+        self.ocr_progress = None;
+        self.status_message = s.status_running_ocr.into();
+    }
+
+    fn action_cancel_ocr(&mut self) {
+        if !self.is_running() {
+            return;
+        }
+        self.cancel_jobs();
+        self.ocr_state = OcrState::Idle;
+        for item in self.queue.iter_mut().filter(|i| i.status == QueueStatus::Running) {
+            item.status = QueueStatus::Pending;
         }
+        self.status_message = self.s().status_cancelled.into();
     }
 
-    // ── poll OCR thread ──────────────────────────────────────────────────────
+    /// The plain-text view of the results with the confidence filter
+    /// applied, rendered again only when something it depends on changed.
+    fn result_text(&mut self) -> &mut String {
+        let key = PlainTextKey {
+            version: self.results_version,
+            min_confidence: self.min_confidence,
+            selection: self.image.as_ref().and_then(|loaded| loaded.selection),
+            join_paragraphs: self.settings.paragraph && self.settings.join_paragraph_lines,
+            decimals: self.settings.confidence_decimals,
+        };
+        if self.plain_text.as_ref().is_none_or(|(cached, _)| *cached != key) {
+            self.plain_text = Some((key, self.results_as(CopyFormat::WithConfidence)));
+        }
+        &mut self.plain_text.get_or_insert_with(|| (key, String::new())).1
+    }
 
-    fn poll_ocr(&mut self) {
-        let result = if let OcrState::Running(rx) = &self.ocr_state {
-            rx.try_recv().ok()
-        } else {
-            None
+    /// Notes that the result lines changed, for `result_text`.
+    fn results_changed(&mut self) {
+        self.results_version += 1;
+    }
+
+    fn results_as(&self, format: CopyFormat) -> String {
+        let opts = TextOptions {
+            min_confidence: self.min_confidence,
+            format,
+            join_paragraphs: self.settings.paragraph && self.settings.join_paragraph_lines,
+            decimals: self.settings.confidence_decimals,
         };
+        if !self.compare_sides.is_empty() {
+            format_compare_results(&self.compare_sides, opts)
+        } else if let Some(lines) = self.chosen_lines() {
+            format_lines(&lines, opts)
+        } else if self.batch_results.is_empty() {
+            format_lines(&self.ocr_results, opts)
+        } else {
+            format_batch_results(&self.batch_results, opts)
+        }
+    }
 
-        if let Some(res) = result {
-            if let Some(err) = res.error {
-                self.status_message = self.s().status_ocr_failed.replacen(
-                    "{}",
-                    err.lines().next().unwrap_or(""),
-                    1,
-                );
-                self.ocr_state = OcrState::Error(err);
-            } else {
-                let count = res.lines.len();
-                self.ocr_result_text = res
-                    .lines
-                    .iter()
-                    .map(|l| format!("{} ({:.1}%)", l.text, l.confidence * 100.0))
-                    .collect::<Vec<_>>()
-                    .join("\n");
-                self.status_message = self.s().status_ocr_complete.replacen(
-                    "{}",
-                    &count.to_string(),
-                    1,
-                );
-                self.ocr_state = OcrState::Done;
-            }
+    /// Whether Copy and Export are limited to a region of the image; only
+    /// single-image results can be.
+    fn region_applies(&self) -> bool {
+        self.batch_results.is_empty() && self.compare_sides.is_empty()
+    }
+
+    /// The single-image results Copy and Export are limited to: those inside
+    /// the region selected on the preview and not left out with Space.
+    /// `None` when neither narrows them.
+    fn chosen_lines(&self) -> Option<Vec<OcrLine>> {
+        let region = self.image.as_ref().and_then(|loaded| loaded.selection);
+        if !self.region_applies() || (region.is_none() && self.excluded_lines.is_empty()) {
+            return None;
         }
+        let kept: Vec<OcrLine> = self
+            .ocr_results
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !self.excluded_lines.contains(i))
+            .map(|(_, line)| line.clone())
+            .collect();
+        Some(match region {
+            Some(region) => lines_in_region(&kept, region),
+            None => kept,
+        })
     }
 
-    // ── setup / dependency dialog ─────────────────────────────────────────────
+    /// Drops the row focus and exclusions, which index results now gone.
+    fn forget_line_marks(&mut self) {
+        self.focused_line = None;
+        self.excluded_lines.clear();
+        self.results_changed();
+    }
 
-    fn draw_setup_dialog(&mut self, ctx: &egui::Context) {
-        if !self.show_setup_dialog {
-            return;
+    /// Copies the results in `copy_format`.
+    fn action_copy_results(&mut self, ctx: &egui::Context) {
+        let text = self.results_as(self.copy_format);
+        if !text.is_empty() {
+            ctx.output_mut(|o| o.copied_text = text);
+            self.copied_timer = 2.0; // show "Copied!" for 2 seconds
+        }
+    }
+
+    fn action_export_settings(&mut self) {
+        let dialog = file_dialog(&self.settings.last_export_dir)
+            .add_filter("JSON", &["json"])
+            .set_file_name("easyocr_settings.json");
+        if let Some(path) = self.pick_path(PathTarget::ExportSettings, || dialog.save_file()) {
+            self.export_settings_to(path);
         }
+    }
 
+    fn export_settings_to(&mut self, path: PathBuf) {
         let s = self.s();
-        let mut open = true;
-        egui::Window::new(s.setup_title)
-            .open(&mut open)
-            .collapsible(false)
-            .resizable(false)
-            .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
-            .min_width(500.0)
-            .show(ctx, |ui| {
-                ui.add_space(4.0);
+        self.settings_save_msg = Some(match self.settings.export(&path) {
+            Ok(()) => (s.msg_settings_exported.replacen("{}", &path.display().to_string(), 1), false),
+            Err(e) => (s.msg_settings_failed.replacen("{}", &e.to_string(), 1), true),
+        });
+    }
 
-                ui.label(
-                    RichText::new(s.setup_not_found)
-                        .strong()
-                        .color(Color32::from_rgb(251, 191, 36)),
-                );
-                ui.label(s.setup_must_install);
+    fn action_import_settings(&mut self) {
+        let dialog = file_dialog(&self.settings.last_export_dir).add_filter("JSON", &["json"]);
+        if let Some(path) = self.pick_path(PathTarget::ImportSettings, || dialog.pick_file()) {
+            self.import_settings_from(path);
+        }
+    }
+
+    fn import_settings_from(&mut self, path: PathBuf) {
+        let s = self.s();
+        self.settings_save_msg = Some(match Settings::import(&path, &self.settings) {
+            Ok(imported) => {
+                self.settings = imported;
+                (s.msg_settings_imported.replacen("{}", &path.display().to_string(), 1), false)
+            }
+            Err(e) => (s.msg_settings_import_failed.replacen("{}", &e.to_string(), 1), true),
+        });
+    }
+
+    fn action_export(&mut self, format: ExportFormat) {
+        let ext = format.extension();
+        let dialog = file_dialog(&self.settings.last_export_dir)
+            .add_filter(export_format_label(self.s(), format), &[ext])
+            .set_file_name(format!("ocr_result.{ext}"));
+        if let Some(path) = self.pick_path(PathTarget::Export(format), || dialog.save_file()) {
+            self.export_to(format, path);
+        }
+    }
+
+    fn export_to(&mut self, format: ExportFormat, path: PathBuf) {
+        if let Some(parent) = path.parent() {
+            let dir = parent.to_string_lossy().to_string();
+            self.settings.remember(|s| s.last_export_dir = dir.clone());
+        }
+        let min = self.min_confidence;
+        let content = if self.batch_results.is_empty() {
+            let image = self.image.as_ref().map(|i| i.path.as_path()).unwrap_or(Path::new(""));
+            let lines = self.chosen_lines().unwrap_or_else(|| self.ocr_results.clone());
+            format.render(image, &filter_lines(&lines, min))
+        } else {
+            let filtered: Vec<(PathBuf, OcrResult)> = self
+                .batch_results
+                .iter()
+                .map(|(path, res)| {
+                    let res = OcrResult {
+                        lines: filter_lines(&res.lines, min),
+                        unparsed: res.unparsed.clone(),
+                        stderr_note: None,
+                        raw: String::new(),
+                        error: res.error.clone(),
+                    };
+                    (path.clone(), res)
+                })
+                .collect();
+            format.render_batch(&filtered)
+        };
+        self.status_message = match std::fs::write(&path, content) {
+            Ok(()) => self
+                .s()
+                .status_exported
+                .replacen("{}", &path.display().to_string(), 1),
+            Err(e) => self
+                .s()
+                .status_export_failed
+                .replacen("{}", &e.to_string(), 1),
+        };
+    }
+
+    // ── keyboard shortcuts ───────────────────────────────────────────────────
+
+    fn handle_shortcuts(&mut self, ctx: &egui::Context) {
+        // Leave copy/paste to the widget when a text field has focus.
+        let text_focused = ctx.wants_keyboard_input();
+
+        if ctx.input_mut(|i| i.consume_shortcut(&SHORTCUT_OPEN)) {
+            self.tab = Tab::Ocr;
+            self.action_open_file(ctx);
+        }
+        if ctx.input_mut(|i| i.consume_shortcut(&SHORTCUT_RUN))
+            && self.image.is_some()
+            && !self.is_running()
+        {
+            self.tab = Tab::Ocr;
+            self.action_run_ocr();
+        }
+        if text_focused {
+            return;
+        }
+
+        // egui turns Cmd+V into a text `Paste` event, which never fires when
+        // the clipboard holds only an image, so watch for the key release.
+        let paste = ctx.input(|i| {
+            i.events.iter().any(|e| {
+                matches!(e, egui::Event::Key { key, pressed: false, modifiers, .. }
+                    if *key == SHORTCUT_PASTE.logical_key && modifiers.command)
+            })
+        });
+        if paste {
+            self.tab = Tab::Ocr;
+            self.action_paste_clipboard(ctx);
+        }
+        // Likewise Cmd+C arrives as a `Copy` event rather than a key press.
+        let copy = ctx.input(|i| i.events.iter().any(|e| matches!(e, egui::Event::Copy)));
+        if copy {
+            self.action_copy_results(ctx);
+        }
+    }
+
+    /// Up and Down move through the single-image result rows, Enter copies
+    /// the focused one and Space leaves it out of Copy and Export or brings
+    /// it back.  Returns whether the focus moved, so its row can be scrolled
+    /// to.  Text fields keep these keys while they have focus.
+    fn handle_result_keys(&mut self, ctx: &egui::Context) -> bool {
+        if !self.region_applies()
+            || self.show_plain_text
+            || self.is_running()
+            || ctx.memory(|m| m.focused().is_some())
+        {
+            return false;
+        }
+        let visible: Vec<usize> = (0..self.ocr_results.len())
+            .filter(|&i| self.ocr_results[i].meets_confidence(self.min_confidence))
+            .collect();
+        if visible.is_empty() {
+            return false;
+        }
+        let (down, up, enter, space) = ctx.input_mut(|i| {
+            (
+                i.consume_key(Modifiers::NONE, Key::ArrowDown),
+                i.consume_key(Modifiers::NONE, Key::ArrowUp),
+                i.consume_key(Modifiers::NONE, Key::Enter),
+                i.consume_key(Modifiers::NONE, Key::Space),
+            )
+        });
+        let moved = (down || up).then(|| next_focus(&visible, self.focused_line, down)).flatten();
+        if let Some(index) = moved {
+            self.focused_line = Some(index);
+        }
+        let Some(focused) = self.focused_line.filter(|f| visible.contains(f)) else {
+            return false;
+        };
+        if enter {
+            ctx.output_mut(|o| o.copied_text = self.ocr_results[focused].text.clone());
+            self.copied_timer = 2.0;
+        }
+        if space {
+            self.results_changed();
+        }
+        if space && !self.excluded_lines.remove(&focused) {
+            self.excluded_lines.insert(focused);
+        }
+        moved.is_some()
+    }
+
+    // ── poll OCR thread ──────────────────────────────────────────────────────
+
+    /// Checks the running job and, once it is done, appends the total run
+    /// time to the status message and auto-copies the results.
+    fn poll_ocr(&mut self, ctx: &egui::Context) {
+        if !self.is_running() {
+            return;
+        }
+        self.poll_ocr_state();
+        if let (OcrState::Done, Some(started)) = (&self.ocr_state, self.ocr_started) {
+            let secs = format!("{:.1}", started.elapsed().as_secs_f32());
+            self.status_message = format!(
+                "{} {}",
+                self.status_message,
+                self.s().status_done_in.replacen("{}", &secs, 1)
+            );
+            if self.settings.auto_copy {
+                self.action_copy_results(ctx);
+            }
+        }
+    }
+
+    fn poll_ocr_state(&mut self) {
+        let progress = match &self.ocr_state {
+            OcrState::Running(job) => job.latest_progress(),
+            OcrState::Batch(jobs) => jobs.iter().find_map(|(_, job)| job.latest_progress()),
+            _ => None,
+        };
+        if progress.is_some() {
+            self.ocr_progress = progress;
+        }
+
+        if let OcrState::Compare = self.ocr_state {
+            for side in &mut self.compare_sides {
+                if let Some(res) = side.job.as_ref().and_then(|job| job.try_recv()) {
+                    side.result = Some(res);
+                    side.job = None;
+                    self.results_version += 1;
+                }
+            }
+            if self.compare_sides.iter().all(|side| side.job.is_none()) {
+                let count = |i: usize| {
+                    self.compare_sides[i].result.as_ref().map_or(0, |r| r.lines.len())
+                };
+                self.status_message = self
+                    .s()
+                    .status_compare_complete
+                    .replacen("{}", &count(0).to_string(), 1)
+                    .replacen("{}", &count(1).to_string(), 1);
+                self.ocr_state = OcrState::Done;
+            }
+            return;
+        }
+
+        if let OcrState::Batch(jobs) = &mut self.ocr_state {
+            let mut finished = Vec::new();
+            jobs.retain(|(path, job)| match job.try_recv() {
+                Some(res) => {
+                    finished.push((path.clone(), res));
+                    false
+                }
+                None => true,
+            });
+            if finished.is_empty() {
+                return;
+            }
+            for (path, res) in finished {
+                let running = |i: &&mut QueueItem| i.status == QueueStatus::Running && i.path == path;
+                if let Some(item) = self.queue.iter_mut().find(running) {
+                    item.status = match res.error {
+                        Some(_) => QueueStatus::Failed,
+                        None => QueueStatus::Done(res.lines.len()),
+                    };
+                }
+                if res.error.is_none() {
+                    self.history.push(
+                        path.to_string_lossy().to_string(),
+                        res.lines.clone(),
+                        self.settings.history_limit as usize,
+                    );
+                    self.save_history();
+                }
+                self.batch_results.push((path, res));
+                self.results_changed();
+            }
+            self.run_next_queued();
+            return;
+        }
+
+        let result = if let OcrState::Running(job) = &self.ocr_state {
+            job.try_recv()
+        } else {
+            None
+        };
+
+        if let Some(res) = result {
+            if let Some(err) = res.error {
+                let kind = err.kind();
+                let summary = match kind {
+                    Some(kind) => failure_text(self.s(), kind).to_string(),
+                    None => err.summary(),
+                };
+                self.status_message = self.s().status_ocr_failed.replacen("{}", &summary, 1);
+                self.ocr_state = OcrState::Error { message: err.to_string(), kind };
+            } else {
+                let count = res.lines.len();
+                self.status_message = if count == 0 && !res.unparsed.is_empty() {
+                    unparsed_warning(self.s(), res.unparsed.len())
+                } else if let Some(note) = &res.stderr_note {
+                    let said = note.lines().collect::<Vec<_>>().join(" ");
+                    self.s().status_no_text_stderr.replacen("{}", &said, 1)
+                } else {
+                    self.s().status_ocr_complete.replacen("{}", &count.to_string(), 1)
+                };
+                self.unparsed_lines = res.unparsed;
+                self.raw_output = res.raw;
+                if let Some(loaded) = &self.image {
+                    self.history.push(
+                        loaded.path.to_string_lossy().to_string(),
+                        res.lines.clone(),
+                        self.settings.history_limit as usize,
+                    );
+                    self.save_history();
+                }
+                self.ocr_results = res.lines;
+                self.forget_line_marks();
+                self.ocr_state = OcrState::Done;
+            }
+        }
+    }
+
+    /// Writes the history to disk, reporting a failure in the status bar.
+    fn save_history(&mut self) {
+        if let Err(e) = self.history.save() {
+            self.status_message =
+                self.s().status_history_save_failed.replacen("{}", &e.to_string(), 1);
+        }
+    }
+
+    /// Show a history entry on the OCR tab: reload its image when it still
+    /// exists and restore the recorded results either way.
+    fn open_history_entry(&mut self, index: usize, ctx: &egui::Context) {
+        let Some(entry) = self.history.entries.get(index).cloned() else {
+            return;
+        };
+        let path = PathBuf::from(&entry.image_path);
+        if path.exists() {
+            self.show_image_from_path(path, ctx);
+        } else {
+            self.image = None;
+            self.batch_results.clear();
+            self.queue.clear();
+            self.unparsed_lines.clear();
+            self.raw_output.clear();
+            self.status_message = self
+                .s()
+                .status_history_image_missing
+                .replacen("{}", &entry.image_path, 1);
+        }
+        self.ocr_results = entry.lines;
+        self.forget_line_marks();
+        self.ocr_state = OcrState::Done;
+        self.tab = Tab::Ocr;
+    }
+
+    // ── region select overlay ────────────────────────────────────────────────
+
+    fn draw_region_select(&mut self, ctx: &egui::Context) {
+        let Some(sel) = &mut self.region_select else {
+            return;
+        };
+        let hint = i18n::get(&self.settings.ui_language).hint_region_select;
+        let outcome = ctx.show_viewport_immediate(
+            egui::ViewportId::from_hash_of("region_select"),
+            egui::ViewportBuilder::default()
+                .with_title(hint)
+                .with_decorations(false)
+                .with_fullscreen(true),
+            |ctx, class| {
+                let cancelled = ctx.input(|i| {
+                    i.key_pressed(egui::Key::Escape) || i.viewport().close_requested()
+                });
+                if cancelled {
+                    return RegionOutcome::Cancelled;
+                }
+                if class == egui::ViewportClass::Embedded {
+                    // The backend cannot open another window; pick inside ours.
+                    egui::Window::new(hint)
+                        .collapsible(false)
+                        .default_size(ctx.screen_rect().size() * 0.9)
+                        .show(ctx, |ui| region_select_ui(ui, sel, hint))
+                        .and_then(|r| r.inner)
+                        .unwrap_or(RegionOutcome::Pending)
+                } else {
+                    egui::CentralPanel::default()
+                        .frame(egui::Frame::none())
+                        .show(ctx, |ui| region_select_ui(ui, sel, hint))
+                        .inner
+                }
+            },
+        );
+
+        match outcome {
+            RegionOutcome::Pending => ctx.request_repaint(),
+            RegionOutcome::Cancelled => {
+                self.region_select = None;
+                self.hotkey_flow = false;
+                self.status_message = self.s().status_cancelled.into();
+            }
+            RegionOutcome::Selected([x, y, w, h]) => {
+                if let Some(sel) = self.region_select.take() {
+                    let cropped = crop_rgba(&sel.rgba, sel.width, x, y, w, h);
+                    let label = self.s().btn_screenshot_region;
+                    let source = ImageSource::Screen(sel.screen);
+                    self.load_image_from_rgba(cropped, w, h, ctx, label, source);
+                    if self.hotkey_flow && self.image.is_some() && !self.is_running() {
+                        self.action_run_ocr();
+                    }
+                }
+            }
+        }
+    }
+
+    // ── setup / dependency dialog ─────────────────────────────────────────────
+
+    /// Keeps the registered global hotkey in line with the settings.
+    fn sync_global_hotkey(&mut self, ctx: &egui::Context) {
+        let wanted = self
+            .settings
+            .global_hotkey_enabled
+            .then(|| self.settings.global_hotkey.trim().to_string());
+        if wanted == self.hotkey_spec {
+            return;
+        }
+        self.hotkey_spec = wanted.clone();
+        if self.global_hotkey.is_none() && wanted.is_some() {
+            match GlobalHotkey::new(ctx) {
+                Ok(hotkey) => self.global_hotkey = Some(hotkey),
+                Err(e) => {
+                    self.hotkey_error = Some(e);
+                    return;
+                }
+            }
+        }
+        self.hotkey_error = match &mut self.global_hotkey {
+            Some(hotkey) => hotkey.set(wanted.as_deref()).err(),
+            None => None,
+        };
+    }
+
+    /// Drives the hotkey capture: starts the region picker on a press, and
+    /// copies the result once the run it started is over.
+    fn handle_global_hotkey(&mut self, ctx: &egui::Context) {
+        self.sync_global_hotkey(ctx);
+        let pressed = self.global_hotkey.as_ref().is_some_and(GlobalHotkey::pressed);
+        if pressed && !self.hotkey_flow && self.region_select.is_none() && !self.is_running() {
+            // Capture first, so the screenshot shows what was in front.
+            self.action_screenshot_region(ctx);
+            self.hotkey_flow = self.region_select.is_some();
+            ctx.send_viewport_cmd(egui::ViewportCommand::Minimized(false));
+            ctx.send_viewport_cmd(egui::ViewportCommand::Focus);
+        } else if self.hotkey_flow && self.region_select.is_none() && !self.is_running() {
+            self.hotkey_flow = false;
+            if matches!(self.ocr_state, OcrState::Done) && !self.result_text().is_empty() {
+                self.action_copy_results(ctx);
+                self.status_message = self.s().status_hotkey_copied.into();
+            }
+        }
+    }
+
+    fn action_toggle_camera(&mut self, ctx: &egui::Context) {
+        if self.camera.take().is_some() {
+            return;
+        }
+        self.camera = Some(CameraView { camera: Camera::open(ctx), frame: None });
+    }
+
+    /// Shows the camera's newest frame, or closes it once the feed stops.
+    fn poll_camera(&mut self, ctx: &egui::Context) {
+        let Some(view) = &mut self.camera else {
+            return;
+        };
+        match view.camera.latest() {
+            Some(Ok(frame)) => {
+                let size = [frame.width() as usize, frame.height() as usize];
+                let color_image = ColorImage::from_rgba_unmultiplied(size, frame.as_raw());
+                let texture = match view.frame.take() {
+                    Some((_, mut texture)) => {
+                        texture.set(color_image, egui::TextureOptions::LINEAR);
+                        texture
+                    }
+                    None => ctx.load_texture("camera", color_image, egui::TextureOptions::LINEAR),
+                };
+                view.frame = Some((frame, texture));
+            }
+            Some(Err(e)) => {
+                self.camera = None;
+                self.status_message = self.s().status_camera_failed.replacen("{}", &e, 1);
+            }
+            None => {}
+        }
+    }
+
+    /// Loads the frame on show like a pasted image and closes the camera.
+    fn action_capture_frame(&mut self, ctx: &egui::Context) {
+        let Some((frame, _)) = self.camera.take().and_then(|view| view.frame) else {
+            return;
+        };
+        let (w, h) = (frame.width() as usize, frame.height() as usize);
+        let label = self.s().btn_capture_frame;
+        self.load_image_from_rgba(frame.into_raw(), w, h, ctx, label, ImageSource::Camera);
+    }
+
+    /// Small window asking for an image URL; closes once the download has
+    /// started, and loads the image when it arrives.
+    fn draw_url_dialog(&mut self, ctx: &egui::Context) {
+        if let Some(rx) = &self.url_download {
+            match rx.try_recv() {
+                Ok(Ok(path)) => {
+                    self.url_download = None;
+                    self.load_image_from_path(path, ctx);
+                }
+                Ok(Err(e)) => {
+                    self.url_download = None;
+                    self.status_message = self.s().status_url_failed.replacen("{}", &e, 1);
+                }
+                Err(_) => ctx.request_repaint(),
+            }
+        }
+        let s = self.s();
+        let Some(url) = &mut self.url_input else {
+            return;
+        };
+        let mut open = true;
+        let mut start = false;
+        egui::Window::new(s.url_title)
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .show(ctx, |ui| {
+                let edit = ui.add(
+                    egui::TextEdit::singleline(url)
+                        .hint_text("https://")
+                        .desired_width(420.0),
+                );
+                if ui.memory(|m| m.focused().is_none()) {
+                    edit.request_focus();
+                }
+                let entered = edit.lost_focus() && ui.input(|i| i.key_pressed(Key::Enter));
+                ui.add_space(6.0);
+                let ready = !url.trim().is_empty();
+                let clicked = ui.add_enabled(ready, egui::Button::new(s.btn_download)).clicked();
+                start = ready && (entered || clicked);
+            });
+        if start {
+            let url = self.url_input.take().unwrap_or_default();
+            self.status_message = self.s().status_downloading.replacen("{}", url.trim(), 1);
+            self.url_download = Some(download::download_image_async(&url));
+        } else if !open {
+            self.url_input = None;
+        }
+    }
+
+    /// Offers typing a path in after a file dialog came back empty, and asks
+    /// for it once taken up.
+    fn draw_path_dialog(&mut self, ctx: &egui::Context) {
+        let s = self.s();
+        if let Some((target, at)) = self.path_fallback {
+            match PATH_FALLBACK_TIME.checked_sub(at.elapsed()) {
+                Some(left) => {
+                    ctx.request_repaint_after(left);
+                    egui::Area::new(egui::Id::new("path_fallback"))
+                        .anchor(egui::Align2::RIGHT_BOTTOM, egui::vec2(-12.0, -36.0))
+                        .show(ctx, |ui| {
+                            egui::Frame::popup(ui.style()).show(ui, |ui| {
+                                let link = ui.link(s.btn_type_path).on_hover_text(s.hint_type_path);
+                                if link.clicked() {
+                                    self.path_fallback = None;
+                                    self.path_input = Some((target, String::new()));
+                                }
+                            });
+                        });
+                }
+                None => self.path_fallback = None,
+            }
+        }
+        let Some((target, path)) = &mut self.path_input else {
+            return;
+        };
+        let target = *target;
+        let mut open = true;
+        let mut done = false;
+        egui::Window::new(s.path_title)
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .show(ctx, |ui| {
+                let edit = ui.add(egui::TextEdit::singleline(path).desired_width(420.0));
+                if ui.memory(|m| m.focused().is_none()) {
+                    edit.request_focus();
+                }
+                let entered = edit.lost_focus() && ui.input(|i| i.key_pressed(Key::Enter));
+                ui.add_space(6.0);
+                let ready = !path.trim().is_empty();
+                let clicked = ui.add_enabled(ready, egui::Button::new(s.btn_use_path)).clicked();
+                done = ready && (entered || clicked);
+            });
+        if done {
+            let path = self.path_input.take().map(|(_, p)| p).unwrap_or_default();
+            self.use_path(target, PathBuf::from(path.trim()), ctx);
+        } else if !open {
+            self.path_input = None;
+        }
+    }
+
+    fn draw_setup_dialog(&mut self, ctx: &egui::Context) {
+        let p = Palette::of(ctx);
+        if !self.show_setup_dialog {
+            return;
+        }
+
+        let s = self.s();
+        // When a Python without easyocr was found, point pip at exactly that
+        // interpreter instead of whichever `pip` happens to be on PATH.
+        let module_missing = match &self.setup_status {
+            SetupStatus::ModuleMissing { python } => Some(python.clone()),
+            _ => None,
+        };
+        let mut open = true;
+        egui::Window::new(s.setup_title)
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .min_width(500.0)
+            .show(ctx, |ui| {
+                ui.add_space(4.0);
+
+                let headline = match &module_missing {
+                    Some(python) => s.setup_module_missing.replacen("{}", python, 1),
+                    None => s.setup_not_found.to_string(),
+                };
+                ui.label(RichText::new(headline).strong().color(p.warning));
+                ui.label(s.setup_must_install);
                 ui.add_space(12.0);
 
                 // ── Step 1 ───────────────────────────────────────────────────
@@ -276,17 +1761,26 @@ impl EasyOcrApp {
                 ui.label(
                     RichText::new("  https://www.python.org/downloads/")
                         .monospace()
-                        .color(Color32::from_rgb(96, 165, 250)),
+                        .color(p.accent),
                 );
                 ui.add_space(8.0);
 
                 // ── Step 2 ───────────────────────────────────────────────────
                 ui.label(RichText::new(s.setup_step2_title).strong());
-                ui.label(s.setup_step2_desc);
+                let install = match &module_missing {
+                    Some(python) => {
+                        ui.label(s.setup_step2_desc_interpreter);
+                        ocr::pip_install_command(python)
+                    }
+                    None => {
+                        ui.label(s.setup_step2_desc);
+                        "pip install easyocr".to_string()
+                    }
+                };
                 ui.label(
-                    RichText::new("  pip install easyocr")
+                    RichText::new(format!("  {install}"))
                         .monospace()
-                        .color(Color32::from_rgb(74, 222, 128))
+                        .color(p.success)
                         .size(14.0),
                 );
                 ui.add_space(8.0);
@@ -301,7 +1795,7 @@ impl EasyOcrApp {
 
                 ui.label(
                     RichText::new(s.setup_tip)
-                        .color(Color32::GRAY)
+                        .color(p.muted)
                         .small(),
                 );
                 ui.add_space(10.0);
@@ -314,16 +1808,16 @@ impl EasyOcrApp {
                             .add(
                                 egui::Button::new(
                                     RichText::new(s.setup_btn_check)
-                                        .color(Color32::WHITE)
+                                        .color(p.on_fill)
                                         .strong(),
                                 )
-                                .fill(Color32::from_rgb(37, 99, 235))
+                                .fill(p.primary_fill)
                                 .min_size(Vec2::new(120.0, 30.0)),
                             )
                             .clicked()
                         {
                             self.setup_rx =
-                                Some(ocr::check_easyocr_async(&self.settings.easyocr_exe));
+                                Some(ocr::check_easyocr_async(&self.settings.easyocr_exe, true));
                             self.setup_status = SetupStatus::Checking;
                         }
                     });
@@ -335,7 +1829,7 @@ impl EasyOcrApp {
                     if self.setup_status == SetupStatus::Checking {
                         ui.spinner();
                         ui.label(
-                            RichText::new(s.setup_checking).color(Color32::GRAY).small(),
+                            RichText::new(s.setup_checking).color(p.muted).small(),
                         );
                     }
                 });
@@ -356,6 +1850,9 @@ impl EasyOcrApp {
             tab_button(ui, s.tab_ocr, self.tab == Tab::Ocr, || {
                 self.tab = Tab::Ocr
             });
+            tab_button(ui, s.tab_history, self.tab == Tab::History, || {
+                self.tab = Tab::History
+            });
             tab_button(ui, s.tab_settings, self.tab == Tab::Settings, || {
                 self.tab = Tab::Settings
             });
@@ -363,52 +1860,135 @@ impl EasyOcrApp {
     }
 
     fn draw_ocr_tab(&mut self, ui: &mut egui::Ui, ctx: &egui::Context) {
+        let p = Palette::of(ctx);
         let s = self.s();
+        let mut hovered = None;
+        let focus_moved = self.handle_result_keys(ctx);
         // ── Toolbar ──────────────────────────────────────────────────────────
         ui.add_space(8.0);
         ui.horizontal(|ui| {
-            if toolbar_button(ui, s.btn_open_image).clicked() {
+            if toolbar_button(ui, s.btn_open_image)
+                .on_hover_text(ctx.format_shortcut(&SHORTCUT_OPEN))
+                .clicked()
+            {
                 self.action_open_file(ctx);
             }
-            if toolbar_button(ui, s.btn_paste_image).clicked() {
+            ui.add_enabled_ui(!self.settings.recent_files.is_empty(), |ui| {
+                let button = egui::Button::new("⏷")
+                    .rounding(Rounding::same(4.0))
+                    .min_size(Vec2::new(20.0, 32.0));
+                egui::menu::menu_custom_button(ui, button, |ui| {
+                    for entry in self.settings.recent_files.clone() {
+                        let name = std::path::Path::new(&entry)
+                            .file_name()
+                            .map(|n| n.to_string_lossy().to_string())
+                            .unwrap_or_else(|| entry.clone());
+                        if ui.button(name).on_hover_text(&entry).clicked() {
+                            ui.close_menu();
+                            self.action_open_recent(entry, ctx);
+                        }
+                    }
+                })
+                .response
+                .on_hover_text(s.hint_open_recent);
+            });
+            if ui
+                .add_enabled_ui(!self.is_running(), |ui| toolbar_button(ui, s.btn_open_folder))
+                .inner
+                .clicked()
+            {
+                self.action_open_folder(ctx);
+            }
+            if toolbar_button(ui, s.btn_paste_image)
+                .on_hover_text(ctx.format_shortcut(&SHORTCUT_PASTE))
+                .clicked()
+            {
                 self.action_paste_clipboard(ctx);
             }
+            if toolbar_button(ui, s.btn_from_url).clicked() {
+                self.url_input.get_or_insert_with(String::new);
+            }
+            if toolbar_button(ui, s.btn_camera).clicked() {
+                self.action_toggle_camera(ctx);
+            }
             if toolbar_button(ui, s.btn_screenshot).clicked() {
                 self.action_screenshot(ctx);
             }
-            if self.setup_status == SetupStatus::Missing {
-                if ui
+            if toolbar_button(ui, s.btn_screenshot_region).clicked() {
+                self.action_screenshot_region(ctx);
+            }
+            // Only offer a display picker when there is a choice to make.
+            if self.screen_labels.len() > 1 {
+                let mut index = self.settings.screen_index.min(self.screen_labels.len() - 1);
+                egui::ComboBox::from_id_salt("screen_picker")
+                    .selected_text(&self.screen_labels[index])
+                    .show_ui(ui, |ui| {
+                        for (i, label) in self.screen_labels.iter().enumerate() {
+                            ui.selectable_value(&mut index, i, label);
+                        }
+                    });
+                if index != self.settings.screen_index {
+                    self.settings.remember(|s| s.screen_index = index);
+                }
+            }
+            let mut auto_run = self.settings.auto_run;
+            if ui
+                .checkbox(&mut auto_run, s.lbl_auto_run)
+                .on_hover_text(s.hint_auto_run)
+                .changed()
+            {
+                self.settings.remember(|s| s.auto_run = auto_run);
+            }
+            let mut auto_copy = self.settings.auto_copy;
+            if ui
+                .checkbox(&mut auto_copy, s.lbl_auto_copy)
+                .on_hover_text(s.hint_auto_copy)
+                .changed()
+            {
+                self.settings.remember(|s| s.auto_copy = auto_copy);
+            }
+            if matches!(
+                self.setup_status,
+                SetupStatus::Missing | SetupStatus::ModuleMissing { .. }
+            ) && ui
                     .add(
                         egui::Button::new(
-                            RichText::new(s.btn_setup).color(Color32::WHITE).strong(),
+                            RichText::new(s.btn_setup).color(p.on_fill).strong(),
                         )
-                        .fill(Color32::from_rgb(202, 138, 4))
+                        .fill(p.warning_fill)
                         .rounding(Rounding::same(4.0))
                         .min_size(Vec2::new(80.0, 32.0)),
                     )
                     .on_hover_text(s.tooltip_setup)
                     .clicked()
-                {
-                    self.show_setup_dialog = true;
-                }
+            {
+                self.show_setup_dialog = true;
             }
             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                 let has_image = self.image.is_some();
-                let is_running = matches!(self.ocr_state, OcrState::Running(_));
+                let is_running = self.is_running();
                 ui.add_enabled_ui(has_image && !is_running, |ui| {
                     if ui
                         .add(
                             egui::Button::new(
-                                RichText::new(s.btn_run_ocr).color(Color32::WHITE).strong(),
+                                RichText::new(s.btn_run_ocr).color(p.on_fill).strong(),
                             )
-                            .fill(Color32::from_rgb(37, 99, 235))
+                            .fill(p.primary_fill)
                             .min_size(Vec2::new(120.0, 32.0)),
                         )
+                        .on_hover_text(ctx.format_shortcut(&SHORTCUT_RUN))
                         .clicked()
                     {
                         self.action_run_ocr();
                     }
                 });
+                // Laid out right to left: the preset picker sits between the
+                // toggle and Run OCR.
+                if self.compare {
+                    self.draw_compare_picker(ui);
+                }
+                ui.checkbox(&mut self.compare, s.lbl_compare)
+                    .on_hover_text(s.hint_compare);
             });
         });
         ui.add_space(8.0);
@@ -418,33 +1998,162 @@ impl EasyOcrApp {
         let panel_height = ui.available_height() - 40.0; // reserve status bar
         ui.horizontal(|ui| {
             // Left: image preview
+            let mut goto_page = None;
+            let mut reload = false;
+            let mut edit = false;
+            let region_applies = self.region_applies();
+            let mut capture = false;
             ui.allocate_ui(Vec2::new(ui.available_width() * 0.55, panel_height), |ui| {
                 egui::Frame::dark_canvas(ui.style())
                     .rounding(Rounding::same(6.0))
                     .show(ui, |ui| {
                         ui.set_min_size(Vec2::new(ui.available_width(), panel_height - 2.0));
-                        if let Some(loaded) = &self.image {
-                            let max = ui.available_size() - Vec2::splat(8.0);
-                            let img_aspect =
-                                loaded.width as f32 / loaded.height as f32;
-                            let (w, h) = fit_into(max.x, max.y, img_aspect);
-                            ui.centered_and_justified(|ui| {
-                                ui.image(egui::load::SizedTexture::new(
-                                    loaded.texture.id(),
-                                    Vec2::new(w, h),
-                                ));
+                        let preprocess = Preprocess::from_settings(&self.settings);
+                        if let Some(view) = &self.camera {
+                            match draw_camera_view(ui, s, view) {
+                                Some(true) => capture = true,
+                                Some(false) => self.camera = None,
+                                None => {}
+                            }
+                        } else if let Some(loaded) = &mut self.image {
+                            if loaded.show_processed
+                                && loaded.processed.as_ref().map(|(o, _)| *o) != Some(preprocess)
+                            {
+                                loaded.processed = processed_texture(ctx, &loaded.path, preprocess)
+                                    .map(|t| (preprocess, t));
+                                // Don't retry an unreadable file every frame.
+                                loaded.show_processed = loaded.processed.is_some();
+                            }
+                            let preview = ui.available_size() - Vec2::new(0.0, 22.0);
+                            ui.allocate_ui(preview, |ui| {
+                                // In a batch, outline the results of the page on show.
+                                let lines = if self.batch_results.is_empty() {
+                                    &self.ocr_results[..]
+                                } else {
+                                    self.batch_results
+                                        .iter()
+                                        .find(|(path, _)| *path == loaded.path)
+                                        .map_or(&[][..], |(_, res)| &res.lines[..])
+                                };
+                                let highlighted = self.hovered_line.map(|h| h.index);
+                                let over = draw_image_preview(
+                                    ui,
+                                    loaded,
+                                    lines,
+                                    self.min_confidence,
+                                    highlighted,
+                                    region_applies,
+                                );
+                                if let Some(index) = over {
+                                    hovered = Some(HoveredLine { index, on_image: true });
+                                }
+                            });
+                            ui.horizontal(|ui| {
+                                if let Some(index) =
+                                    self.pages.iter().position(|p| *p == loaded.path)
+                                {
+                                    let count = self.pages.len();
+                                    if ui.add_enabled(index > 0, egui::Button::new("◀").small()).clicked() {
+                                        goto_page = Some(index - 1);
+                                    }
+                                    let label = match self.page_kind {
+                                        PageKind::Document => s.lbl_page,
+                                        PageKind::Frame => s.lbl_frame,
+                                    };
+                                    ui.label(
+                                        RichText::new(
+                                            label
+                                                .replacen("{}", &(index + 1).to_string(), 1)
+                                                .replacen("{}", &count.to_string(), 1),
+                                        )
+                                        .small(),
+                                    );
+                                    // Animations have too many frames to step through.
+                                    if self.page_kind == PageKind::Frame {
+                                        let mut frame = index;
+                                        let slider = egui::Slider::new(&mut frame, 0..=count - 1)
+                                            .show_value(false);
+                                        if ui.add(slider).changed() {
+                                            goto_page = Some(frame);
+                                        }
+                                    }
+                                    if ui
+                                        .add_enabled(index + 1 < count, egui::Button::new("▶").small())
+                                        .clicked()
+                                    {
+                                        goto_page = Some(index + 1);
+                                    }
+                                }
+                                ui.label(
+                                    RichText::new(image_info_line(s, loaded))
+                                        .color(p.muted)
+                                        .small(),
+                                )
+                                .on_hover_text(s.hint_select_region);
+                                // Any image, even a temp capture, may have been
+                                // edited in another app.
+                                if ui
+                                    .small_button("✎")
+                                    .on_hover_text(s.hint_open_in_editor)
+                                    .clicked()
+                                {
+                                    edit = true;
+                                }
+                                if ui
+                                    .small_button("⟳")
+                                    .on_hover_text(s.hint_reload_image)
+                                    .clicked()
+                                {
+                                    reload = true;
+                                }
+                                if !preprocess.is_noop() {
+                                    ui.checkbox(&mut loaded.show_processed, s.lbl_show_processed)
+                                        .on_hover_text(s.hint_show_processed);
+                                }
+                                if let (Some(region), true) = (loaded.selection, region_applies) {
+                                    let inside = lines_in_region(&self.ocr_results, region).len();
+                                    let label = s
+                                        .lbl_region_lines
+                                        .replacen("{}", &inside.to_string(), 1)
+                                        .replacen("{}", &self.ocr_results.len().to_string(), 1);
+                                    ui.label(RichText::new(label).color(p.accent).small())
+                                        .on_hover_text(s.hint_region_lines);
+                                    if ui
+                                        .small_button("✕")
+                                        .on_hover_text(s.hint_clear_selection)
+                                        .clicked()
+                                    {
+                                        loaded.selection = None;
+                                    }
+                                }
                             });
+                        } else if !self.settings.seen_onboarding {
+                            if draw_onboarding(ui, s) {
+                                self.settings.remember(|s| s.seen_onboarding = true);
+                            }
                         } else {
                             ui.centered_and_justified(|ui| {
                                 ui.label(
                                     RichText::new(s.placeholder_drop_image)
-                                        .color(Color32::GRAY)
+                                        .color(p.muted)
                                         .size(16.0),
                                 );
                             });
                         }
                     });
             });
+            if let Some(index) = goto_page {
+                self.show_page(index, ctx);
+            }
+            if reload {
+                self.action_reload_image(ctx);
+            }
+            if edit {
+                self.action_open_in_editor();
+            }
+            if capture {
+                self.action_capture_frame(ctx);
+            }
 
             ui.add_space(8.0);
 
@@ -453,6 +2162,7 @@ impl EasyOcrApp {
                 ui.set_height(panel_height);
                 ui.horizontal(|ui| {
                     ui.label(RichText::new(s.lbl_results).strong());
+                    ui.checkbox(&mut self.show_plain_text, s.lbl_plain_text);
                     ui.with_layout(
                         egui::Layout::right_to_left(egui::Align::Center),
                         |ui| {
@@ -463,41 +2173,299 @@ impl EasyOcrApp {
                             };
                             if ui
                                 .add_enabled(
-                                    !self.ocr_result_text.is_empty(),
+                                    !self.result_text().is_empty(),
                                     egui::Button::new(btn_label),
                                 )
+                                .on_hover_text(ctx.format_shortcut(&SHORTCUT_COPY))
                                 .clicked()
                             {
                                 self.action_copy_results(ctx);
                             }
+                            ui.add_enabled_ui(!self.result_text().is_empty(), |ui| {
+                                ui.menu_button("⏷", |ui| {
+                                    for format in CopyFormat::ALL {
+                                        let label = copy_format_label(s, format);
+                                        if ui.radio(self.copy_format == format, label).clicked() {
+                                            ui.close_menu();
+                                            self.copy_format = format;
+                                            self.action_copy_results(ctx);
+                                        }
+                                    }
+                                })
+                                .response
+                                .on_hover_text(s.hint_copy_format);
+                            });
+                            let has_results = !self.ocr_results.is_empty()
+                                || !self.batch_results.is_empty();
+                            ui.add_enabled_ui(has_results && !self.is_running(), |ui| {
+                                ui.menu_button(s.btn_export, |ui| {
+                                    for format in ExportFormat::all() {
+                                        if ui.button(export_format_label(s, *format)).clicked() {
+                                            ui.close_menu();
+                                            self.action_export(*format);
+                                        }
+                                    }
+                                });
+                            });
                         },
                     );
                 });
                 ui.add_space(4.0);
 
-                match &self.ocr_state {
-                    OcrState::Running(_) => {
-                        ui.centered_and_justified(|ui| {
-                            ui.spinner();
+                if !self.queue.is_empty() {
+                    self.draw_queue(ui);
+                }
+
+                let all_lines = || {
+                    self.ocr_results
+                        .iter()
+                        .chain(self.batch_results.iter().flat_map(|(_, r)| &r.lines))
+                        .chain(self.compare_sides.iter().flat_map(|side| {
+                            side.result.iter().flat_map(|r| &r.lines)
+                        }))
+                };
+                // Compare columns carry their own summaries.
+                if !self.is_running() && self.compare_sides.is_empty() {
+                    if let Some(stats) = ResultStats::of(all_lines()) {
+                        ui.horizontal(|ui| {
+                            let summary = ui.label(
+                                RichText::new(stats.summary(s, self.settings.confidence_decimals))
+                                    .color(p.muted)
+                                    .small(),
+                            );
+                            if self.region_applies() {
+                                summary.on_hover_text(s.hint_result_keys);
+                            }
+                            if !stats.histogram.iter().all(|&n| n == 0) {
+                                draw_confidence_sparkline(ui, p, &stats.histogram);
+                            }
                         });
                     }
-                    OcrState::Error(err) => {
-                        egui::ScrollArea::vertical()
-                            .id_salt("err_scroll")
-                            .show(ui, |ui| {
-                                ui.label(
-                                    RichText::new(err.as_str())
-                                        .color(Color32::from_rgb(248, 113, 113))
-                                        .monospace(),
-                                );
+                }
+                if all_lines().any(OcrLine::has_position) {
+                    let total = all_lines().count();
+                    let shown = all_lines()
+                        .filter(|l| l.meets_confidence(self.min_confidence))
+                        .count();
+                    let mut min_confidence = self.min_confidence;
+                    ui.horizontal(|ui| {
+                        ui.label(s.lbl_min_confidence);
+                        ui.add(
+                            egui::Slider::new(&mut min_confidence, 0.0..=1.0)
+                                .custom_formatter(|v, _| format!("{:.0}%", v * 100.0)),
+                        );
+                        if shown < total {
+                            ui.label(
+                                RichText::new(confidence_filter_summary(s, shown, total))
+                                    .color(p.muted)
+                                    .small(),
+                            );
+                        }
+                    });
+                    self.min_confidence = min_confidence;
+                }
+                if !self.excluded_lines.is_empty() && self.region_applies() {
+                    ui.horizontal(|ui| {
+                        let count = self.excluded_lines.len().to_string();
+                        ui.label(
+                            RichText::new(s.lbl_lines_left_out.replacen("{}", &count, 1))
+                                .color(p.muted)
+                                .small(),
+                        )
+                        .on_hover_text(s.hint_result_keys);
+                        if ui.small_button("✕").clicked() {
+                            self.excluded_lines.clear();
+                            self.results_changed();
+                        }
+                    });
+                }
+
+                if !self.unparsed_lines.is_empty() && !self.is_running() {
+                    ui.label(
+                        RichText::new(unparsed_warning(s, self.unparsed_lines.len()))
+                            .color(p.warning),
+                    );
+                    egui::CollapsingHeader::new(s.lbl_raw_output)
+                        .id_salt("unparsed_output")
+                        .show(ui, |ui| {
+                            egui::ScrollArea::vertical()
+                                .id_salt("unparsed_scroll")
+                                .max_height(120.0)
+                                .show(ui, |ui| {
+                                    ui.label(
+                                        RichText::new(self.unparsed_lines.join("\n"))
+                                            .monospace()
+                                            .small(),
+                                    );
+                                });
+                        });
+                }
+
+                if !self.raw_output.trim().is_empty() && !self.is_running() {
+                    let header = egui::CollapsingHeader::new(s.lbl_cli_output)
+                        .id_salt("cli_output")
+                        .show(ui, |ui| {
+                            if ui.small_button(s.btn_copy).clicked() {
+                                ui.ctx().output_mut(|o| o.copied_text = self.raw_output.clone());
+                            }
+                            egui::ScrollArea::vertical()
+                                .id_salt("cli_output_scroll")
+                                .max_height(160.0)
+                                .show(ui, |ui| {
+                                    ui.label(
+                                        RichText::new(self.raw_output.trim_end())
+                                            .monospace()
+                                            .small(),
+                                    );
+                                });
+                        });
+                    header.header_response.on_hover_text(s.hint_cli_output);
+                }
+
+                match &self.ocr_state {
+                    _ if !self.compare_sides.is_empty() => {
+                        let min_conf = self.min_confidence;
+                        let decimals = self.settings.confidence_decimals;
+                        let tags = self.settings.show_script_tags.then_some(s);
+                        let mut edited = false;
+                        ui.columns(self.compare_sides.len(), |columns| {
+                            for (i, (ui, side)) in
+                                columns.iter_mut().zip(&mut self.compare_sides).enumerate()
+                            {
+                                edited |= draw_compare_side(ui, s, i, side, min_conf, decimals, tags);
+                            }
+                        });
+                        if edited {
+                            self.results_changed();
+                        }
+                    }
+                    OcrState::Running(_) => {
+                        ui.centered_and_justified(|ui| {
+                            ui.spinner();
+                        });
+                    }
+                    OcrState::Error { message: err, kind } => {
+                        egui::ScrollArea::vertical()
+                            .id_salt("err_scroll")
+                            .show(ui, |ui| match *kind {
+                                Some(kind) => {
+                                    ui.label(
+                                        RichText::new(failure_text(s, kind))
+                                            .color(p.error)
+                                            .strong(),
+                                    );
+                                    egui::CollapsingHeader::new(s.lbl_error_details)
+                                        .id_salt("err_details")
+                                        .show(ui, |ui| {
+                                            ui.label(RichText::new(err.as_str()).monospace());
+                                        });
+                                }
+                                None => {
+                                    ui.label(
+                                        RichText::new(err.as_str())
+                                            .color(p.error)
+                                            .monospace(),
+                                    );
+                                }
+                            });
+                    }
+                    _ if !self.show_plain_text
+                        && (!self.ocr_results.is_empty() || !self.batch_results.is_empty()) =>
+                    {
+                        let min_conf = self.min_confidence;
+                        let decimals = self.settings.confidence_decimals;
+                        let tags = self.settings.show_script_tags.then_some(s);
+                        let shown = self.image.as_ref().map(|loaded| loaded.path.clone());
+                        let hover = self.hovered_line;
+                        let on_image = hover.filter(|h| h.on_image).map(|h| h.index);
+                        let scroll_to_hover = on_image != self.scrolled_to_line;
+                        self.scrolled_to_line = on_image;
+                        let mut edited = false;
+                        egui::ScrollArea::vertical()
+                            .id_salt("result_rows_scroll")
+                            .show(ui, |ui| {
+                                let mut over_row = |(row, changed): (Option<usize>, bool)| {
+                                    if let Some(index) = row {
+                                        hovered = Some(HoveredLine { index, on_image: false });
+                                    }
+                                    edited |= changed;
+                                };
+                                if self.batch_results.is_empty() {
+                                    let marks = RowMarks {
+                                        hover,
+                                        scroll_to_hover,
+                                        focused: self.focused_line,
+                                        scroll_to_focus: focus_moved,
+                                        excluded: Some(&self.excluded_lines),
+                                    };
+                                    over_row(draw_result_rows(
+                                        ui,
+                                        &mut self.ocr_results,
+                                        min_conf,
+                                        decimals,
+                                        tags,
+                                        marks,
+                                    ));
+                                }
+                                for (path, res) in &mut self.batch_results {
+                                    let name = path
+                                        .file_name()
+                                        .map(|n| n.to_string_lossy().to_string())
+                                        .unwrap_or_default();
+                                    ui.label(RichText::new(name).strong());
+                                    match &res.error {
+                                        Some(err) => {
+                                            ui.label(
+                                                RichText::new(err.summary())
+                                                    .color(p.error),
+                                            );
+                                        }
+                                        // Only the page on show is linked to the image.
+                                        None if shown.as_ref() == Some(path) => {
+                                            over_row(draw_result_rows(
+                                                ui,
+                                                &mut res.lines,
+                                                min_conf,
+                                                decimals,
+                                                tags,
+                                                RowMarks {
+                                                    hover,
+                                                    scroll_to_hover,
+                                                    ..RowMarks::default()
+                                                },
+                                            ));
+                                        }
+                                        None => {
+                                            over_row((
+                                                None,
+                                                draw_result_rows(
+                                                    ui,
+                                                    &mut res.lines,
+                                                    min_conf,
+                                                    decimals,
+                                                    tags,
+                                                    RowMarks::default(),
+                                                )
+                                                .1,
+                                            ));
+                                        }
+                                    }
+                                    ui.add_space(6.0);
+                                }
                             });
+                        if edited {
+                            self.results_changed();
+                        }
                     }
                     _ => {
                         egui::ScrollArea::vertical()
                             .id_salt("result_scroll")
                             .show(ui, |ui| {
+                                // Edits here stay in this text, for copying by
+                                // hand; the rows view corrects the lines
+                                // themselves.
                                 ui.add(
-                                    egui::TextEdit::multiline(&mut self.ocr_result_text)
+                                    egui::TextEdit::multiline(self.result_text())
                                         .desired_width(f32::INFINITY)
                                         .desired_rows(30)
                                         .font(FontId::monospace(14.0)),
@@ -511,234 +2479,1092 @@ impl EasyOcrApp {
         // ── Status bar ───────────────────────────────────────────────────────
         ui.separator();
         ui.horizontal(|ui| {
-            let is_running = matches!(self.ocr_state, OcrState::Running(_));
+            let is_running = self.is_running();
+            if self.page_split.is_some() && !is_running {
+                ui.spinner();
+            }
             if is_running {
                 ui.spinner();
+                if let Some(started) = self.ocr_started {
+                    ui.label(
+                        RichText::new(format!("{:.0}s", started.elapsed().as_secs_f32()))
+                            .monospace()
+                            .color(p.muted)
+                            .small(),
+                    );
+                }
+                if ui.small_button(s.btn_cancel).clicked() {
+                    self.action_cancel_ocr();
+                }
                 ui.add_space(4.0);
             }
+            if let OcrState::Batch(_) = &self.ocr_state {
+                let (done, total) = self.queue_progress();
+                let fraction = done as f32 / total.max(1) as f32;
+                ui.add(
+                    egui::ProgressBar::new(fraction)
+                        .desired_width(160.0)
+                        .show_percentage(),
+                );
+            }
             ui.label(
                 RichText::new(&self.status_message)
-                    .color(Color32::LIGHT_GRAY)
+                    .color(p.subtle)
                     .small(),
             );
+            if let OcrState::Running(job) = &self.ocr_state {
+                let seen = job.lines_so_far();
+                if seen > 0 {
+                    ui.label(
+                        RichText::new(s.status_lines_so_far.replacen("{}", &seen.to_string(), 1))
+                            .color(p.muted)
+                            .small(),
+                    );
+                }
+            }
+            if let (true, Some(progress)) = (is_running, &self.ocr_progress) {
+                ui.label(RichText::new(format!("— {}", progress)).color(p.muted).small());
+            }
+            if let Some(megapixels) = self.large_image_megapixels() {
+                ui.label(
+                    RichText::new(
+                        s.status_large_image.replacen("{}", &format!("{:.0}", megapixels), 1),
+                    )
+                    .color(p.warning)
+                    .small(),
+                );
+                if ui.small_button(s.btn_downscale).clicked() {
+                    self.settings.preprocess_downscale = true;
+                }
+            }
         });
+        if hovered != self.hovered_line {
+            self.hovered_line = hovered;
+            // The other pane was drawn with the old highlight.
+            ctx.request_repaint();
+        }
     }
 
-    fn draw_settings_tab(&mut self, ui: &mut egui::Ui) {
-        egui::ScrollArea::vertical().show(ui, |ui| {
-            let s = self.s();
-            ui.add_space(12.0);
-            section_header(ui, s.section_languages);
-            ui.horizontal(|ui| {
-                ui.label(s.lbl_language_codes);
-                ui.add(
-                    egui::TextEdit::singleline(&mut self.settings.languages)
-                        .desired_width(200.0)
-                        .hint_text(s.hint_language_codes),
-                );
-                ui.label(
-                    RichText::new(s.lbl_comma_separated)
-                        .color(Color32::GRAY)
-                        .small(),
-                );
+    fn draw_history_tab(&mut self, ui: &mut egui::Ui, ctx: &egui::Context) {
+        let p = Palette::of(ctx);
+        let s = self.s();
+        ui.add_space(8.0);
+        ui.horizontal(|ui| {
+            ui.label(
+                RichText::new(s.lbl_history_count.replacen(
+                    "{}",
+                    &self.history.entries.len().to_string(),
+                    1,
+                ))
+                .strong(),
+            );
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui
+                    .add_enabled(
+                        !self.history.entries.is_empty(),
+                        egui::Button::new(s.btn_clear_history),
+                    )
+                    .clicked()
+                {
+                    self.history.entries.clear();
+                    self.save_history();
+                }
             });
-            ui.add_space(12.0);
+        });
+        ui.separator();
 
-            section_header(ui, s.section_hardware);
-            ui.checkbox(&mut self.settings.gpu, s.lbl_gpu);
-            ui.horizontal(|ui| {
-                ui.label(s.lbl_workers);
-                ui.add(
-                    egui::DragValue::new(&mut self.settings.workers)
-                        .range(0..=64)
-                        .suffix(s.lbl_workers_suffix),
-                );
-                ui.label(
-                    RichText::new(s.hint_workers_auto).color(Color32::GRAY).small(),
-                );
+        if self.history.entries.is_empty() {
+            ui.centered_and_justified(|ui| {
+                ui.label(RichText::new(s.placeholder_history).color(p.muted));
             });
-            ui.checkbox(&mut self.settings.quantize, s.lbl_quantize);
-            ui.add_space(12.0);
-
-            section_header(ui, s.section_decoder);
-            for dec in Decoder::all() {
-                let label = match dec {
-                    Decoder::Greedy => s.decoder_greedy,
-                    Decoder::BeamSearch => s.decoder_beam,
-                    Decoder::WordBeamSearch => s.decoder_word_beam,
-                };
-                ui.radio_value(&mut self.settings.decoder, dec.clone(), label);
-            }
-            ui.add_space(4.0);
-            ui.add_enabled_ui(
-                matches!(
-                    self.settings.decoder,
-                    Decoder::BeamSearch | Decoder::WordBeamSearch
-                ),
-                |ui| {
-                    ui.horizontal(|ui| {
-                        ui.label(s.lbl_beam_width);
-                        ui.add(
-                            egui::DragValue::new(&mut self.settings.beam_width)
-                                .range(1..=50),
-                        );
-                    });
-                },
-            );
-            ui.add_space(12.0);
+            return;
+        }
 
-            section_header(ui, s.section_recognition);
-            ui.horizontal(|ui| {
-                ui.label(s.lbl_batch_size);
-                ui.add(
-                    egui::DragValue::new(&mut self.settings.batch_size)
-                        .range(1..=64),
-                );
-            });
-            ui.horizontal(|ui| {
-                ui.label(s.lbl_min_size);
-                ui.add(
-                    egui::DragValue::new(&mut self.settings.min_size)
-                        .range(1..=200),
-                );
-            });
-            ui.checkbox(&mut self.settings.paragraph, s.lbl_paragraph);
-            ui.horizontal(|ui| {
-                ui.label(s.lbl_margin);
-                ui.add(
-                    egui::Slider::new(&mut self.settings.add_margin, 0.0..=0.5)
-                        .fixed_decimals(2),
-                );
+        let mut clicked = None;
+        egui::ScrollArea::vertical()
+            .id_salt("history_scroll")
+            .show(ui, |ui| {
+                for (i, entry) in self.history.entries.iter().enumerate() {
+                    let name = std::path::Path::new(&entry.image_path)
+                        .file_name()
+                        .map(|n| n.to_string_lossy().to_string())
+                        .unwrap_or_else(|| entry.image_path.clone());
+                    let preview = entry.lines.first().map(|l| l.text.as_str()).unwrap_or("");
+                    let label = format!(
+                        "{}  ·  {}  ·  {}\n{}",
+                        history::format_timestamp(entry.timestamp),
+                        name,
+                        s.lbl_history_lines.replacen("{}", &entry.lines.len().to_string(), 1),
+                        preview
+                    );
+                    if ui
+                        .add(egui::Button::new(label).frame(false).wrap())
+                        .on_hover_text(&entry.image_path)
+                        .clicked()
+                    {
+                        clicked = Some(i);
+                    }
+                    ui.separator();
+                }
             });
-            ui.add_space(12.0);
+        if let Some(i) = clicked {
+            self.open_history_entry(i, ctx);
+        }
+    }
 
-            section_header(ui, s.section_thresholds);
-            threshold_row(ui, s.lbl_text_threshold, &mut self.settings.text_threshold, s.hint_text_threshold);
-            threshold_row(ui, s.lbl_low_text, &mut self.settings.low_text, s.hint_low_text);
-            threshold_row(ui, s.lbl_link_threshold, &mut self.settings.link_threshold, s.hint_link_threshold);
-            threshold_row(ui, s.lbl_contrast_ths, &mut self.settings.contrast_ths, s.hint_contrast_ths);
-            ui.horizontal(|ui| {
-                ui.label(s.lbl_adjust_contrast);
-                ui.add(
-                    egui::Slider::new(&mut self.settings.adjust_contrast, 0.0..=1.0)
-                        .fixed_decimals(2),
-                );
-                ui.label(
-                    RichText::new(s.hint_adjust_contrast)
-                        .color(Color32::GRAY)
-                        .small(),
-                );
+    /// The batch queue.  Pending images can be dragged into another order or
+    /// removed before they run.
+    fn draw_queue(&mut self, ui: &mut egui::Ui) {
+        let s = self.s();
+        let p = Palette::of(ui.ctx());
+        let (done, total) = self.queue_progress();
+        let title = s
+            .lbl_queue
+            .replacen("{}", &done.to_string(), 1)
+            .replacen("{}", &total.to_string(), 1);
+        let mut moved = None;
+        let mut removed = None;
+        egui::CollapsingHeader::new(title)
+            .id_salt("batch_queue")
+            .default_open(true)
+            .show(ui, |ui| {
+                egui::ScrollArea::vertical()
+                    .id_salt("queue_scroll")
+                    .max_height(160.0)
+                    .show(ui, |ui| {
+                        for (i, item) in self.queue.iter().enumerate() {
+                            let row = draw_queue_row(ui, s, p, i, item, &mut removed);
+                            if item.status != QueueStatus::Pending {
+                                continue;
+                            }
+                            if row.dnd_hover_payload::<usize>().is_some() {
+                                ui.painter().rect_stroke(row.rect, 2.0, Stroke::new(1.0, p.accent));
+                            }
+                            if let Some(from) = row.dnd_release_payload::<usize>() {
+                                moved = Some((*from, i));
+                            }
+                        }
+                    });
             });
-            ui.add_space(12.0);
+        if let Some(i) = removed {
+            self.queue.remove(i);
+        }
+        if let Some((from, to)) = moved {
+            let item = self.queue.remove(from);
+            self.queue.insert(to, item);
+        }
+    }
 
-            section_header(ui, s.section_paths);
-            ui.horizontal(|ui| {
-                ui.label(s.lbl_model_dir);
-                ui.add(
-                    egui::TextEdit::singleline(&mut self.settings.model_storage_directory)
-                        .desired_width(260.0)
-                        .hint_text(s.hint_model_dir),
-                );
-                if ui.small_button(s.btn_browse).clicked() {
-                    if let Some(dir) = rfd::FileDialog::new().pick_folder() {
-                        self.settings.model_storage_directory =
-                            dir.to_string_lossy().to_string();
+    fn draw_compare_picker(&mut self, ui: &mut egui::Ui) {
+        let s = self.s();
+        let selected = match &self.compare_preset {
+            ComparePreset::Builtin(preset) => builtin_preset_label(s, *preset).to_string(),
+            ComparePreset::User(name) => name.clone(),
+        };
+        egui::ComboBox::from_id_salt("compare_preset")
+            .selected_text(selected)
+            .show_ui(ui, |ui| {
+                for preset in BuiltinPreset::all() {
+                    ui.selectable_value(
+                        &mut self.compare_preset,
+                        ComparePreset::Builtin(*preset),
+                        builtin_preset_label(s, *preset),
+                    );
+                }
+                for preset in &self.user_presets {
+                    ui.selectable_value(
+                        &mut self.compare_preset,
+                        ComparePreset::User(preset.name.clone()),
+                        &preset.name,
+                    );
+                }
+            })
+            .response
+            .on_hover_text(s.hint_compare_preset);
+    }
+
+    fn draw_presets_row(&mut self, ui: &mut egui::Ui) {
+        let s = self.s();
+        ui.horizontal(|ui| {
+            let mut delete = None;
+            ui.menu_button(s.btn_apply_preset, |ui| {
+                for preset in BuiltinPreset::all() {
+                    if ui.button(builtin_preset_label(s, *preset)).clicked() {
+                        preset.fields().apply_to(&mut self.settings);
+                        ui.close_menu();
                     }
                 }
+                if !self.user_presets.is_empty() {
+                    ui.separator();
+                }
+                for (i, preset) in self.user_presets.iter().enumerate() {
+                    ui.horizontal(|ui| {
+                        if ui.button(&preset.name).clicked() {
+                            preset.apply_to(&mut self.settings);
+                            ui.close_menu();
+                        }
+                        if ui.small_button("🗑").on_hover_text(s.btn_delete).clicked() {
+                            delete = Some(i);
+                        }
+                    });
+                }
             });
-            ui.horizontal(|ui| {
-                ui.label(s.lbl_easyocr_exe);
+            if let Some(i) = delete {
+                self.user_presets.remove(i);
+                self.save_user_presets();
+            }
+
+            ui.add(
+                egui::TextEdit::singleline(&mut self.preset_name_input)
+                    .desired_width(160.0)
+                    .hint_text(s.hint_preset_name),
+            );
+            let name = self.preset_name_input.trim().to_string();
+            if ui
+                .add_enabled(!name.is_empty(), egui::Button::new(s.btn_save_preset))
+                .clicked()
+            {
+                let preset = Preset {
+                    name: name.clone(),
+                    fields: PresetFields::from_settings(&self.settings),
+                };
+                // Saving under an existing name overwrites that preset.
+                match self.user_presets.iter_mut().find(|p| p.name == name) {
+                    Some(existing) => *existing = preset,
+                    None => self.user_presets.push(preset),
+                }
+                self.preset_name_input.clear();
+                self.save_user_presets();
+            }
+        });
+    }
+
+    /// The user's named language lists, each setting `languages` in one
+    /// click, and a menu that saves the current list as a new one.
+    fn draw_language_shortcuts(&mut self, ui: &mut egui::Ui) {
+        let s = self.s();
+        let current = lang::split_codes(&self.settings.languages);
+        ui.horizontal_wrapped(|ui| {
+            let mut delete = None;
+            for (i, shortcut) in self.settings.language_shortcuts.iter().enumerate() {
+                let selected = lang::split_codes(&shortcut.languages) == current;
+                let button = ui
+                    .selectable_label(selected, &shortcut.label)
+                    .on_hover_text(s.hint_language_shortcut.replacen("{}", &shortcut.languages, 1));
+                if button.clicked() {
+                    self.settings.languages = shortcut.languages.clone();
+                }
+                button.context_menu(|ui| {
+                    if ui.button(s.btn_delete).clicked() {
+                        delete = Some(i);
+                        ui.close_menu();
+                    }
+                });
+            }
+            if let Some(i) = delete {
+                self.settings.remember(|settings| {
+                    if i < settings.language_shortcuts.len() {
+                        settings.language_shortcuts.remove(i);
+                    }
+                });
+            }
+
+            ui.menu_button(s.btn_add_shortcut, |ui| {
                 ui.add(
-                    egui::TextEdit::singleline(&mut self.settings.easyocr_exe)
-                        .desired_width(260.0)
-                        .hint_text(s.hint_easyocr_exe),
+                    egui::TextEdit::singleline(&mut self.shortcut_name_input)
+                        .desired_width(160.0)
+                        .hint_text(s.hint_shortcut_name),
                 );
-                if ui.small_button(s.btn_browse).clicked() {
-                    if let Some(f) = rfd::FileDialog::new().pick_file() {
-                        self.settings.easyocr_exe = f.to_string_lossy().to_string();
-                    }
+                let label = self.shortcut_name_input.trim().to_string();
+                let can_save = !label.is_empty() && !current.is_empty();
+                if ui.add_enabled(can_save, egui::Button::new(s.btn_save_shortcut)).clicked() {
+                    let shortcut = LanguageShortcut { label, languages: current.join(",") };
+                    // Saving under an existing label replaces that shortcut.
+                    self.settings.remember(|settings| {
+                        let shortcuts = &mut settings.language_shortcuts;
+                        match shortcuts.iter_mut().find(|l| l.label == shortcut.label) {
+                            Some(existing) => *existing = shortcut.clone(),
+                            None => shortcuts.push(shortcut.clone()),
+                        }
+                    });
+                    self.shortcut_name_input.clear();
+                    ui.close_menu();
                 }
             });
-            ui.add_space(12.0);
+        });
+    }
 
-            section_header(ui, s.section_ui);
-            ui.horizontal(|ui| {
-                ui.label(s.lbl_ui_language);
-                ui.radio_value(&mut self.settings.ui_language, UiLanguage::Chinese, "中文");
-                ui.radio_value(&mut self.settings.ui_language, UiLanguage::English, "English");
-            });
-            ui.add_space(16.0);
+    /// Selected languages as removable chips plus a searchable "add" menu.
+    /// Edits are written straight back to the comma-separated `languages`
+    /// string, which stays the source of truth for the CLI.
+    fn draw_language_picker(&mut self, ui: &mut egui::Ui) {
+        let s = self.s();
+        let mut codes = lang::split_codes(&self.settings.languages);
+        let mut changed = false;
 
-            ui.horizontal(|ui| {
-                let s = self.s();
+        ui.horizontal_wrapped(|ui| {
+            let mut remove = None;
+            for (i, code) in codes.iter().enumerate() {
+                let label = match lang::name_of(code) {
+                    Some(name) => format!("{} ({})  ✕", name, code),
+                    None => format!("{}  ✕", code),
+                };
                 if ui
                     .add(
-                        egui::Button::new(
-                            RichText::new(s.btn_save_settings)
-                                .color(Color32::WHITE)
-                                .strong(),
-                        )
-                        .fill(Color32::from_rgb(37, 99, 235))
-                        .min_size(Vec2::new(140.0, 32.0)),
+                        egui::Button::new(RichText::new(label).small())
+                            .rounding(Rounding::same(10.0)),
                     )
+                    .on_hover_text(s.btn_delete)
                     .clicked()
                 {
-                    match self.settings.save() {
-                        Ok(()) => {
-                            self.settings_save_msg =
-                                Some((s.msg_settings_saved.into(), false));
+                    remove = Some(i);
+                }
+            }
+            if let Some(i) = remove {
+                codes.remove(i);
+                changed = true;
+            }
+
+            ui.menu_button(s.btn_add_language, |ui| {
+                ui.add(
+                    egui::TextEdit::singleline(&mut self.language_search)
+                        .desired_width(200.0)
+                        .hint_text(s.hint_search_language),
+                )
+                .request_focus();
+                let query = self.language_search.to_lowercase();
+                egui::ScrollArea::vertical().max_height(240.0).show(ui, |ui| {
+                    for (code, name) in lang::LANGUAGES {
+                        if codes.iter().any(|c| c == code)
+                            || !(code.contains(&query) || name.to_lowercase().contains(&query))
+                        {
+                            continue;
                         }
-                        Err(e) => {
-                            self.settings_save_msg =
-                                Some((s.msg_settings_failed.replacen("{}", &e, 1), true));
+                        if ui.button(format!("{} ({})", name, code)).clicked() {
+                            codes.push(code.to_string());
+                            changed = true;
+                            self.language_search.clear();
+                            ui.close_menu();
                         }
                     }
-                }
+                });
+            });
+        });
 
-                if ui.button(s.btn_reset).clicked() {
-                    self.settings = Settings::default();
-                    self.settings_save_msg = None;
-                }
+        if changed {
+            self.settings.languages = codes.join(",");
+        }
+    }
 
-                if let Some((msg, is_err)) = &self.settings_save_msg {
-                    ui.label(
-                        RichText::new(msg.as_str()).color(if *is_err {
-                            Color32::from_rgb(248, 113, 113)
-                        } else {
-                            Color32::from_rgb(74, 222, 128)
-                        }),
-                    );
+    fn save_user_presets(&mut self) {
+        if let Err(e) = Preset::save_user(&self.user_presets) {
+            self.settings_save_msg = Some((self.s().msg_settings_failed.replacen("{}", &e.to_string(), 1), true));
+        }
+    }
+
+    /// Where the models live and how much space they take, so the large
+    /// first-run download isn't a mystery.
+    fn draw_model_dir_usage(&mut self, ui: &mut egui::Ui) {
+        const REFRESH: Duration = Duration::from_secs(10);
+        let s = self.s();
+        let p = Palette::of(ui.ctx());
+        let dir = ocr::effective_model_dir(&self.settings);
+        let stale = self
+            .model_dir_usage
+            .as_ref()
+            .is_none_or(|(measured, at, _)| *measured != dir || at.elapsed() > REFRESH);
+        if stale {
+            let size = dir_size(&dir).ok();
+            self.model_dir_usage = Some((dir.clone(), Instant::now(), size));
+        }
+        let size = self.model_dir_usage.as_ref().and_then(|(_, _, size)| *size);
+        let shown = dir.display().to_string();
+        ui.horizontal(|ui| {
+            let text = match size {
+                Some(bytes) => s
+                    .lbl_model_usage
+                    .replacen("{}", &shown, 1)
+                    .replacen("{}", &format_bytes(bytes), 1),
+                None => s.lbl_model_missing.replacen("{}", &shown, 1),
+            };
+            ui.label(RichText::new(text).color(p.muted).small());
+            let open = ui.add_enabled(
+                size.is_some(),
+                egui::Button::new(s.btn_show_model_folder).small(),
+            );
+            if open.clicked() {
+                if let Err(e) = open_in_default_app(&dir) {
+                    self.settings_save_msg = Some((e.to_string(), true));
                 }
-            });
-            ui.add_space(20.0);
+            }
         });
     }
-}
 
-impl eframe::App for EasyOcrApp {
-    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
-        // Poll setup availability check.
-        if self.setup_status == SetupStatus::Checking {
-            if let Some(rx) = &self.setup_rx {
-                if let Ok(available) = rx.try_recv() {
-                    self.setup_rx = None;
-                    if available {
-                        self.setup_status = SetupStatus::Ready;
-                        self.show_setup_dialog = false;
-                    } else {
-                        self.setup_status = SetupStatus::Missing;
-                        self.show_setup_dialog = true;
-                        self.status_message = self.s().status_easyocr_missing.into();
-                    }
-                } else {
-                    ctx.request_repaint();
+    /// A settings section's header, which folds the section away or opens
+    /// it again when clicked.  Returns whether its contents should be drawn;
+    /// the state is remembered across restarts.
+    fn section_open(&mut self, ui: &mut egui::Ui, id: &str, title: &str) -> bool {
+        let open = !self.settings.collapsed_sections.iter().any(|c| c == id);
+        let arrow = if open { "▼" } else { "▶" };
+        let header = ui
+            .add(
+                egui::Label::new(RichText::new(format!("{} {}", arrow, title)).strong().size(14.0))
+                    .sense(egui::Sense::click()),
+            )
+            .on_hover_cursor(egui::CursorIcon::PointingHand);
+        if header.clicked() {
+            self.settings.remember(|s| {
+                s.collapsed_sections.retain(|c| c != id);
+                if open {
+                    s.collapsed_sections.push(id.to_string());
                 }
-            }
+            });
         }
+        ui.separator();
+        ui.add_space(4.0);
+        open != header.clicked()
+    }
+
+    /// The GUI's version and those of Python and EasyOCR, copyable for bug
+    /// reports.
+    fn draw_about(&mut self, ui: &mut egui::Ui) {
+        let s = self.s();
+        if self.versions.is_none() && self.versions_rx.is_none() {
+            self.versions_rx = Some(ocr::check_versions_async(&self.settings.easyocr_exe));
+        }
+        let probed = |version: Option<&String>| match (&self.versions, version) {
+            (_, Some(v)) => v.clone(),
+            (None, _) => s.lbl_version_checking.to_string(),
+            (Some(_), None) => s.lbl_version_unknown.to_string(),
+        };
+        let versions = self.versions.as_ref();
+        let rows = [
+            (s.lbl_version_gui, env!("CARGO_PKG_VERSION").to_string()),
+            (s.lbl_version_python, probed(versions.and_then(|v| v.python.as_ref()))),
+            (s.lbl_version_easyocr, probed(versions.and_then(|v| v.easyocr.as_ref()))),
+        ];
+        egui::Grid::new("about_versions").num_columns(2).show(ui, |ui| {
+            for (label, version) in &rows {
+                ui.label(*label);
+                ui.label(RichText::new(version).monospace());
+                ui.end_row();
+            }
+        });
+        if ui.button(s.btn_copy_versions).clicked() {
+            let text = rows
+                .iter()
+                .map(|(label, version)| format!("{} {}", label, version))
+                .collect::<Vec<_>>()
+                .join("\n");
+            ui.ctx().output_mut(|o| o.copied_text = text);
+            self.settings_save_msg = Some((s.msg_versions_copied.into(), false));
+        }
+    }
+
+    fn draw_settings_tab(&mut self, ui: &mut egui::Ui) {
+        let p = Palette::of(ui.ctx());
+        // A fixed id lets egui's persisted memory restore the scroll offset.
+        egui::ScrollArea::vertical().id_salt("settings_scroll").show(ui, |ui| {
+            let s = self.s();
+            ui.add_space(12.0);
+            if self.section_open(ui, "presets", s.section_presets) {
+                self.draw_presets_row(ui);
+            }
+            ui.add_space(12.0);
+
+            if self.section_open(ui, "languages", s.section_languages) {
+                self.draw_language_shortcuts(ui);
+                self.draw_language_picker(ui);
+                egui::CollapsingHeader::new(s.lbl_advanced_codes)
+                    .id_salt("advanced_language_codes")
+                    .show(ui, |ui| {
+                        ui.horizontal(|ui| {
+                            ui.label(s.lbl_language_codes);
+                            ui.add(
+                                egui::TextEdit::singleline(&mut self.settings.languages)
+                                    .desired_width(200.0)
+                                    .hint_text(s.hint_language_codes),
+                            );
+                            ui.label(
+                                RichText::new(s.lbl_comma_separated)
+                                    .color(p.muted)
+                                    .small(),
+                            );
+                        });
+                    });
+                if let Err(problem) = lang::validate(&ocr::parse_languages(&self.settings.languages)) {
+                    ui.label(
+                        RichText::new(language_problem_text(s, &problem))
+                            .color(p.error)
+                            .small(),
+                    );
+                }
+            }
+            ui.add_space(12.0);
+
+            if self.section_open(ui, "hardware", s.section_hardware) {
+                ui.horizontal(|ui| {
+                    ui.checkbox(&mut self.settings.gpu, s.lbl_gpu);
+                    let (text, color) = match self.cuda_available {
+                        Some(true) => (s.hint_cuda_detected, p.success),
+                        Some(false) => (s.hint_cuda_missing, p.warning),
+                        None if self.cuda_rx.is_some() => (s.hint_cuda_checking, p.muted),
+                        None => ("", p.muted),
+                    };
+                    ui.label(RichText::new(text).color(color).small());
+                });
+                if self.settings.gpu {
+                    ui.horizontal(|ui| {
+                        ui.label(s.lbl_gpu_device);
+                        ui.add(
+                            egui::TextEdit::singleline(&mut self.settings.gpu_device)
+                                .hint_text(s.hint_gpu_device_placeholder)
+                                .desired_width(80.0),
+                        )
+                        .on_hover_text(s.hint_gpu_device);
+                    });
+                }
+                ui.horizontal(|ui| {
+                    ui.label(s.lbl_workers);
+                    ui.add(
+                        egui::DragValue::new(&mut self.settings.workers)
+                            .range(0..=64)
+                            .suffix(s.lbl_workers_suffix),
+                    );
+                    let auto = ocr::effective_workers(0).to_string();
+                    ui.label(
+                        RichText::new(s.hint_workers_auto.replacen("{}", &auto, 1))
+                            .color(p.muted)
+                            .small(),
+                    );
+                });
+                ui.horizontal(|ui| {
+                    ui.label(s.lbl_batch_concurrency);
+                    ui.add(
+                        egui::DragValue::new(&mut self.settings.batch_concurrency)
+                            .range(1..=MAX_BATCH_CONCURRENCY),
+                    )
+                    .on_hover_text(s.hint_batch_concurrency);
+                    if ocr::uses_server(&self.settings) && self.settings.batch_concurrency > 1 {
+                        ui.label(RichText::new(s.lbl_batch_serialized).color(p.muted).small());
+                    }
+                });
+                ui.horizontal(|ui| {
+                    ui.label(s.lbl_timeout);
+                    ui.add(
+                        egui::DragValue::new(&mut self.settings.timeout_secs)
+                            .range(0..=3600)
+                            .suffix(s.lbl_seconds_suffix),
+                    );
+                    ui.label(RichText::new(s.hint_timeout).color(p.muted).small());
+                });
+                ui.checkbox(&mut self.settings.quantize, s.lbl_quantize);
+            }
+            ui.add_space(12.0);
+
+            if self.section_open(ui, "decoder", s.section_decoder) {
+                for dec in Decoder::all() {
+                    let label = match dec {
+                        Decoder::Greedy => s.decoder_greedy,
+                        Decoder::BeamSearch => s.decoder_beam,
+                        Decoder::WordBeamSearch => s.decoder_word_beam,
+                    };
+                    ui.radio_value(&mut self.settings.decoder, dec.clone(), label);
+                }
+                ui.add_space(4.0);
+                ui.add_enabled_ui(
+                    matches!(
+                        self.settings.decoder,
+                        Decoder::BeamSearch | Decoder::WordBeamSearch
+                    ),
+                    |ui| {
+                        ui.horizontal(|ui| {
+                            ui.label(s.lbl_beam_width);
+                            ui.add(
+                                egui::DragValue::new(&mut self.settings.beam_width)
+                                    .range(1..=50),
+                            );
+                        });
+                    },
+                );
+            }
+            ui.add_space(12.0);
+
+            if self.section_open(ui, "recognition", s.section_recognition) {
+                ui.horizontal(|ui| {
+                    ui.label(s.lbl_batch_size);
+                    ui.add(
+                        egui::DragValue::new(&mut self.settings.batch_size)
+                            .range(1..=64),
+                    );
+                });
+                ui.horizontal(|ui| {
+                    ui.label(s.lbl_min_size);
+                    ui.add(
+                        egui::DragValue::new(&mut self.settings.min_size)
+                            .range(1..=200),
+                    );
+                });
+                ui.horizontal(|ui| {
+                    ui.label(s.lbl_canvas_size);
+                    ui.add(
+                        egui::DragValue::new(&mut self.settings.canvas_size)
+                            .range(256..=8192)
+                            .speed(16.0),
+                    )
+                    .on_hover_text(s.hint_canvas_size);
+                });
+                ui.horizontal(|ui| {
+                    ui.label(s.lbl_mag_ratio);
+                    ui.add(
+                        egui::DragValue::new(&mut self.settings.mag_ratio)
+                            .range(0.5..=5.0)
+                            .speed(0.05)
+                            .fixed_decimals(2),
+                    )
+                    .on_hover_text(s.hint_mag_ratio);
+                });
+                ui.checkbox(&mut self.settings.paragraph, s.lbl_paragraph);
+                ui.add_enabled_ui(self.settings.paragraph, |ui| {
+                    ui.checkbox(&mut self.settings.join_paragraph_lines, s.lbl_join_paragraph_lines)
+                        .on_hover_text(s.hint_join_paragraph_lines);
+                    ui.horizontal(|ui| {
+                        ui.label(s.lbl_x_ths);
+                        ui.add(
+                            egui::DragValue::new(&mut self.settings.x_ths)
+                                .range(0.0..=5.0)
+                                .speed(0.05)
+                                .fixed_decimals(2),
+                        )
+                        .on_hover_text(s.hint_x_ths);
+                        ui.label(s.lbl_y_ths);
+                        ui.add(
+                            egui::DragValue::new(&mut self.settings.y_ths)
+                                .range(0.0..=5.0)
+                                .speed(0.05)
+                                .fixed_decimals(2),
+                        )
+                        .on_hover_text(s.hint_y_ths);
+                    });
+                });
+                ui.checkbox(&mut self.settings.detail, s.lbl_detail)
+                    .on_hover_text(s.hint_detail);
+                ui.checkbox(&mut self.settings.detect_only, s.lbl_detect_only)
+                    .on_hover_text(s.hint_detect_only);
+                ui.add_enabled(
+                    self.settings.detail,
+                    egui::Checkbox::new(&mut self.settings.json_output, s.lbl_json_output),
+                )
+                .on_hover_text(s.hint_json_output);
+                let server = ui
+                    .add_enabled(
+                        !self.settings.detect_only,
+                        egui::Checkbox::new(
+                            &mut self.settings.persistent_server,
+                            s.lbl_persistent_server,
+                        ),
+                    )
+                    .on_hover_text(s.hint_persistent_server);
+                if server.changed() && !self.settings.persistent_server {
+                    ocr::stop_server();
+                }
+                ui.checkbox(&mut self.settings.quiet, s.lbl_quiet).on_hover_text(s.hint_quiet);
+                ui.horizontal(|ui| {
+                    ui.checkbox(&mut self.settings.sort_by_position, s.lbl_sort_by_position)
+                        .on_hover_text(s.hint_sort_by_position);
+                    ui.add_enabled(
+                        (self.settings.sort_by_position || self.settings.vertical_text)
+                            && self.settings.detail,
+                        egui::DragValue::new(&mut self.settings.row_tolerance)
+                            .range(0.0..=2.0)
+                            .speed(0.01)
+                            .fixed_decimals(2),
+                    )
+                    .on_hover_text(s.hint_row_tolerance);
+                });
+                ui.checkbox(&mut self.settings.vertical_text, s.lbl_vertical_text)
+                    .on_hover_text(s.hint_vertical_text);
+                ui.horizontal(|ui| {
+                    ui.label(s.lbl_margin);
+                    ui.add(
+                        egui::Slider::new(&mut self.settings.add_margin, 0.0..=0.5)
+                            .fixed_decimals(2),
+                    );
+                });
+                ui.horizontal(|ui| {
+                    ui.label(s.lbl_rotation_info);
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.settings.rotation_info)
+                            .desired_width(160.0)
+                            .hint_text(s.hint_rotation_info),
+                    );
+                    ui.label(
+                        RichText::new(s.lbl_comma_separated)
+                            .color(p.muted)
+                            .small(),
+                    );
+                });
+                ui.horizontal(|ui| {
+                    ui.label(s.lbl_allowlist);
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.settings.allowlist)
+                            .desired_width(160.0)
+                            .hint_text(s.hint_allowlist_example),
+                    )
+                    .on_hover_text(s.hint_allowlist);
+                });
+                ui.horizontal(|ui| {
+                    ui.label(s.lbl_blocklist);
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.settings.blocklist)
+                            .desired_width(160.0),
+                    )
+                    .on_hover_text(s.hint_blocklist);
+                });
+            }
+            ui.add_space(12.0);
+
+            if self.section_open(ui, "preprocessing", s.section_preprocessing) {
+                ui.checkbox(&mut self.settings.preprocess_grayscale, s.lbl_preprocess_grayscale);
+                ui.checkbox(&mut self.settings.preprocess_contrast, s.lbl_preprocess_contrast)
+                    .on_hover_text(s.hint_preprocess_contrast);
+                ui.checkbox(&mut self.settings.preprocess_upscale, s.lbl_preprocess_upscale)
+                    .on_hover_text(s.hint_preprocess_upscale);
+                ui.add_enabled(
+                    !self.settings.preprocess_upscale,
+                    egui::Checkbox::new(&mut self.settings.preprocess_downscale, s.lbl_preprocess_downscale),
+                )
+                .on_hover_text(s.hint_preprocess_downscale);
+            }
+            ui.add_space(12.0);
+
+            if self.section_open(ui, "thresholds", s.section_thresholds) {
+                let defaults = Settings::default();
+                let settings = &mut self.settings;
+                for (label, value, default, hint) in [
+                    (s.lbl_text_threshold, &mut settings.text_threshold, defaults.text_threshold, s.hint_text_threshold),
+                    (s.lbl_low_text, &mut settings.low_text, defaults.low_text, s.hint_low_text),
+                    (s.lbl_link_threshold, &mut settings.link_threshold, defaults.link_threshold, s.hint_link_threshold),
+                    (s.lbl_contrast_ths, &mut settings.contrast_ths, defaults.contrast_ths, s.hint_contrast_ths),
+                    (s.lbl_adjust_contrast, &mut settings.adjust_contrast, defaults.adjust_contrast, s.hint_adjust_contrast),
+                ] {
+                    threshold_row(ui, s, label, value, default, hint);
+                }
+            }
+            ui.add_space(12.0);
+
+            if self.section_open(ui, "paths", s.section_paths) {
+                ui.horizontal(|ui| {
+                    ui.label(s.lbl_model_dir);
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.settings.model_storage_directory)
+                            .desired_width(260.0)
+                            .hint_text(s.hint_model_dir),
+                    );
+                    if ui.small_button(s.btn_browse).clicked() {
+                        if let Some(dir) = rfd::FileDialog::new().pick_folder() {
+                            self.settings.model_storage_directory =
+                                dir.to_string_lossy().to_string();
+                        }
+                    }
+                });
+                self.draw_model_dir_usage(ui);
+                ui.horizontal(|ui| {
+                    ui.label(s.lbl_model_mirror).on_hover_text(s.hint_model_mirror);
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.settings.model_download_url)
+                            .desired_width(260.0)
+                            .hint_text(s.hint_model_mirror_field),
+                    );
+                });
+                ui.horizontal(|ui| {
+                    ui.label(s.lbl_captures_dir);
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.settings.save_captures_dir)
+                            .desired_width(260.0)
+                            .hint_text(s.hint_captures_dir),
+                    );
+                    if ui.small_button(s.btn_browse).clicked() {
+                        if let Some(dir) = rfd::FileDialog::new().pick_folder() {
+                            self.settings.save_captures_dir = dir.to_string_lossy().to_string();
+                        }
+                    }
+                });
+                ui.horizontal(|ui| {
+                    ui.label(s.lbl_capture_format).on_hover_text(s.hint_capture_format);
+                    ui.radio_value(&mut self.settings.temp_format, TempFormat::Png, "PNG");
+                    ui.radio_value(&mut self.settings.temp_format, TempFormat::Jpeg, "JPEG");
+                    ui.add_enabled_ui(self.settings.temp_format == TempFormat::Jpeg, |ui| {
+                        ui.label(s.lbl_jpeg_quality);
+                        ui.add(
+                            egui::DragValue::new(&mut self.settings.temp_jpeg_quality).range(1..=100),
+                        );
+                    });
+                });
+                ui.horizontal(|ui| {
+                    ui.label(s.lbl_easyocr_exe);
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.settings.easyocr_exe)
+                            .desired_width(260.0)
+                            .hint_text(s.hint_easyocr_exe),
+                    );
+                    if ui.small_button(s.btn_browse).clicked() {
+                        if let Some(f) = rfd::FileDialog::new().pick_file() {
+                            self.settings.easyocr_exe = f.to_string_lossy().to_string();
+                        }
+                    }
+                });
+                ui.horizontal(|ui| {
+                    ui.label(s.lbl_extra_args);
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.settings.extra_args)
+                            .desired_width(260.0)
+                            .hint_text(s.hint_extra_args_example),
+                    )
+                    .on_hover_text(s.hint_extra_args);
+                    if let Err(e) = ocr::split_args(&self.settings.extra_args) {
+                        ui.label(RichText::new(e).color(p.error).small());
+                    }
+                });
+            }
+            ui.add_space(12.0);
+
+            if self.section_open(ui, "ui", s.section_ui) {
+                ui.horizontal(|ui| {
+                    ui.label(s.lbl_ui_language);
+                    egui::ComboBox::from_id_salt("ui_language")
+                        .selected_text(self.settings.ui_language.native_name())
+                        .show_ui(ui, |ui| {
+                            for lang in UiLanguage::all() {
+                                ui.selectable_value(
+                                    &mut self.settings.ui_language,
+                                    lang.clone(),
+                                    lang.native_name(),
+                                );
+                            }
+                        });
+                });
+                ui.horizontal(|ui| {
+                    ui.label(s.lbl_theme);
+                    ui.radio_value(&mut self.settings.theme, Theme::Dark, s.theme_dark);
+                    ui.radio_value(&mut self.settings.theme, Theme::Light, s.theme_light);
+                    ui.radio_value(&mut self.settings.theme, Theme::System, s.theme_system);
+                });
+                ui.horizontal(|ui| {
+                    ui.label(s.lbl_history_limit);
+                    ui.add(egui::DragValue::new(&mut self.settings.history_limit).range(1..=1000));
+                });
+                ui.horizontal(|ui| {
+                    ui.label(s.lbl_confidence_decimals);
+                    ui.add(egui::DragValue::new(&mut self.settings.confidence_decimals).range(0..=3));
+                });
+                ui.checkbox(&mut self.settings.show_script_tags, s.lbl_script_tags)
+                    .on_hover_text(s.hint_script_tags);
+                ui.horizontal(|ui| {
+                    ui.checkbox(&mut self.settings.global_hotkey_enabled, s.lbl_global_hotkey)
+                        .on_hover_text(s.hint_global_hotkey);
+                    ui.add_enabled(
+                        self.settings.global_hotkey_enabled,
+                        egui::TextEdit::singleline(&mut self.settings.global_hotkey)
+                            .hint_text("Ctrl+Shift+O")
+                            .desired_width(140.0),
+                    );
+                    if let Some(err) = &self.hotkey_error {
+                        ui.label(
+                            RichText::new(s.msg_hotkey_failed.replacen("{}", err, 1))
+                                .color(p.error)
+                                .small(),
+                        );
+                    }
+                });
+            }
+            ui.add_space(12.0);
+
+            if self.section_open(ui, "about", s.section_about) {
+                self.draw_about(ui);
+            }
+            ui.add_space(16.0);
+
+            ui.horizontal(|ui| {
+                let s = self.s();
+                if ui
+                    .add(
+                        egui::Button::new(
+                            RichText::new(s.btn_save_settings)
+                                .color(p.on_fill)
+                                .strong(),
+                        )
+                        .fill(p.primary_fill)
+                        .min_size(Vec2::new(140.0, 32.0)),
+                    )
+                    .clicked()
+                {
+                    match self.settings.save() {
+                        Ok(()) => {
+                            self.settings_save_msg =
+                                Some((s.msg_settings_saved.into(), false));
+                        }
+                        Err(e) => {
+                            self.settings_save_msg =
+                                Some((s.msg_settings_failed.replacen("{}", &e.to_string(), 1), true));
+                        }
+                    }
+                }
+
+                if ui.button(s.btn_reset).clicked() {
+                    let previous = std::mem::take(&mut self.settings);
+                    self.reset_undo = Some((previous, Instant::now()));
+                    self.settings_save_msg = Some((s.msg_settings_reset.into(), false));
+                }
+                if let Some((_, at)) = &self.reset_undo {
+                    match RESET_UNDO_TIME.checked_sub(at.elapsed()) {
+                        Some(left) => {
+                            ui.ctx().request_repaint_after(left);
+                            if ui.link(s.btn_undo_reset).clicked() {
+                                if let Some((previous, _)) = self.reset_undo.take() {
+                                    self.settings = previous;
+                                }
+                                self.settings_save_msg = None;
+                            }
+                        }
+                        None => self.reset_undo = None,
+                    }
+                }
+
+                if ui
+                    .button(s.btn_copy_command)
+                    .on_hover_text(s.hint_copy_command)
+                    .clicked()
+                {
+                    let image = self
+                        .image
+                        .as_ref()
+                        .map_or_else(|| PathBuf::from("image.png"), |img| img.path.clone());
+                    let command = ocr::command_line(&self.settings, &image);
+                    ui.ctx().output_mut(|o| o.copied_text = command);
+                    self.settings_save_msg = Some((s.msg_command_copied.into(), false));
+                }
+
+                if ui.button(s.btn_export_settings).clicked() {
+                    self.action_export_settings();
+                }
+                if ui.button(s.btn_import_settings).clicked() {
+                    self.action_import_settings();
+                }
+
+                if let Some((msg, is_err)) = &self.settings_save_msg {
+                    ui.label(
+                        RichText::new(msg.as_str()).color(if *is_err {
+                            p.error
+                        } else {
+                            p.success
+                        }),
+                    );
+                }
+            });
+            ui.add_space(20.0);
+        });
+    }
+
+    /// Records the window placement so `on_exit` can persist it.  While
+    /// maximized or minimized only the flag changes, so the restored size is
+    /// the last normal one.
+    fn track_window_geometry(&mut self, ctx: &egui::Context) {
+        let geometry = ctx.input(|i| {
+            let viewport = i.viewport();
+            if viewport.minimized == Some(true) || viewport.fullscreen == Some(true) {
+                return None;
+            }
+            let maximized = viewport.maximized == Some(true);
+            if maximized {
+                let mut geometry = self.window_geometry.or(self.settings.window)?;
+                geometry.maximized = true;
+                return Some(geometry);
+            }
+            Some(WindowGeometry {
+                position: viewport.outer_rect.map(|r| [r.min.x, r.min.y]),
+                size: viewport.inner_rect.map(|r| [r.width(), r.height()])?,
+                maximized,
+            })
+        });
+        if geometry.is_some() {
+            self.window_geometry = geometry;
+        }
+    }
+}
+
+impl eframe::App for EasyOcrApp {
+    fn on_exit(&mut self, _gl: Option<&eframe::glow::Context>) {
+        remove_temp_captures();
+        let (geometry, tab) = (self.window_geometry, self.tab);
+        self.settings.remember(|s| {
+            s.window = geometry.or(s.window);
+            s.tab = tab.name().to_string();
+        });
+    }
+
+    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        theme::apply(ctx, self.settings.theme);
+        let p = Palette::of(ctx);
+        self.track_window_geometry(ctx);
+
+        // Poll setup availability check.
+        if self.setup_status == SetupStatus::Checking {
+            if let Some(rx) = &self.setup_rx {
+                if let Ok(resolution) = rx.try_recv() {
+                    self.setup_rx = None;
+                    self.setup_status = match resolution {
+                        ocr::Resolution::Found { .. } => SetupStatus::Ready,
+                        ocr::Resolution::ModuleMissing { python } => {
+                            SetupStatus::ModuleMissing { python }
+                        }
+                        ocr::Resolution::NotFound => SetupStatus::Missing,
+                    };
+                    if self.setup_status == SetupStatus::Ready {
+                        self.show_setup_dialog = false;
+                        if self.cuda_available.is_none() && self.cuda_rx.is_none() {
+                            self.cuda_rx = Some(ocr::check_cuda_async(&self.settings.easyocr_exe));
+                        }
+                    } else {
+                        self.show_setup_dialog = true;
+                        self.status_message = self.s().status_easyocr_missing.into();
+                    }
+                } else {
+                    ctx.request_repaint();
+                }
+            }
+        }
+
+        if let Some(rx) = &self.cuda_rx {
+            match rx.try_recv() {
+                Ok(available) => {
+                    self.cuda_available = available;
+                    self.cuda_rx = None;
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => ctx.request_repaint(),
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => self.cuda_rx = None,
+            }
+        }
+
+        if let Some(rx) = &self.versions_rx {
+            match rx.try_recv() {
+                Ok(versions) => {
+                    self.versions = Some(versions);
+                    self.versions_rx = None;
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => ctx.request_repaint(),
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => self.versions_rx = None,
+            }
+        }
+
+        self.handle_shortcuts(ctx);
+        self.handle_global_hotkey(ctx);
+
+        self.poll_page_split(ctx);
 
         // Poll background OCR thread.
-        self.poll_ocr();
-        if matches!(self.ocr_state, OcrState::Running(_)) {
+        self.poll_ocr(ctx);
+        if self.is_running() {
             ctx.request_repaint();
         }
 
@@ -753,12 +3579,11 @@ impl eframe::App for EasyOcrApp {
         }
 
         // Handle file drag-and-drop.
-        if !ctx.input(|i| i.raw.dropped_files.is_empty()) {
-            if let Some(file) = ctx.input(|i| i.raw.dropped_files.first().cloned()) {
-                if let Some(path) = file.path {
-                    self.load_image_from_path(path, ctx);
-                }
-            }
+        let dropped: Vec<PathBuf> =
+            ctx.input(|i| i.raw.dropped_files.iter().filter_map(|f| f.path.clone()).collect());
+        if !dropped.is_empty() {
+            self.tab = Tab::Ocr;
+            self.action_drop_files(dropped, ctx);
         }
 
         // ── Top panel: title + tabs ──────────────────────────────────────────
@@ -769,7 +3594,7 @@ impl eframe::App for EasyOcrApp {
                     RichText::new("EasyOCR")
                         .strong()
                         .size(20.0)
-                        .color(Color32::from_rgb(96, 165, 250)),
+                        .color(p.accent),
                 );
                 ui.add_space(16.0);
                 self.draw_tab_bar(ui);
@@ -781,27 +3606,35 @@ impl eframe::App for EasyOcrApp {
         egui::CentralPanel::default().show(ctx, |ui| {
             match self.tab {
                 Tab::Ocr => self.draw_ocr_tab(ui, ctx),
+                Tab::History => self.draw_history_tab(ui, ctx),
                 Tab::Settings => self.draw_settings_tab(ui),
             }
         });
 
         // ── Setup dialog (rendered on top of everything else) ────────────────
         self.draw_setup_dialog(ctx);
+        self.draw_url_dialog(ctx);
+        self.draw_path_dialog(ctx);
+        self.poll_camera(ctx);
+
+        // ── Region picker (separate fullscreen viewport) ─────────────────────
+        self.draw_region_select(ctx);
     }
 }
 
 // ── widget helpers ────────────────────────────────────────────────────────────
 
 fn tab_button(ui: &mut egui::Ui, label: &str, active: bool, on_click: impl FnOnce()) {
+    let p = Palette::of(ui.ctx());
     let fill = if active {
-        Color32::from_rgb(37, 99, 235)
+        p.primary_fill
     } else {
         Color32::TRANSPARENT
     };
     let text_color = if active {
-        Color32::WHITE
+        p.on_fill
     } else {
-        Color32::LIGHT_GRAY
+        p.subtle
     };
     let btn = egui::Button::new(RichText::new(label).color(text_color))
         .fill(fill)
@@ -821,21 +3654,1043 @@ fn toolbar_button(ui: &mut egui::Ui, label: &str) -> egui::Response {
     )
 }
 
-fn section_header(ui: &mut egui::Ui, title: &str) {
-    ui.label(RichText::new(title).strong().size(14.0));
-    ui.separator();
-    ui.add_space(4.0);
-}
-
-fn threshold_row(ui: &mut egui::Ui, label: &str, value: &mut f32, hint: &str) {
-    ui.horizontal(|ui| {
-        ui.label(label);
-        ui.add(egui::Slider::new(value, 0.0..=1.0).fixed_decimals(2));
-        ui.label(RichText::new(hint).color(Color32::GRAY).small());
+/// First-run tips shown in place of the empty preview.  Returns whether
+/// they were dismissed.
+fn draw_onboarding(ui: &mut egui::Ui, s: &i18n::Strings) -> bool {
+    let p = Palette::of(ui.ctx());
+    let paste = ui.ctx().format_shortcut(&SHORTCUT_PASTE);
+    let tips = [
+        s.onboarding_drop.to_string(),
+        s.onboarding_paste.replacen("{}", &paste, 1),
+        s.onboarding_screenshot.to_string(),
+        s.onboarding_settings.to_string(),
+    ];
+    let mut dismissed = false;
+    ui.vertical_centered(|ui| {
+        ui.add_space((ui.available_height() / 2.0 - 90.0).max(12.0));
+        ui.label(RichText::new(s.onboarding_title).strong().size(18.0));
+        ui.add_space(8.0);
+        for tip in &tips {
+            ui.label(RichText::new(format!("•  {}", tip)).color(p.muted));
+        }
+        ui.add_space(12.0);
+        dismissed = ui.button(s.btn_onboarding_dismiss).clicked();
     });
+    dismissed
 }
 
-// ── image utilities ───────────────────────────────────────────────────────────
+/// The live camera preview with Capture and Close buttons underneath.
+/// Returns `Some(true)` for Capture and `Some(false)` for Close.
+fn draw_camera_view(ui: &mut egui::Ui, s: &i18n::Strings, view: &CameraView) -> Option<bool> {
+    let p = Palette::of(ui.ctx());
+    let preview = ui.available_size() - Vec2::new(0.0, 28.0);
+    ui.allocate_ui(preview, |ui| {
+        ui.centered_and_justified(|ui| match &view.frame {
+            Some((_, texture)) => {
+                ui.add(egui::Image::new(texture).shrink_to_fit());
+            }
+            None => {
+                ui.label(RichText::new(s.lbl_camera_starting).color(p.muted).size(16.0));
+            }
+        });
+    });
+    let mut clicked = None;
+    ui.horizontal(|ui| {
+        let capture = ui.add_enabled(
+            view.frame.is_some(),
+            egui::Button::new(RichText::new(s.btn_capture_frame).color(p.on_fill).strong())
+                .fill(p.primary_fill),
+        );
+        if capture.clicked() {
+            clicked = Some(true);
+        }
+        if ui.button(s.btn_close_camera).clicked() {
+            clicked = Some(false);
+        }
+    });
+    clicked
+}
+
+/// One queue entry: a drag handle and remove button while it is pending,
+/// otherwise how it went.  Sets `removed` to `index` when removed.
+fn draw_queue_row(
+    ui: &mut egui::Ui,
+    s: &i18n::Strings,
+    p: &Palette,
+    index: usize,
+    item: &QueueItem,
+    removed: &mut Option<usize>,
+) -> egui::Response {
+    let name = item
+        .path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    ui.horizontal(|ui| {
+        if item.status == QueueStatus::Pending {
+            ui.dnd_drag_source(egui::Id::new(("queue_item", index)), index, |ui| {
+                ui.label(RichText::new("☰").color(p.muted));
+            })
+            .response
+            .on_hover_text(s.hint_queue_drag);
+        } else {
+            ui.add_space(14.0);
+        }
+        ui.label(RichText::new(name).small());
+        match item.status {
+            QueueStatus::Pending => {
+                if ui.small_button("✕").on_hover_text(s.hint_queue_remove).clicked() {
+                    *removed = Some(index);
+                }
+            }
+            QueueStatus::Running => {
+                ui.spinner();
+            }
+            QueueStatus::Done(lines) => {
+                let text = s.lbl_queue_lines.replacen("{}", &lines.to_string(), 1);
+                ui.label(RichText::new(text).color(p.muted).small());
+            }
+            QueueStatus::Failed => {
+                ui.label(RichText::new(s.lbl_queue_failed).color(p.error).small());
+            }
+        }
+    })
+    .response
+}
+
+/// One row per line: the confidence percentage, coloured by
+/// `confidence_color`, followed by the recognised text in an editable field
+/// so misreads can be fixed before copying or exporting.  Text-only results
+/// have no confidence to show, and detect-only boxes show their geometry
+/// instead of text.  Lines below `min_confidence` are skipped.  The row of
+/// `hover` is highlighted, and scrolled to when its box has just been
+/// hovered in the image.  Returns the index of the row under the pointer,
+/// and whether any text was edited.
+fn draw_result_rows(
+    ui: &mut egui::Ui,
+    lines: &mut [OcrLine],
+    min_confidence: f32,
+    decimals: u8,
+    tags: Option<&i18n::Strings>,
+    marks: RowMarks,
+) -> (Option<usize>, bool) {
+    let p = Palette::of(ui.ctx());
+    let mut hovered = None;
+    let mut edited = false;
+    for (index, line) in lines.iter_mut().enumerate() {
+        if !line.meets_confidence(min_confidence) {
+            continue;
+        }
+        let highlight = marks.hover.filter(|h| h.index == index);
+        let fill = if highlight.is_some() {
+            ui.visuals().selection.bg_fill.gamma_multiply(0.5)
+        } else {
+            Color32::TRANSPARENT
+        };
+        let focused = marks.focused == Some(index);
+        let stroke = if focused { Stroke::new(1.0, p.accent) } else { Stroke::NONE };
+        let excluded = marks.excluded.is_some_and(|set| set.contains(&index));
+        let row = egui::Frame::none()
+            .fill(fill)
+            .stroke(stroke)
+            .rounding(3.0)
+            .show(ui, |ui| {
+                if excluded {
+                    ui.multiply_opacity(0.4);
+                }
+                edited |= draw_result_row(ui, p, line, decimals, tags);
+            })
+            .response;
+        if (marks.scroll_to_hover && highlight.is_some_and(|h| h.on_image))
+            || (focused && marks.scroll_to_focus)
+        {
+            row.scroll_to_me(None);
+        }
+        if ui.rect_contains_pointer(row.rect) {
+            hovered = Some(index);
+        }
+    }
+    (hovered, edited)
+}
+
+/// The row the arrow keys move the focus to among the `visible` ones, or
+/// `None` at either end.  With no row focused yet, Down starts at the top
+/// and Up at the bottom.
+fn next_focus(visible: &[usize], focused: Option<usize>, down: bool) -> Option<usize> {
+    match focused.and_then(|f| visible.iter().position(|&i| i == f)) {
+        None if down => visible.first().copied(),
+        None => visible.last().copied(),
+        Some(at) if down => visible.get(at + 1).copied(),
+        Some(at) => at.checked_sub(1).map(|at| visible[at]),
+    }
+}
+
+/// How [`draw_result_rows`] marks up rows beyond their content.
+#[derive(Clone, Copy, Default)]
+struct RowMarks<'a> {
+    hover: Option<HoveredLine>,
+    /// The box of `hover` has just been hovered in the image; its row is
+    /// scrolled into view
+    scroll_to_hover: bool,
+    /// The row picked with the arrow keys, scrolled into view when it has
+    /// just moved
+    focused: Option<usize>,
+    scroll_to_focus: bool,
+    /// Rows left out of Copy and Export, drawn faded
+    excluded: Option<&'a HashSet<usize>>,
+}
+
+/// One result line; `tags` labels it with its script when set.  Returns
+/// whether its text was edited.
+fn draw_result_row(
+    ui: &mut egui::Ui,
+    p: &Palette,
+    line: &mut OcrLine,
+    decimals: u8,
+    tags: Option<&i18n::Strings>,
+) -> bool {
+    if line.is_detection_only() {
+        let bounds = egui::Rect::from_points(&line.bbox.map(|[x, y]| egui::pos2(x, y)));
+        ui.label(
+            RichText::new(format!(
+                "({:.0}, {:.0})  {:.0}×{:.0}",
+                bounds.min.x,
+                bounds.min.y,
+                bounds.width(),
+                bounds.height()
+            ))
+                .monospace()
+                .color(p.muted),
+        );
+        return false;
+    }
+    ui.horizontal(|ui| {
+        if line.has_position() {
+            ui.label(
+                // Pad to the width of 100% so the text column lines up.
+                RichText::new(format!(
+                    "{:>1$}",
+                    percent(line.confidence, decimals),
+                    percent(1.0, decimals).len()
+                ))
+                    .monospace()
+                    .color(confidence_color(p, line.confidence)),
+            );
+        }
+        if let Some((s, script)) = tags.zip(line.script()) {
+            ui.label(RichText::new(script_label(s, script)).small().color(p.muted));
+        }
+        ui.add(
+            egui::TextEdit::singleline(&mut line.text)
+                .font(FontId::proportional(14.0))
+                .desired_width(f32::INFINITY),
+        )
+        .changed()
+    })
+    .inner
+}
+
+/// Number of bars in the confidence sparkline; each covers 10 %.
+const HISTOGRAM_BINS: usize = 10;
+
+/// One-line quality summary of a run, shown above the results.
+#[derive(Debug, PartialEq)]
+struct ResultStats {
+    lines: usize,
+    /// Whitespace-separated words
+    words: usize,
+    chars: usize,
+    /// Han, kana and hangul characters, which are read one at a time
+    /// rather than as words
+    cjk_chars: usize,
+    /// Words made only of such characters
+    cjk_words: usize,
+    /// Mean and minimum confidence; `None` for text-only results.
+    confidence: Option<(f32, f32)>,
+    histogram: [usize; HISTOGRAM_BINS],
+}
+
+impl ResultStats {
+    fn of<'a>(lines: impl Iterator<Item = &'a OcrLine>) -> Option<Self> {
+        let mut stats = Self {
+            lines: 0,
+            words: 0,
+            chars: 0,
+            cjk_chars: 0,
+            cjk_words: 0,
+            confidence: None,
+            histogram: [0; HISTOGRAM_BINS],
+        };
+        let (mut sum, mut min, mut scored) = (0.0, f32::INFINITY, 0);
+        for line in lines {
+            stats.lines += 1;
+            for word in line.text.split_whitespace() {
+                stats.words += 1;
+                stats.cjk_words += usize::from(word.chars().all(is_cjk));
+            }
+            stats.chars += line.text.chars().count();
+            stats.cjk_chars += line.text.chars().filter(|&c| is_cjk(c)).count();
+            if line.has_confidence() {
+                sum += line.confidence;
+                min = min.min(line.confidence);
+                scored += 1;
+                let bin = (line.confidence * HISTOGRAM_BINS as f32) as usize;
+                stats.histogram[bin.min(HISTOGRAM_BINS - 1)] += 1;
+            }
+        }
+        if scored > 0 {
+            stats.confidence = Some((sum / scored as f32, min));
+        }
+        (stats.lines > 0).then_some(stats)
+    }
+
+    /// Rough reading time: 230 words a minute, and 400 characters a minute
+    /// for CJK text, which has no spaces to split words on.
+    fn reading_minutes(&self) -> f32 {
+        (self.words - self.cjk_words) as f32 / 230.0 + self.cjk_chars as f32 / 400.0
+    }
+
+    fn summary(&self, s: &i18n::Strings, decimals: u8) -> String {
+        let mut text = s
+            .lbl_result_stats
+            .replacen("{}", &self.lines.to_string(), 1)
+            .replacen("{}", &self.words.to_string(), 1)
+            .replacen("{}", &self.chars.to_string(), 1);
+        let minutes = self.reading_minutes();
+        if minutes >= 1.0 {
+            text += &s.lbl_reading_time.replacen("{}", &format!("{:.0}", minutes), 1);
+        }
+        if let Some((mean, min)) = self.confidence {
+            text += &s
+                .lbl_result_confidence
+                .replacen("{}", &percent(mean, decimals), 1)
+                .replacen("{}", &percent(min, decimals), 1);
+        }
+        text
+    }
+}
+
+/// Tiny bar chart of how many lines fall in each confidence decile, coloured
+/// like the per-line percentages.
+fn draw_confidence_sparkline(ui: &mut egui::Ui, p: &Palette, histogram: &[usize]) {
+    let (rect, response) =
+        ui.allocate_exact_size(Vec2::new(4.0 * histogram.len() as f32, 14.0), egui::Sense::hover());
+    let max = histogram.iter().copied().max().unwrap_or(0).max(1) as f32;
+    let painter = ui.painter_at(rect);
+    for (i, &count) in histogram.iter().enumerate() {
+        if count == 0 {
+            continue;
+        }
+        let x = rect.left() + 4.0 * i as f32;
+        let height = (rect.height() * count as f32 / max).max(1.0);
+        let bar = egui::Rect::from_min_max(
+            egui::pos2(x, rect.bottom() - height),
+            egui::pos2(x + 3.0, rect.bottom()),
+        );
+        let confidence = (i as f32 + 0.5) / histogram.len() as f32;
+        painter.rect_filled(bar, 0.0, confidence_color(p, confidence));
+    }
+    response.on_hover_text(
+        histogram
+            .iter()
+            .enumerate()
+            .filter(|(_, &n)| n > 0)
+            .map(|(i, n)| format!("{}–{}%: {}", i * 10, i * 10 + 10, n))
+            .collect::<Vec<_>>()
+            .join("\n"),
+    );
+}
+
+/// Green above 90 %, yellow from 70 %, red below.
+fn confidence_color(p: &Palette, confidence: f32) -> Color32 {
+    if confidence > 0.9 {
+        p.success
+    } else if confidence >= 0.7 {
+        p.warning
+    } else {
+        p.error
+    }
+}
+
+/// "Screen 1: 1920×1080 (primary)" style labels for the display picker.
+fn screen_labels(s: &i18n::Strings, screens: &[screenshots::Screen]) -> Vec<String> {
+    screens
+        .iter()
+        .enumerate()
+        .map(|(i, screen)| {
+            let info = &screen.display_info;
+            let primary = if info.is_primary {
+                format!(" ({})", s.lbl_primary)
+            } else {
+                String::new()
+            };
+            format!("{} {}: {}×{}{}", s.lbl_screen, i + 1, info.width, info.height, primary)
+        })
+        .collect()
+}
+
+/// "1920×1080 · 1.2 MB · PNG" for files, "1920×1080 · from screen 2" for
+/// captures.
+fn image_info_line(s: &i18n::Strings, loaded: &LoadedImage) -> String {
+    let mut parts = vec![format!("{}×{}", loaded.width, loaded.height)];
+    match loaded.source {
+        ImageSource::File { bytes, format } => {
+            parts.extend(bytes.map(format_bytes));
+            parts.extend(
+                format
+                    .and_then(|f| f.extensions_str().first())
+                    .map(|ext| ext.to_uppercase()),
+            );
+        }
+        ImageSource::Clipboard => parts.push(s.lbl_from_clipboard.into()),
+        ImageSource::Camera => parts.push(s.lbl_from_camera.into()),
+        ImageSource::Screen(i) => {
+            parts.push(s.lbl_from_screen.replacen("{}", &(i + 1).to_string(), 1))
+        }
+    }
+    parts.join(" · ")
+}
+
+/// Total size of the files under `dir`.  Symlinks are not followed.
+fn dir_size(dir: &Path) -> std::io::Result<u64> {
+    let mut total = 0;
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let kind = entry.file_type()?;
+        if kind.is_dir() {
+            total += dir_size(&entry.path())?;
+        } else if kind.is_file() {
+            total += entry.metadata()?.len();
+        }
+    }
+    Ok(total)
+}
+
+/// Opens `path` the way double-clicking it would: a folder in the file
+/// manager, a file in the app associated with its type.
+fn open_in_default_app(path: &Path) -> std::io::Result<()> {
+    let opener = if cfg!(target_os = "windows") {
+        "explorer"
+    } else if cfg!(target_os = "macos") {
+        "open"
+    } else {
+        "xdg-open"
+    };
+    std::process::Command::new(opener).arg(path).spawn().map(drop)
+}
+
+/// Human-readable size with binary units, e.g. "512 B", "1.5 MB".
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", value, UNITS[unit])
+}
+
+fn failure_text(s: &i18n::Strings, kind: FailureKind) -> &'static str {
+    match kind {
+        FailureKind::GpuOutOfMemory => s.err_gpu_oom,
+        FailureKind::GpuUnavailable => s.err_gpu_unavailable,
+        FailureKind::ModelDownload => s.err_model_download,
+        FailureKind::UnsupportedLanguage => s.err_unsupported_language,
+        FailureKind::MissingPythonModule => s.err_missing_module,
+    }
+}
+
+fn unparsed_warning(s: &i18n::Strings, count: usize) -> String {
+    s.warn_unparsed_lines.replacen("{}", &count.to_string(), 1)
+}
+
+fn language_problem_text(s: &i18n::Strings, problem: &LanguageProblem) -> String {
+    match problem {
+        LanguageProblem::Unknown(codes) => s.lang_unknown.replacen("{}", &codes.join(", "), 1),
+        LanguageProblem::Incompatible { code, anchor } => {
+            s.lang_incompatible.replacen("{}", code, 1).replacen("{}", anchor, 1)
+        }
+    }
+}
+
+fn script_label(s: &i18n::Strings, script: Script) -> &'static str {
+    match script {
+        Script::Latin => s.script_latin,
+        Script::Cjk => s.script_cjk,
+        Script::Digits => s.script_digits,
+        Script::Other => s.script_other,
+    }
+}
+
+fn builtin_preset_label(s: &i18n::Strings, preset: BuiltinPreset) -> &'static str {
+    match preset {
+        BuiltinPreset::Default => s.preset_default,
+        BuiltinPreset::DenseDocument => s.preset_dense_document,
+        BuiltinPreset::SceneText => s.preset_scene_text,
+        BuiltinPreset::Handwriting => s.preset_handwriting,
+    }
+}
+
+/// How long a settings Reset can be undone.
+const RESET_UNDO_TIME: Duration = Duration::from_secs(10);
+
+/// Pastes closer together than this are treated as one.
+const PASTE_DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Fingerprint of a pasted image, to spot the same clipboard pasted again.
+fn rgba_hash(rgba: &[u8], width: usize, height: usize) -> u64 {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    (width, height).hash(&mut hasher);
+    rgba.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// How long typing a path in is offered after a file dialog came back empty.
+const PATH_FALLBACK_TIME: Duration = Duration::from_secs(8);
+
+/// A file dialog that starts in `dir` when it is set.
+fn file_dialog(dir: &str) -> rfd::FileDialog {
+    let dialog = rfd::FileDialog::new();
+    if dir.is_empty() {
+        dialog
+    } else {
+        dialog.set_directory(dir)
+    }
+}
+
+
+fn threshold_row(
+    ui: &mut egui::Ui,
+    s: &i18n::Strings,
+    label: &str,
+    value: &mut f32,
+    default: f32,
+    hint: &str,
+) {
+    let p = Palette::of(ui.ctx());
+    ui.horizontal(|ui| {
+        ui.label(label);
+        // The value box is added separately so `slider.rect` is just the
+        // rail, which the default tick is placed along.
+        let slider = ui.add(egui::Slider::new(value, 0.0..=1.0).show_value(false));
+        let handle_radius = slider.rect.height() / 2.5;
+        let rail = slider.rect.x_range().shrink(handle_radius);
+        let x = egui::lerp(rail.min..=rail.max, default);
+        ui.painter().vline(
+            x,
+            egui::Rangef::new(slider.rect.center().y + 3.0, slider.rect.bottom()),
+            Stroke::new(1.5, p.muted),
+        );
+        ui.add(egui::DragValue::new(value).range(0.0..=1.0).speed(0.01).fixed_decimals(2));
+        let reset = ui
+            .add_enabled(*value != default, egui::Button::new("↺").small())
+            .on_hover_text(s.hint_reset_default.replacen("{}", &format!("{:.2}", default), 1));
+        if reset.clicked() {
+            *value = default;
+        }
+        ui.label(RichText::new(hint).color(p.muted).small());
+    });
+}
+
+/// Show `loaded` fitted into the remaining space, with mouse-wheel zoom
+/// around the cursor, drag to pan and double-click to reset to fit.
+/// Detected boxes are painted on top using the same on-screen rect, with
+/// line `highlighted` picked out.  When `selectable`, Shift+drag draws the
+/// selection instead of panning and right-click clears it.  Returns the
+/// line whose box is under the pointer.
+fn draw_image_preview(
+    ui: &mut egui::Ui,
+    loaded: &mut LoadedImage,
+    lines: &[OcrLine],
+    min_confidence: f32,
+    highlighted: Option<usize>,
+    selectable: bool,
+) -> Option<usize> {
+    let (rect, response) =
+        ui.allocate_exact_size(ui.available_size(), egui::Sense::click_and_drag());
+    let fit_rect = rect.shrink(4.0);
+    let aspect = loaded.width as f32 / loaded.height as f32;
+    let (fit_w, fit_h) = fit_into(fit_rect.width(), fit_rect.height(), aspect);
+
+    if response.double_clicked() {
+        loaded.zoom = 1.0;
+        loaded.pan = Vec2::ZERO;
+    }
+    if response.drag_started() {
+        loaded.selecting = selectable && ui.input(|i| i.modifiers.shift);
+    }
+    if response.dragged() && !loaded.selecting {
+        loaded.pan += response.drag_delta();
+    }
+    if response.secondary_clicked() {
+        loaded.selection = None;
+    }
+    if let Some(cursor) = response.hover_pos() {
+        let factor = ui.input(|i| i.zoom_delta() * (i.smooth_scroll_delta.y * 0.0015).exp());
+        if factor != 1.0 {
+            let new_zoom = (loaded.zoom * factor).clamp(1.0, 20.0);
+            // Keep the image point under the cursor fixed while scaling.
+            let center = rect.center() + loaded.pan;
+            let new_center = cursor - (cursor - center) * (new_zoom / loaded.zoom);
+            loaded.pan = new_center - rect.center();
+            loaded.zoom = new_zoom;
+        }
+    }
+    if loaded.zoom <= 1.0 {
+        loaded.pan = Vec2::ZERO;
+    }
+
+    let image_rect = egui::Rect::from_center_size(
+        rect.center() + loaded.pan,
+        Vec2::new(fit_w, fit_h) * loaded.zoom,
+    );
+    let painter = ui.painter_at(rect);
+    let texture = match &loaded.processed {
+        Some((_, processed)) if loaded.show_processed => processed,
+        _ => &loaded.texture,
+    };
+    painter.image(
+        texture.id(),
+        image_rect,
+        egui::Rect::from_min_max(egui::pos2(0.0, 0.0), egui::pos2(1.0, 1.0)),
+        Color32::WHITE,
+    );
+    let image_size = [loaded.width, loaded.height];
+    draw_bbox_overlay(&painter, image_rect, image_size, lines, min_confidence, highlighted);
+
+    let pixels = Vec2::new(loaded.width as f32, loaded.height as f32);
+    let scale = pixels / image_rect.size();
+    if loaded.selecting {
+        let to_image = |pos: egui::Pos2| {
+            let p = (pos - image_rect.min) * scale;
+            egui::pos2(p.x.clamp(0.0, pixels.x), p.y.clamp(0.0, pixels.y))
+        };
+        let start = ui.input(|i| i.pointer.press_origin());
+        if let (Some(start), Some(pos)) = (start, response.interact_pointer_pos()) {
+            loaded.selection = Some(egui::Rect::from_two_pos(to_image(start), to_image(pos)));
+        }
+        if response.drag_stopped() {
+            loaded.selecting = false;
+            // A Shift+click is not a region.
+            if loaded.selection.is_some_and(|r| r.width() < 4.0 || r.height() < 4.0) {
+                loaded.selection = None;
+            }
+        }
+    }
+    if let (Some(region), true) = (loaded.selection, selectable) {
+        let on_screen = egui::Rect::from_min_max(
+            image_rect.min + region.min.to_vec2() / scale,
+            image_rect.min + region.max.to_vec2() / scale,
+        );
+        let accent = Palette::of(ui.ctx()).accent;
+        painter.rect(on_screen, 0.0, accent.gamma_multiply(0.12), Stroke::new(1.5, accent));
+    }
+    let pointer = response.hover_pos()?;
+    line_at(image_rect, image_size, lines, min_confidence, pointer)
+}
+
+/// The screenshot stretched over the whole area with the dragged selection
+/// outlined and everything outside it dimmed.
+fn region_select_ui(ui: &mut egui::Ui, sel: &mut RegionSelect, hint: &str) -> RegionOutcome {
+    let (rect, response) = ui.allocate_exact_size(ui.available_size(), egui::Sense::drag());
+    let painter = ui.painter_at(rect);
+    painter.image(
+        sel.texture.id(),
+        rect,
+        egui::Rect::from_min_max(egui::pos2(0.0, 0.0), egui::pos2(1.0, 1.0)),
+        Color32::WHITE,
+    );
+
+    if response.drag_started() {
+        if let Some(pos) = response.interact_pointer_pos() {
+            sel.drag = Some((pos, pos));
+        }
+    } else if response.dragged() {
+        if let (Some((start, _)), Some(pos)) = (sel.drag, response.interact_pointer_pos()) {
+            sel.drag = Some((start, pos));
+        }
+    }
+
+    let dim = Color32::from_black_alpha(110);
+    match sel.drag {
+        Some((start, end)) => {
+            let r = egui::Rect::from_two_pos(start, end).intersect(rect);
+            // Four bands around the selection.
+            let pos2 = egui::pos2;
+            for band in [
+                egui::Rect::from_min_max(rect.min, pos2(rect.max.x, r.min.y)),
+                egui::Rect::from_min_max(pos2(rect.min.x, r.max.y), rect.max),
+                egui::Rect::from_min_max(pos2(rect.min.x, r.min.y), pos2(r.min.x, r.max.y)),
+                egui::Rect::from_min_max(pos2(r.max.x, r.min.y), pos2(rect.max.x, r.max.y)),
+            ] {
+                painter.rect_filled(band, 0.0, dim);
+            }
+            painter.rect_stroke(r, 0.0, Stroke::new(2.0, Color32::from_rgb(96, 165, 250)));
+        }
+        None => {
+            painter.rect_filled(rect, 0.0, dim);
+        }
+    }
+    painter.text(
+        rect.center_top() + Vec2::new(0.0, 24.0),
+        egui::Align2::CENTER_TOP,
+        hint,
+        FontId::proportional(18.0),
+        Color32::WHITE,
+    );
+
+    if response.drag_stopped() {
+        if let Some((start, end)) = sel.drag.take() {
+            let scale = Vec2::new(
+                sel.width as f32 / rect.width(),
+                sel.height as f32 / rect.height(),
+            );
+            let r = egui::Rect::from_two_pos(start, end).intersect(rect);
+            let x = ((r.min.x - rect.min.x) * scale.x).floor().max(0.0) as usize;
+            let y = ((r.min.y - rect.min.y) * scale.y).floor().max(0.0) as usize;
+            let w = ((r.width() * scale.x).round() as usize).min(sel.width - x);
+            let h = ((r.height() * scale.y).round() as usize).min(sel.height - y);
+            // A click without a real drag keeps the picker open.
+            if w >= 4 && h >= 4 {
+                return RegionOutcome::Selected([x, y, w, h]);
+            }
+        }
+    }
+    RegionOutcome::Pending
+}
+
+/// Draw each line's four-corner polygon over an image displayed in `rect`.
+/// `image_size` is the source image size in pixels, used to scale the
+/// bounding-box coordinates reported by EasyOCR.  The `highlighted` line
+/// is filled and outlined in the accent colour.
+fn draw_bbox_overlay(
+    painter: &egui::Painter,
+    rect: egui::Rect,
+    image_size: [u32; 2],
+    lines: &[OcrLine],
+    min_confidence: f32,
+    highlighted: Option<usize>,
+) {
+    let stroke = Stroke::new(1.5, Color32::from_rgba_unmultiplied(74, 222, 128, 200));
+    let accent = Palette::of(painter.ctx()).accent;
+    for (index, points) in screen_boxes(rect, image_size, lines, min_confidence) {
+        if Some(index) == highlighted {
+            painter.add(egui::Shape::convex_polygon(
+                points.to_vec(),
+                accent.gamma_multiply(0.25),
+                Stroke::new(2.5, accent),
+            ));
+        } else {
+            painter.add(egui::Shape::closed_line(points.to_vec(), stroke));
+        }
+    }
+}
+
+/// The lines at least half of whose box lies inside `region`, in image
+/// pixels.  Text-only lines have no box and are left out.
+fn lines_in_region(lines: &[OcrLine], region: egui::Rect) -> Vec<OcrLine> {
+    lines
+        .iter()
+        .filter(|line| {
+            let bounds = egui::Rect::from_points(&line.bbox.map(|[x, y]| egui::pos2(x, y)));
+            let inside = bounds.intersect(region);
+            line.has_position() && inside.is_positive() && inside.area() >= bounds.area() / 2.0
+        })
+        .cloned()
+        .collect()
+}
+
+/// The on-screen corners of every box drawn by [`draw_bbox_overlay`], with
+/// the index of its line.
+fn screen_boxes(
+    rect: egui::Rect,
+    image_size: [u32; 2],
+    lines: &[OcrLine],
+    min_confidence: f32,
+) -> impl Iterator<Item = (usize, [egui::Pos2; 4])> + '_ {
+    let scale = (image_size[0] > 0 && image_size[1] > 0).then(|| {
+        Vec2::new(rect.width() / image_size[0] as f32, rect.height() / image_size[1] as f32)
+    });
+    scale.into_iter().flat_map(move |scale| {
+        lines
+            .iter()
+            .enumerate()
+            .filter(move |(_, l)| l.has_position() && l.meets_confidence(min_confidence))
+            .map(move |(index, line)| {
+                (index, line.bbox.map(|[x, y]| rect.min + Vec2::new(x * scale.x, y * scale.y)))
+            })
+    })
+}
+
+/// The line whose box contains `pos`; the smallest one where boxes overlap,
+/// so a word inside a paragraph box can still be picked.
+fn line_at(
+    rect: egui::Rect,
+    image_size: [u32; 2],
+    lines: &[OcrLine],
+    min_confidence: f32,
+    pos: egui::Pos2,
+) -> Option<usize> {
+    screen_boxes(rect, image_size, lines, min_confidence)
+        .filter(|(_, corners)| polygon_contains(corners, pos))
+        .min_by(|(_, a), (_, b)| polygon_area(a).total_cmp(&polygon_area(b)))
+        .map(|(index, _)| index)
+}
+
+/// Even-odd hit test, so boxes that aren't axis-aligned work too.
+fn polygon_contains(corners: &[egui::Pos2], pos: egui::Pos2) -> bool {
+    let mut inside = false;
+    for (i, a) in corners.iter().enumerate() {
+        let b = corners[(i + 1) % corners.len()];
+        let crosses = (a.y > pos.y) != (b.y > pos.y);
+        if crosses && pos.x < a.x + (pos.y - a.y) / (b.y - a.y) * (b.x - a.x) {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+fn polygon_area(corners: &[egui::Pos2]) -> f32 {
+    let twice: f32 = corners
+        .iter()
+        .zip(corners.iter().cycle().skip(1))
+        .map(|(a, b)| a.x * b.y - b.x * a.y)
+        .sum();
+    twice.abs() / 2.0
+}
+
+// ── result formatting ─────────────────────────────────────────────────────────
+
+/// How results are rendered as text.
+#[derive(Clone, Copy)]
+struct TextOptions {
+    min_confidence: f32,
+    format: CopyFormat,
+    /// Results are paragraphs: reflow each into one line and separate them
+    /// with blank lines.
+    join_paragraphs: bool,
+    /// `Settings::confidence_decimals`
+    decimals: u8,
+}
+
+/// A 0–1 confidence as a percentage with `decimals` digits after the point.
+fn percent(confidence: f32, decimals: u8) -> String {
+    format!("{:.*}%", decimals as usize, confidence * 100.0)
+}
+
+/// One entry per line that meets `min_confidence`, by default
+/// `text (NN.N%)` as shown in the results pane.
+fn format_lines(lines: &[OcrLine], opts: TextOptions) -> String {
+    let rendered = lines
+        .iter()
+        .filter(|l| l.meets_confidence(opts.min_confidence))
+        .map(|l| {
+            if opts.join_paragraphs {
+                opts.format.line(&OcrLine { text: join_lines(&l.text), ..l.clone() }, opts.decimals)
+            } else {
+                opts.format.line(l, opts.decimals)
+            }
+        })
+        .collect::<Vec<_>>();
+    rendered.join(if opts.join_paragraphs { "\n\n" } else { "\n" })
+}
+
+/// Collapses the line breaks and runs of spaces within each paragraph of
+/// `text` into single spaces; blank lines still separate paragraphs.
+fn join_lines(text: &str) -> String {
+    let mut paragraphs = Vec::new();
+    let mut current: Vec<&str> = Vec::new();
+    for line in text.lines() {
+        if line.trim().is_empty() {
+            if !current.is_empty() {
+                paragraphs.push(current.join(" "));
+                current.clear();
+            }
+        } else {
+            current.extend(line.split_whitespace());
+        }
+    }
+    if !current.is_empty() {
+        paragraphs.push(current.join(" "));
+    }
+    paragraphs.join("\n\n")
+}
+
+fn filter_lines(lines: &[OcrLine], min_confidence: f32) -> Vec<OcrLine> {
+    lines
+        .iter()
+        .filter(|l| l.meets_confidence(min_confidence))
+        .cloned()
+        .collect()
+}
+
+/// One column of a compare run: the profile name, a summary of its result
+/// and the result rows.
+fn draw_compare_side(
+    ui: &mut egui::Ui,
+    s: &i18n::Strings,
+    index: usize,
+    side: &mut CompareSide,
+    min_confidence: f32,
+    decimals: u8,
+    tags: Option<&i18n::Strings>,
+) -> bool {
+    let p = Palette::of(ui.ctx());
+    ui.label(RichText::new(&side.label).strong());
+    match &mut side.result {
+        None => {
+            ui.spinner();
+            false
+        }
+        Some(res) => match &res.error {
+            Some(err) => {
+                ui.label(RichText::new(err.summary()).color(p.error));
+                false
+            }
+            None => {
+                if let Some(stats) = ResultStats::of(res.lines.iter()) {
+                    ui.label(RichText::new(stats.summary(s, decimals)).color(p.muted).small());
+                }
+                egui::ScrollArea::vertical()
+                    .id_salt(("compare_scroll", index))
+                    .show(ui, |ui| {
+                        let marks = RowMarks::default();
+                        draw_result_rows(ui, &mut res.lines, min_confidence, decimals, tags, marks)
+                            .1
+                    })
+                    .inner
+            }
+        },
+    }
+}
+
+/// Results of a compare run, one `== profile ==` section per side.
+fn format_compare_results(sides: &[CompareSide], opts: TextOptions) -> String {
+    sides
+        .iter()
+        .map(|side| {
+            let body = match &side.result {
+                Some(res) => match &res.error {
+                    Some(err) => format!("[{}]", err.summary()),
+                    None => format_lines(&res.lines, opts),
+                },
+                None => String::new(),
+            };
+            format!("== {} ==\n{}", side.label, body)
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// Results of a folder batch, one `== file ==` section per image.
+fn format_batch_results(results: &[(PathBuf, OcrResult)], opts: TextOptions) -> String {
+    results
+        .iter()
+        .map(|(path, res)| {
+            let name = path
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let body = match &res.error {
+                Some(err) => format!("[{}]", err.summary()),
+                None => format_lines(&res.lines, opts),
+            };
+            format!("== {} ==\n{}", name, body)
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+fn confidence_filter_summary(s: &i18n::Strings, shown: usize, total: usize) -> String {
+    s.lbl_confidence_filtered
+        .replacen("{}", &shown.to_string(), 1)
+        .replacen("{}", &total.to_string(), 1)
+        .replacen("{}", &(total - shown).to_string(), 1)
+}
+
+fn copy_format_label(s: &i18n::Strings, format: CopyFormat) -> &'static str {
+    match format {
+        CopyFormat::TextOnly => s.copy_text_only,
+        CopyFormat::WithConfidence => s.copy_with_confidence,
+        CopyFormat::Tsv => s.copy_tsv,
+    }
+}
+
+fn export_format_label(s: &i18n::Strings, format: ExportFormat) -> &'static str {
+    match format {
+        ExportFormat::Text => s.export_txt,
+        ExportFormat::Csv => s.export_csv,
+        ExportFormat::Json => s.export_json,
+        ExportFormat::Hocr => s.export_hocr,
+        ExportFormat::Layout => s.export_layout,
+    }
+}
+
+fn batch_progress(s: &i18n::Strings, done: usize, total: usize) -> String {
+    s.status_batch_progress
+        .replacen("{}", &done.to_string(), 1)
+        .replacen("{}", &total.to_string(), 1)
+}
+
+// ── image utilities ───────────────────────────────────────────────────────────
+
+/// Image files directly inside `dir` whose extension is in
+/// `IMAGE_EXTENSIONS`, sorted by path.
+fn list_images_in_dir(dir: &Path) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = std::fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|e| e.ok().map(|e| e.path()))
+                .filter(|p| p.is_file() && has_image_extension(p))
+                .collect()
+        })
+        .unwrap_or_default();
+    paths.sort();
+    paths
+}
+
+/// Whether `path` looks like an image or a document that can be opened.
+fn has_image_extension(path: &Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_ascii_lowercase())
+        .is_some_and(|e| {
+            IMAGE_EXTENSIONS.contains(&e.as_str()) || pages::DOCUMENT_EXTENSIONS.contains(&e.as_str())
+        })
+}
+
+/// Decodes `path` into a fresh, unzoomed preview.
+fn decode_image(path: &Path, ctx: &egui::Context) -> Result<LoadedImage, String> {
+    let color_image = load_color_image_from_path(path)?;
+    let width = color_image.size[0] as u32;
+    let height = color_image.size[1] as u32;
+    let texture = ctx.load_texture("ocr_image", color_image, egui::TextureOptions::LINEAR);
+    let source = ImageSource::File {
+        bytes: std::fs::metadata(path).ok().map(|m| m.len()),
+        format: image::ImageReader::open(path)
+            .and_then(|r| r.with_guessed_format())
+            .ok()
+            .and_then(|r| r.format()),
+    };
+    Ok(LoadedImage {
+        path: path.to_path_buf(),
+        source,
+        texture,
+        width,
+        height,
+        zoom: 1.0,
+        pan: Vec2::ZERO,
+        processed: None,
+        show_processed: false,
+        selection: None,
+        selecting: false,
+    })
+}
 
 fn load_color_image_from_path(path: &std::path::Path) -> Result<ColorImage, String> {
     let img = image::open(path).map_err(|e| e.to_string())?;
@@ -844,14 +4699,112 @@ fn load_color_image_from_path(path: &std::path::Path) -> Result<ColorImage, Stri
     Ok(ColorImage::from_rgba_unmultiplied([w, h], &rgba))
 }
 
-fn save_rgba_as_png(
+/// Copy the `w`×`h` region at (`x`, `y`) out of an RGBA buffer that is
+/// `width` pixels wide.  The region must lie inside the buffer.
+fn crop_rgba(rgba: &[u8], width: usize, x: usize, y: usize, w: usize, h: usize) -> Vec<u8> {
+    let mut out = Vec::with_capacity(w * h * 4);
+    for row in y..y + h {
+        let start = (row * width + x) * 4;
+        out.extend_from_slice(&rgba[start..start + w * 4]);
+    }
+    out
+}
+
+/// Where to write a pasted or captured image: a timestamped file in `dir`
+/// when one is configured, otherwise a single temp file that each capture
+/// overwrites.
+/// Start of the temp file names captures get this session; see
+/// `remove_temp_captures`.
+fn temp_capture_prefix() -> String {
+    format!("easyocr_gui_capture_{}_", std::process::id())
+}
+
+/// Where a pasted or captured image is written for the CLI to read.  Each
+/// capture gets its own file, so a run still reading the previous one
+/// isn't handed a half-written replacement.
+fn capture_path(dir: &str, format: TempFormat) -> Result<PathBuf, String> {
+    let ext = format.extension();
+    if dir.trim().is_empty() {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+        return Ok(std::env::temp_dir().join(format!("{}{}.{}", temp_capture_prefix(), n, ext)));
+    }
+    let dir = PathBuf::from(ocr::expand_home_dir(dir.trim()));
+    std::fs::create_dir_all(&dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+    let stamp = history::file_stamp(history::now_secs());
+    let mut path = dir.join(format!("capture-{}.{}", stamp, ext));
+    // Several captures within one second get a counter suffix.
+    let mut n = 2;
+    while path.exists() {
+        path = dir.join(format!("capture-{}-{}.{}", stamp, n, ext));
+        n += 1;
+    }
+    Ok(path)
+}
+
+/// Deletes the temp captures, downloaded images and split pages written by
+/// this process.
+fn remove_temp_captures() {
+    let prefixes = [temp_capture_prefix(), download::temp_prefix()];
+    let Ok(entries) = std::fs::read_dir(std::env::temp_dir()) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let name = entry.file_name().to_string_lossy().to_string();
+        if prefixes.iter().any(|p| name.starts_with(p)) {
+            let _ = std::fs::remove_file(entry.path());
+        } else if name.starts_with(&pages::temp_prefix()) {
+            let _ = std::fs::remove_dir_all(entry.path());
+        }
+    }
+}
+
+fn save_capture(
     rgba: &[u8],
     width: u32,
     height: u32,
     path: &std::path::Path,
+    format: TempFormat,
+    jpeg_quality: u8,
 ) -> Result<(), String> {
-    image::save_buffer(path, rgba, width, height, image::ColorType::Rgba8)
-        .map_err(|e| e.to_string())
+    match format {
+        TempFormat::Png => image::save_buffer(path, rgba, width, height, image::ColorType::Rgba8)
+            .map_err(|e| e.to_string()),
+        TempFormat::Jpeg => {
+            let file = std::fs::File::create(path).map_err(|e| e.to_string())?;
+            image::codecs::jpeg::JpegEncoder::new_with_quality(
+                std::io::BufWriter::new(file),
+                jpeg_quality,
+            )
+            .encode(&rgba_over_white(rgba), width, height, image::ExtendedColorType::Rgb8)
+            .map_err(|e| e.to_string())
+        }
+    }
+}
+
+/// Drops the alpha channel JPEG can't store, putting see-through pixels on
+/// white so dark text on a transparent background stays readable.
+fn rgba_over_white(rgba: &[u8]) -> Vec<u8> {
+    rgba.chunks_exact(4)
+        .flat_map(|px| {
+            let alpha = px[3] as u32;
+            let blend = move |c: u8| ((c as u32 * alpha + 255 * (255 - alpha)) / 255) as u8;
+            [blend(px[0]), blend(px[1]), blend(px[2])]
+        })
+        .collect()
+}
+
+/// Decodes `path` and runs it through `preprocess` for the before/after
+/// preview.  `None` if the file can no longer be read.
+fn processed_texture(
+    ctx: &egui::Context,
+    path: &Path,
+    preprocess: Preprocess,
+) -> Option<TextureHandle> {
+    let img = preprocess.apply(image::open(path).ok()?.to_rgba8());
+    let size = [img.width() as usize, img.height() as usize];
+    let color_image = ColorImage::from_rgba_unmultiplied(size, img.as_raw());
+    Some(ctx.load_texture("ocr_image_processed", color_image, egui::TextureOptions::LINEAR))
 }
 
 /// Scale (w, h) image to fit within (max_w, max_h) preserving aspect ratio.
@@ -864,3 +4817,180 @@ fn fit_into(max_w: f32, max_h: f32, aspect: f32) -> (f32, f32) {
         by_height
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        capture_path, crop_rgba, dir_size, format_bytes, format_lines, join_lines, line_at,
+        lines_in_region, next_focus, percent, rgba_over_white, save_capture, CopyFormat,
+        ResultStats, TextOptions,
+    };
+    use easyocr_core::ocr::OcrLine;
+    use easyocr_core::settings::TempFormat;
+
+    #[test]
+    fn line_at_picks_the_smallest_box_under_the_pointer() {
+        let boxed = |x: f32, y: f32, w: f32, h: f32| OcrLine {
+            bbox: [[x, y], [x + w, y], [x + w, y + h], [x, y + h]],
+            text: "t".into(),
+            confidence: 0.9,
+        };
+        // A paragraph with a word inside it and a tilted box to the right.
+        let mut tilted = boxed(0.0, 0.0, 0.0, 0.0);
+        tilted.bbox = [[150.0, 10.0], [190.0, 20.0], [180.0, 60.0], [140.0, 50.0]];
+        let lines = [boxed(0.0, 0.0, 100.0, 50.0), boxed(10.0, 10.0, 20.0, 10.0), tilted];
+        // Shown at half size, offset by (5, 5).
+        let rect = egui::Rect::from_min_size(egui::pos2(5.0, 5.0), egui::vec2(100.0, 100.0));
+        let at = |x, y| line_at(rect, [200, 200], &lines, 0.0, egui::pos2(x, y));
+        assert_eq!(at(12.0, 12.0), Some(1));
+        assert_eq!(at(40.0, 20.0), Some(0));
+        assert_eq!(at(88.0, 22.0), Some(2));
+        assert_eq!(at(76.0, 8.0), None);
+        assert_eq!(line_at(rect, [200, 200], &lines, 0.95, egui::pos2(12.0, 12.0)), None);
+        assert_eq!(line_at(rect, [0, 0], &lines, 0.0, egui::pos2(12.0, 12.0)), None);
+    }
+
+    #[test]
+    fn crop_rgba_copies_region_rows() {
+        // 3×2 image where each pixel's red channel is its index.
+        let rgba: Vec<u8> = (0..6u8).flat_map(|i| [i, 0, 0, 255]).collect();
+        let cropped = crop_rgba(&rgba, 3, 1, 0, 2, 2);
+        let reds: Vec<u8> = cropped.chunks(4).map(|p| p[0]).collect();
+        assert_eq!(reds, vec![1, 2, 4, 5]);
+    }
+
+    #[test]
+    fn result_stats_summarise_lines() {
+        let line = |text: &str, confidence: f32| OcrLine {
+            bbox: [[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]],
+            text: text.into(),
+            confidence,
+        };
+        let lines = [line("ab", 0.5), line("cde", 1.0), line("f", 0.95)];
+        let stats = ResultStats::of(lines.iter()).unwrap();
+        assert_eq!((stats.lines, stats.chars), (3, 6));
+        let (mean, min) = stats.confidence.unwrap();
+        assert!((mean - 0.8166).abs() < 1e-3);
+        assert_eq!(min, 0.5);
+        assert_eq!(stats.histogram[5], 1);
+        assert_eq!(stats.histogram[9], 2);
+        assert_eq!(ResultStats::of([].iter()), None);
+    }
+
+    #[test]
+    fn result_stats_count_words_and_cjk() {
+        let line = |text: &str| OcrLine {
+            bbox: [[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]],
+            text: text.into(),
+            confidence: 0.9,
+        };
+        let lines = [line("ab"), line("cde fg"), line("你好")];
+        let stats = ResultStats::of(lines.iter()).unwrap();
+        assert_eq!((stats.lines, stats.words, stats.chars, stats.cjk_chars), (3, 4, 10, 2));
+        assert_eq!(stats.cjk_words, 1);
+    }
+
+    #[test]
+    fn copy_formats() {
+        let lines = [
+            OcrLine {
+                bbox: [[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]],
+                text: "a\tb".into(),
+                confidence: 0.875,
+            },
+            OcrLine { bbox: [[0.0; 2]; 4], text: "plain".into(), confidence: 0.0 },
+        ];
+        let opts = |format| TextOptions {
+            min_confidence: 0.0,
+            format,
+            join_paragraphs: false,
+            decimals: 1,
+        };
+        assert_eq!(format_lines(&lines, opts(CopyFormat::TextOnly)), "a\tb\nplain");
+        assert_eq!(format_lines(&lines[..1], opts(CopyFormat::WithConfidence)), "a\tb (87.5%)");
+        let precise = TextOptions { decimals: 3, ..opts(CopyFormat::WithConfidence) };
+        assert_eq!(format_lines(&lines[..1], precise), "a\tb (87.500%)");
+        assert_eq!(percent(0.9018, 0), "90%");
+        assert_eq!(format_lines(&lines, opts(CopyFormat::Tsv)), "a b\t0.875\nplain\t");
+    }
+
+    #[test]
+    fn joined_paragraphs_keep_blank_line_separators() {
+        assert_eq!(join_lines("one\ntwo  three\n\n\nfour\n"), "one two three\n\nfour");
+        let line = |text: &str| OcrLine { bbox: [[0.0; 2]; 4], text: text.into(), confidence: 0.0 };
+        let opts = TextOptions {
+            min_confidence: 0.0,
+            format: CopyFormat::TextOnly,
+            join_paragraphs: true,
+            decimals: 1,
+        };
+        assert_eq!(format_lines(&[line("a\nb"), line("c")], opts), "a b\n\nc");
+    }
+
+    #[test]
+    fn temp_captures_get_distinct_paths() {
+        let first = capture_path("", TempFormat::Png).unwrap();
+        let second = capture_path("", TempFormat::Jpeg).unwrap();
+        assert_ne!(first, second);
+        assert!(first.starts_with(std::env::temp_dir()));
+        assert_eq!(second.extension().unwrap(), "jpg");
+    }
+
+    #[test]
+    fn arrow_keys_step_through_the_visible_rows() {
+        let visible = [1, 3, 4];
+        assert_eq!(next_focus(&visible, None, true), Some(1));
+        assert_eq!(next_focus(&visible, None, false), Some(4));
+        assert_eq!(next_focus(&visible, Some(1), true), Some(3));
+        assert_eq!(next_focus(&visible, Some(3), false), Some(1));
+        assert_eq!(next_focus(&visible, Some(4), true), None);
+        assert_eq!(next_focus(&visible, Some(1), false), None);
+        // A row hidden by the confidence filter starts over.
+        assert_eq!(next_focus(&visible, Some(2), true), Some(1));
+        assert_eq!(next_focus(&[], None, true), None);
+    }
+
+    #[test]
+    fn lines_in_region_keeps_boxes_mostly_inside() {
+        let boxed = |text: &str, x: f32| OcrLine {
+            bbox: [[x, 0.0], [x + 10.0, 0.0], [x + 10.0, 10.0], [x, 10.0]],
+            text: text.into(),
+            confidence: 0.9,
+        };
+        let text_only = OcrLine { bbox: [[0.0; 2]; 4], text: "plain".into(), confidence: 0.0 };
+        let lines = [boxed("in", 0.0), boxed("half", 15.0), boxed("edge", 17.0), text_only];
+        let region = egui::Rect::from_min_max(egui::pos2(-5.0, -5.0), egui::pos2(20.0, 20.0));
+        let kept = lines_in_region(&lines, region);
+        let texts: Vec<&str> = kept.iter().map(|l| l.text.as_str()).collect();
+        assert_eq!(texts, ["in", "half"]);
+    }
+
+    #[test]
+    fn jpeg_captures_drop_alpha_onto_white() {
+        let rgba = [10, 20, 30, 255, 0, 0, 0, 0, 0, 0, 0, 128];
+        assert_eq!(rgba_over_white(&rgba), [10, 20, 30, 255, 255, 255, 127, 127, 127]);
+        let path = capture_path("", TempFormat::Jpeg).unwrap();
+        save_capture(&[0, 0, 0, 255].repeat(64), 8, 8, &path, TempFormat::Jpeg, 80).unwrap();
+        let saved = image::open(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+        assert_eq!((saved.width(), saved.height()), (8, 8));
+    }
+
+    #[test]
+    fn dir_size_sums_nested_files() {
+        let dir = std::env::temp_dir().join(format!("dir_size_test_{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("nested")).unwrap();
+        std::fs::write(dir.join("a.bin"), [0u8; 10]).unwrap();
+        std::fs::write(dir.join("nested").join("b.bin"), [0u8; 5]).unwrap();
+        assert_eq!(dir_size(&dir).unwrap(), 15);
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(dir_size(&dir).is_err());
+    }
+
+    #[test]
+    fn format_bytes_uses_binary_units() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MB");
+    }
+}
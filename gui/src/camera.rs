@@ -0,0 +1,252 @@
+use image::{ImageFormat, RgbaImage};
+use std::io::Read;
+use std::process::{Child, Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, TrySendError};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::thread;
+
+/// Preview rate; plenty for aiming at a page and cheap to decode.
+const FRAME_RATE: u32 = 15;
+
+/// A live camera feed.  Frames come from `ffmpeg` as a stream of JPEGs,
+/// which keeps platform camera APIs (and the C toolchains their bindings
+/// need to build) out of the GUI.  The process is stopped when this is
+/// dropped.
+pub struct Camera {
+    /// `None` until the capture thread has started ffmpeg.
+    child: Arc<Mutex<Option<Child>>>,
+    stopped: Arc<AtomicBool>,
+    frames: Receiver<Result<RgbaImage, String>>,
+}
+
+impl Camera {
+    /// Starts the first camera on a thread of its own, since finding it can
+    /// mean asking ffmpeg for the device list.  Frames, and a final message
+    /// for the status bar when the feed stops or can't start, wake the UI
+    /// through `ctx`.  Frames the UI has not taken yet are replaced rather
+    /// than queued.
+    pub fn open(ctx: &egui::Context) -> Self {
+        let child = Arc::new(Mutex::new(None));
+        let stopped = Arc::new(AtomicBool::new(false));
+        let (tx, frames) = mpsc::sync_channel(1);
+        let ctx = ctx.clone();
+        let (slot, stop) = (Arc::clone(&child), Arc::clone(&stopped));
+        thread::spawn(move || {
+            let started = input_args().and_then(|args| {
+                Command::new("ffmpeg")
+                    .args(["-hide_banner", "-loglevel", "error"])
+                    .args(args)
+                    .arg("-vf")
+                    .arg(format!("fps={}", FRAME_RATE))
+                    .args(["-f", "image2pipe", "-c:v", "mjpeg", "-q:v", "2", "-"])
+                    .stdin(Stdio::null())
+                    .stdout(Stdio::piped())
+                    .stderr(Stdio::piped())
+                    .spawn()
+                    .map_err(not_installed)
+            });
+            let mut child = match started {
+                Ok(child) => child,
+                Err(e) => {
+                    let _ = tx.send(Err(e));
+                    ctx.request_repaint();
+                    return;
+                }
+            };
+            let stdout = child.stdout.take();
+            let stderr = child.stderr.take();
+            {
+                let mut slot = slot.lock().unwrap_or_else(PoisonError::into_inner);
+                // Closed while ffmpeg was starting.
+                if stop.load(Ordering::SeqCst) {
+                    let _ = child.kill();
+                    let _ = child.wait();
+                    return;
+                }
+                *slot = Some(child);
+            }
+            let errors = thread::spawn(move || {
+                let mut text = String::new();
+                if let Some(mut stderr) = stderr {
+                    let _ = stderr.read_to_string(&mut text);
+                }
+                text
+            });
+            let mut buf = Vec::new();
+            let mut chunk = [0u8; 64 * 1024];
+            let mut stdout = stdout;
+            while let Some(Ok(n @ 1..)) = stdout.as_mut().map(|s| s.read(&mut chunk)) {
+                buf.extend_from_slice(&chunk[..n]);
+                for jpeg in take_jpeg_frames(&mut buf) {
+                    let Ok(frame) = image::load_from_memory_with_format(&jpeg, ImageFormat::Jpeg)
+                    else {
+                        continue;
+                    };
+                    match tx.try_send(Ok(frame.to_rgba8())) {
+                        // The UI still has the last one to show; skip this.
+                        Err(TrySendError::Full(_)) => continue,
+                        Err(TrySendError::Disconnected(_)) => return,
+                        Ok(()) => ctx.request_repaint(),
+                    }
+                }
+            }
+            let _ = tx.send(Err(stopped_reason(&errors.join().unwrap_or_default())));
+            ctx.request_repaint();
+        });
+        Self { child, stopped, frames }
+    }
+
+    /// The newest frame since the last call, or why the feed stopped.
+    pub fn latest(&self) -> Option<Result<RgbaImage, String>> {
+        self.frames.try_iter().last()
+    }
+}
+
+impl Drop for Camera {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::SeqCst);
+        let child = self.child.lock().unwrap_or_else(PoisonError::into_inner).take();
+        if let Some(mut child) = child {
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+    }
+}
+
+fn not_installed(e: std::io::Error) -> String {
+    format!(
+        "Camera capture uses ffmpeg, which could not be started ({}).\n\n\
+         Install it, e.g.:\n  apt install ffmpeg\n  brew install ffmpeg",
+        e
+    )
+}
+
+/// ffmpeg's input options for the platform's default camera.
+fn input_args() -> Result<Vec<String>, String> {
+    let args: &[&str] = if cfg!(target_os = "windows") {
+        // DirectShow opens devices by name, so ask for the list first.
+        let listing = Command::new("ffmpeg")
+            .args(["-hide_banner", "-list_devices", "true", "-f", "dshow", "-i", "dummy"])
+            .output()
+            .map_err(not_installed)?;
+        let name = first_dshow_camera(&String::from_utf8_lossy(&listing.stderr))
+            .ok_or("No camera found.")?;
+        return Ok(["-f", "dshow", "-i"]
+            .iter()
+            .map(|a| a.to_string())
+            .chain([format!("video={}", name)])
+            .collect());
+    } else if cfg!(target_os = "macos") {
+        &["-f", "avfoundation", "-framerate", "30", "-i", "0"]
+    } else {
+        &["-f", "v4l2", "-i", "/dev/video0"]
+    };
+    Ok(args.iter().map(|a| a.to_string()).collect())
+}
+
+/// The first video device in `ffmpeg -list_devices` output.  Newer builds
+/// tag each device `(video)`; older ones list them under a heading.
+fn first_dshow_camera(listing: &str) -> Option<String> {
+    let mut in_video_section = false;
+    for line in listing.lines() {
+        if line.contains("DirectShow video devices") {
+            in_video_section = true;
+            continue;
+        }
+        if line.contains("DirectShow audio devices") {
+            in_video_section = false;
+        }
+        if line.contains("Alternative name") {
+            continue;
+        }
+        let quoted = line.split('"').nth(1).filter(|name| !name.is_empty());
+        if let Some(name) = quoted {
+            if line.trim_end().ends_with("(video)") || in_video_section {
+                return Some(name.to_string());
+            }
+        }
+    }
+    None
+}
+
+/// Removes every complete JPEG (`FF D8` … `FF D9`) from the front of `buf`.
+/// `FF D9` can't occur inside the compressed data, where `FF` bytes are
+/// always followed by `00` or a restart marker.
+fn take_jpeg_frames(buf: &mut Vec<u8>) -> Vec<Vec<u8>> {
+    let mut frames = Vec::new();
+    let mut consumed = 0;
+    loop {
+        let rest = &buf[consumed..];
+        let Some(start) = rest.windows(2).position(|w| w == [0xFF, 0xD8]) else {
+            // Keep a trailing FF that may begin the next frame's marker.
+            consumed = buf.len() - usize::from(buf.last() == Some(&0xFF));
+            break;
+        };
+        let Some(len) = rest[start + 2..].windows(2).position(|w| w == [0xFF, 0xD9]) else {
+            consumed += start;
+            break;
+        };
+        let end = start + 2 + len + 2;
+        frames.push(rest[start..end].to_vec());
+        consumed += end;
+    }
+    buf.drain(..consumed);
+    frames
+}
+
+/// Why the feed ended, from what ffmpeg printed.
+fn stopped_reason(stderr: &str) -> String {
+    let last = stderr.lines().map(str::trim).rfind(|l| !l.is_empty());
+    match last {
+        _ if stderr.contains("Permission denied") || stderr.contains("not authorized") => {
+            "Access to the camera was denied. Allow it in the system privacy settings.".into()
+        }
+        _ if stderr.contains("No such file or directory") || stderr.contains("Could not find") => {
+            "No camera found.".into()
+        }
+        Some(line) => line.to_string(),
+        None => "The camera stopped.".into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{first_dshow_camera, stopped_reason, take_jpeg_frames};
+
+    #[test]
+    fn jpeg_frames_are_split_across_reads() {
+        let mut buf = vec![0xFF, 0xD8, 1, 2, 0xFF, 0xD9, 0xFF, 0xD8, 3];
+        assert_eq!(take_jpeg_frames(&mut buf), [vec![0xFF, 0xD8, 1, 2, 0xFF, 0xD9]]);
+        assert_eq!(buf, [0xFF, 0xD8, 3]);
+        buf.extend([0xFF]);
+        assert!(take_jpeg_frames(&mut buf).is_empty());
+        buf.extend([0xD9, 0xFF]);
+        assert_eq!(take_jpeg_frames(&mut buf), [vec![0xFF, 0xD8, 3, 0xFF, 0xD9]]);
+        assert_eq!(buf, [0xFF]);
+    }
+
+    #[test]
+    fn finds_the_first_directshow_camera() {
+        let newer = "[dshow @ 0x1] \"Integrated Camera\" (video)\n\
+                     [dshow @ 0x1]   Alternative name \"@device_pnp_\\\\?\\usb\"\n\
+                     [dshow @ 0x1] \"Microphone\" (audio)\n";
+        assert_eq!(first_dshow_camera(newer).as_deref(), Some("Integrated Camera"));
+        let older = "[dshow @ 0x2] DirectShow video devices\n\
+                     [dshow @ 0x2]  \"USB Camera\"\n\
+                     [dshow @ 0x2] DirectShow audio devices\n\
+                     [dshow @ 0x2]  \"Microphone\"\n";
+        assert_eq!(first_dshow_camera(older).as_deref(), Some("USB Camera"));
+        assert_eq!(first_dshow_camera("[dshow @ 0x3] \"Microphone\" (audio)\n"), None);
+    }
+
+    #[test]
+    fn explains_common_camera_failures() {
+        let missing = "[video4linux2,v4l2 @ 0x1] Cannot open video device /dev/video0: \
+                       No such file or directory\n";
+        assert_eq!(stopped_reason(missing), "No camera found.");
+        assert!(stopped_reason("/dev/video0: Permission denied\n").contains("denied"));
+        assert_eq!(stopped_reason("weird failure\n\n"), "weird failure");
+        assert_eq!(stopped_reason(""), "The camera stopped.");
+    }
+}
@@ -0,0 +1,102 @@
+use crate::export::ExportFormat;
+use easyocr_core::{OcrEngine, Settings};
+use std::path::PathBuf;
+
+const USAGE: &str = "Usage: easyocr-gui --ocr <image> [--lang en,ch_sim] [--json]";
+
+/// A run without the GUI, asked for on the command line.
+#[derive(Debug, PartialEq)]
+pub struct Headless {
+    image: PathBuf,
+    /// Replaces the saved language list for this run
+    languages: Option<String>,
+    json: bool,
+}
+
+/// Reads the command line.  `Ok(None)` means no `--ocr` was given and the
+/// GUI should start as usual.
+pub fn parse(args: &[String]) -> Result<Option<Headless>, String> {
+    if !args.iter().any(|a| a == "--ocr") {
+        return Ok(None);
+    }
+    let mut image = None;
+    let mut languages = None;
+    let mut json = false;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--ocr" => image = Some(args.next().ok_or("--ocr needs an image path")?.into()),
+            "--lang" => languages = Some(args.next().ok_or("--lang needs language codes")?.clone()),
+            "--json" => json = true,
+            other => return Err(format!("Unknown argument: {other}")),
+        }
+    }
+    let image = image.ok_or("--ocr needs an image path")?;
+    Ok(Some(Headless { image, languages, json }))
+}
+
+/// Handles a headless run if the command line asks for one, returning the
+/// process exit code: 0 on success, 1 when OCR failed, 2 for bad arguments.
+pub fn run_from_args() -> Option<i32> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match parse(&args) {
+        Ok(None) => None,
+        Ok(Some(headless)) => Some(headless.run()),
+        Err(e) => {
+            eprintln!("{e}\n{USAGE}");
+            Some(2)
+        }
+    }
+}
+
+impl Headless {
+    /// OCRs the image with the saved settings and prints the text, one line
+    /// per recognised line, or the same JSON the Export menu writes.
+    fn run(self) -> i32 {
+        let mut settings = Settings::load();
+        if let Some(languages) = self.languages {
+            settings.languages = languages;
+        }
+        // A server would only outlive this one image.
+        settings.persistent_server = false;
+        match OcrEngine::new(settings).run(&self.image) {
+            Ok(lines) => {
+                let format = if self.json { ExportFormat::Json } else { ExportFormat::Text };
+                let out = format.render(&self.image, &lines);
+                if !out.is_empty() {
+                    println!("{out}");
+                }
+                0
+            }
+            Err(e) => {
+                eprintln!("{e}");
+                1
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, Headless};
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(str::to_owned).collect()
+    }
+
+    #[test]
+    fn parses_headless_runs() {
+        assert_eq!(parse(&[]), Ok(None));
+        assert_eq!(
+            parse(&args("--json --ocr scan.png --lang en,ch_sim")),
+            Ok(Some(Headless {
+                image: "scan.png".into(),
+                languages: Some("en,ch_sim".into()),
+                json: true,
+            }))
+        );
+        assert!(parse(&args("--ocr")).is_err());
+        assert!(parse(&args("--ocr scan.png --lang")).is_err());
+        assert!(parse(&args("--ocr scan.png --frobnicate")).is_err());
+    }
+}
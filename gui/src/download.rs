@@ -0,0 +1,104 @@
+use image::ImageFormat;
+use std::io::Read;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+/// Downloads larger than this are refused rather than read into memory.
+const MAX_DOWNLOAD_BYTES: u64 = 50 * 1024 * 1024;
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Fetches `url` on a background thread and sends back the temp file the
+/// image was saved to, or a message for the status bar.
+pub fn download_image_async(url: &str) -> mpsc::Receiver<Result<PathBuf, String>> {
+    let (tx, rx) = mpsc::channel();
+    let url = url.trim().to_string();
+    thread::spawn(move || {
+        let _ = tx.send(download_image(&url));
+    });
+    rx
+}
+
+fn download_image(url: &str) -> Result<PathBuf, String> {
+    if !(url.starts_with("http://") || url.starts_with("https://")) {
+        return Err(format!("Not an http(s) URL: {}", url));
+    }
+    let response = ureq::get(url)
+        .timeout(TIMEOUT)
+        .call()
+        .map_err(|e| match e {
+            ureq::Error::Status(code, r) => format!("HTTP {} {}", code, r.status_text()),
+            ureq::Error::Transport(t) => t.to_string(),
+        })?;
+    let content_type = response.header("Content-Type").map(str::to_string);
+    let mut bytes = Vec::new();
+    response
+        .into_reader()
+        .take(MAX_DOWNLOAD_BYTES + 1)
+        .read_to_end(&mut bytes)
+        .map_err(|e| e.to_string())?;
+    if bytes.len() as u64 > MAX_DOWNLOAD_BYTES {
+        return Err(format!("Image is larger than {} MB", MAX_DOWNLOAD_BYTES / (1024 * 1024)));
+    }
+    let format = check_image(content_type.as_deref(), &bytes)?;
+    let path = download_path(format);
+    std::fs::write(&path, &bytes).map_err(|e| format!("{}: {}", path.display(), e))?;
+    Ok(path)
+}
+
+/// The format of a downloaded body, judged by its content rather than the
+/// server's word.  An explicit non-image content type (say an HTML error
+/// page) is reported as such.
+fn check_image(content_type: Option<&str>, bytes: &[u8]) -> Result<ImageFormat, String> {
+    let mime = content_type
+        .and_then(|t| t.split(';').next())
+        .map(|t| t.trim().to_ascii_lowercase());
+    match image::guess_format(bytes) {
+        Ok(format) if format.reading_enabled() => Ok(format),
+        _ => match mime {
+            Some(mime) if !mime.starts_with("image/") => {
+                Err(format!("The URL returned {}, not an image", mime))
+            }
+            _ => Err("The URL did not return a supported image".into()),
+        },
+    }
+}
+
+/// Start of the temp file names downloads get this session; they are
+/// deleted on exit along with the temp captures.
+pub fn temp_prefix() -> String {
+    format!("easyocr_gui_url_{}_", std::process::id())
+}
+
+fn download_path(format: ImageFormat) -> PathBuf {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let ext = format.extensions_str().first().copied().unwrap_or("img");
+    std::env::temp_dir().join(format!(
+        "{}{}.{}",
+        temp_prefix(),
+        COUNTER.fetch_add(1, Ordering::Relaxed),
+        ext
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::check_image;
+    use image::ImageFormat;
+
+    #[test]
+    fn check_image_trusts_content_over_headers() {
+        let png = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR";
+        assert_eq!(check_image(Some("application/octet-stream"), png), Ok(ImageFormat::Png));
+        assert_eq!(check_image(None, png), Ok(ImageFormat::Png));
+        let html = b"<!doctype html><title>Not found</title>";
+        assert_eq!(
+            check_image(Some("text/html; charset=utf-8"), html),
+            Err("The URL returned text/html, not an image".into())
+        );
+        assert!(check_image(Some("image/png"), html).is_err());
+    }
+}
@@ -0,0 +1,452 @@
+use easyocr_core::lang::Script;
+use easyocr_core::ocr::{OcrLine, OcrResult};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+/// File formats offered by the Export menu.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExportFormat {
+    Text,
+    Csv,
+    Json,
+    /// hOCR (XHTML with `ocr_line` bounding boxes) for document-management
+    /// and PDF tools.
+    Hocr,
+    /// Plain text with each line placed on a monospace grid near where it
+    /// sits in the image, for forms and tables whose layout carries meaning.
+    Layout,
+}
+
+impl ExportFormat {
+    pub fn all() -> &'static [ExportFormat] {
+        &[
+            ExportFormat::Text,
+            ExportFormat::Csv,
+            ExportFormat::Json,
+            ExportFormat::Hocr,
+            ExportFormat::Layout,
+        ]
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Text => "txt",
+            ExportFormat::Csv => "csv",
+            ExportFormat::Json => "json",
+            ExportFormat::Hocr => "hocr",
+            ExportFormat::Layout => "txt",
+        }
+    }
+
+    /// Serialize the lines recognised in `image`.  Only hOCR and the layout
+    /// look at the image itself, for the page size.
+    pub fn render(&self, image: &Path, lines: &[OcrLine]) -> String {
+        match self {
+            ExportFormat::Text => to_text(lines),
+            ExportFormat::Csv => to_csv(lines),
+            ExportFormat::Json => to_json(lines),
+            ExportFormat::Hocr => hocr_document(&[hocr_page(1, image, lines)]),
+            ExportFormat::Layout => {
+                let (width, height) = page_size(image, lines);
+                to_layout(lines, width, height)
+            }
+        }
+    }
+
+    /// Serialize the results of a folder batch.  Failed images are kept so the
+    /// export accounts for every file: as a `[error]` note in text, an empty
+    /// row in CSV, and an `error` field in JSON.
+    pub fn render_batch(&self, results: &[(PathBuf, OcrResult)]) -> String {
+        match self {
+            ExportFormat::Text | ExportFormat::Layout => results
+                .iter()
+                .map(|(path, res)| {
+                    let body = match &res.error {
+                        Some(err) => format!("[{}]", err.summary()),
+                        None => self.render(path, &res.lines),
+                    };
+                    format!("== {} ==\n{}", path.display(), body)
+                })
+                .collect::<Vec<_>>()
+                .join("\n\n"),
+            ExportFormat::Csv => {
+                let mut out = format!("file,{}\n", CSV_HEADER);
+                for (path, res) in results {
+                    let file = csv_field(&path.display().to_string());
+                    if res.lines.is_empty() {
+                        out.push_str(&file);
+                        out.push_str(",,,,,,,,,,\n");
+                    }
+                    for line in &res.lines {
+                        out.push_str(&file);
+                        out.push(',');
+                        out.push_str(&csv_row(line));
+                        out.push('\n');
+                    }
+                }
+                out
+            }
+            ExportFormat::Json => {
+                let entries: Vec<BatchEntry> = results
+                    .iter()
+                    .map(|(path, res)| BatchEntry {
+                        file: path.display().to_string(),
+                        lines: json_lines(&res.lines),
+                        error: res.error.as_ref().map(ToString::to_string),
+                    })
+                    .collect();
+                serde_json::to_string_pretty(&entries).unwrap_or_default()
+            }
+            // One page per image; failed images become empty pages.
+            ExportFormat::Hocr => {
+                let pages: Vec<String> = results
+                    .iter()
+                    .enumerate()
+                    .map(|(i, (path, res))| hocr_page(i + 1, path, &res.lines))
+                    .collect();
+                hocr_document(&pages)
+            }
+        }
+    }
+}
+
+const CSV_HEADER: &str = "text,confidence,x1,y1,x2,y2,x3,y3,x4,y4";
+
+#[derive(Serialize)]
+struct BatchEntry<'a> {
+    file: String,
+    lines: Vec<JsonLine<'a>>,
+    error: Option<String>,
+}
+
+/// A line as exported to JSON, tagged with its script so the lines in one
+/// writing system can be picked out downstream.
+#[derive(Serialize)]
+struct JsonLine<'a> {
+    #[serde(flatten)]
+    line: &'a OcrLine,
+    script: Option<Script>,
+}
+
+fn json_lines(lines: &[OcrLine]) -> Vec<JsonLine<'_>> {
+    lines.iter().map(|line| JsonLine { line, script: line.script() }).collect()
+}
+
+/// One recognised text per line.
+fn to_text(lines: &[OcrLine]) -> String {
+    lines
+        .iter()
+        .map(|l| l.text.as_str())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn to_csv(lines: &[OcrLine]) -> String {
+    let mut out = format!("{}\n", CSV_HEADER);
+    for line in lines {
+        out.push_str(&csv_row(line));
+        out.push('\n');
+    }
+    out
+}
+
+fn to_json(lines: &[OcrLine]) -> String {
+    serde_json::to_string_pretty(&json_lines(lines)).unwrap_or_default()
+}
+
+/// Lays `lines` out on a grid of character cells over a `width` × `height`
+/// image.  A cell is as wide as the average character and as tall as the
+/// average line, so each line lands in the row of its centre and is centred
+/// on it horizontally.  A line that would overwrite text already in its row
+/// is pushed right past it.  Lines without a box can't be placed and follow
+/// the grid as plain text.
+fn to_layout(lines: &[OcrLine], width: u32, height: u32) -> String {
+    let placed: Vec<&OcrLine> =
+        lines.iter().filter(|l| l.has_position() && !l.text.is_empty()).collect();
+    let loose: Vec<&str> =
+        lines.iter().filter(|l| !l.has_position()).map(|l| l.text.as_str()).collect();
+    if placed.is_empty() {
+        return loose.join("\n");
+    }
+
+    let chars = |l: &OcrLine| l.text.chars().count() as f32;
+    let char_width = placed
+        .iter()
+        .map(|l| {
+            let [x0, _, x1, _] = axis_aligned(&l.bbox);
+            x1.saturating_sub(x0) as f32 / chars(l)
+        })
+        .sum::<f32>()
+        / placed.len() as f32;
+    let row_height = placed
+        .iter()
+        .map(|l| {
+            let [_, y0, _, y1] = axis_aligned(&l.bbox);
+            y1.saturating_sub(y0) as f32
+        })
+        .sum::<f32>()
+        / placed.len() as f32;
+    let char_width = char_width.max(1.0);
+    let row_height = row_height.max(1.0);
+    let columns = (width as f32 / char_width).ceil() as usize;
+    let rows = (height as f32 / row_height).ceil() as usize;
+
+    let centre = |l: &OcrLine| {
+        let (x, y) = l.bbox.iter().fold((0.0, 0.0), |(x, y), p| (x + p[0], y + p[1]));
+        (x / 4.0, y / 4.0)
+    };
+    let mut placed = placed;
+    placed.sort_by(|a, b| {
+        let (ax, ay) = centre(a);
+        let (bx, by) = centre(b);
+        ay.total_cmp(&by).then(ax.total_cmp(&bx))
+    });
+
+    let mut grid: Vec<Vec<char>> = vec![Vec::new(); rows];
+    for line in placed {
+        let (x, y) = centre(line);
+        let text: Vec<char> = line.text.chars().collect();
+        let row = ((y / row_height).max(0.0) as usize).min(rows.saturating_sub(1));
+        if grid.len() <= row {
+            grid.resize(row + 1, Vec::new());
+        }
+        let cells = &mut grid[row];
+        let wanted = (x / char_width - text.len() as f32 / 2.0).round().max(0.0) as usize;
+        // Keep text from running off the right edge of the page.
+        let mut column = wanted.min(columns.saturating_sub(text.len()));
+        if column < cells.len() {
+            column = column.max(cells.len() + 1);
+        }
+        cells.resize(column, ' ');
+        cells.extend(text);
+    }
+
+    let mut out: Vec<String> =
+        grid.iter().map(|cells| cells.iter().collect::<String>().trim_end().to_string()).collect();
+    while out.last().is_some_and(|r| r.is_empty()) {
+        out.pop();
+    }
+    out.extend(loose.iter().map(|t| t.to_string()));
+    out.join("\n")
+}
+
+fn hocr_document(pages: &[String]) -> String {
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <!DOCTYPE html PUBLIC \"-//W3C//DTD XHTML 1.0 Transitional//EN\" \
+         \"http://www.w3.org/TR/xhtml1/DTD/xhtml1-transitional.dtd\">\n\
+         <html xmlns=\"http://www.w3.org/1999/xhtml\">\n\
+         <head>\n\
+         <title></title>\n\
+         <meta http-equiv=\"Content-Type\" content=\"text/html;charset=utf-8\"/>\n\
+         <meta name=\"ocr-system\" content=\"easyocr-gui\"/>\n\
+         <meta name=\"ocr-capabilities\" content=\"ocr_page ocr_line ocrx_word\"/>\n\
+         </head>\n\
+         <body>\n{}</body>\n</html>\n",
+        pages.concat()
+    )
+}
+
+/// One `ocr_page` div.  Each detection becomes an `ocr_line` holding a
+/// single `ocrx_word`, since EasyOCR does not split lines into words.
+fn hocr_page(number: usize, image: &Path, lines: &[OcrLine]) -> String {
+    let (width, height) = page_size(image, lines);
+    let mut out = format!(
+        "<div class=\"ocr_page\" id=\"page_{n}\" title=\"image &quot;{}&quot;; bbox 0 0 {} {}; ppageno {}\">\n",
+        xml_escape(&image.display().to_string()),
+        width,
+        height,
+        number - 1,
+        n = number,
+    );
+    for (i, line) in lines.iter().enumerate() {
+        let [x0, y0, x1, y1] = axis_aligned(&line.bbox);
+        let bbox = format!("bbox {} {} {} {}", x0, y0, x1, y1);
+        out.push_str(&format!(
+            "<span class=\"ocr_line\" id=\"line_{n}_{i}\" title=\"{bbox}\">\
+             <span class=\"ocrx_word\" id=\"word_{n}_{i}\" title=\"{bbox}; x_wconf {conf}\">{text}</span>\
+             </span>\n",
+            n = number,
+            i = i + 1,
+            bbox = bbox,
+            conf = (line.confidence * 100.0).round().clamp(0.0, 100.0) as u32,
+            text = xml_escape(&line.text),
+        ));
+    }
+    out.push_str("</div>\n");
+    out
+}
+
+/// Size of `image` from its header, or the extent of the boxes when the
+/// image can no longer be read.
+fn page_size(image: &Path, lines: &[OcrLine]) -> (u32, u32) {
+    image::image_dimensions(image).unwrap_or_else(|_| {
+        lines.iter().map(|l| axis_aligned(&l.bbox)).fold((0, 0), |(w, h), [_, _, x1, y1]| {
+            (w.max(x1), h.max(y1))
+        })
+    })
+}
+
+/// `[x0, y0, x1, y1]` enclosing a (possibly rotated) quadrilateral, clamped
+/// to non-negative pixel coordinates.
+fn axis_aligned(bbox: &[[f32; 2]; 4]) -> [u32; 4] {
+    let xs = bbox.iter().map(|p| p[0]);
+    let ys = bbox.iter().map(|p| p[1]);
+    let px = |v: f32| v.max(0.0).round() as u32;
+    [
+        px(xs.clone().fold(f32::INFINITY, f32::min)),
+        px(ys.clone().fold(f32::INFINITY, f32::min)),
+        px(xs.fold(f32::NEG_INFINITY, f32::max)),
+        px(ys.fold(f32::NEG_INFINITY, f32::max)),
+    ]
+}
+
+fn xml_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn csv_row(line: &OcrLine) -> String {
+    let mut fields = vec![csv_field(&line.text), line.confidence.to_string()];
+    for [x, y] in &line.bbox {
+        fields.push(x.to_string());
+        fields.push(y.to_string());
+    }
+    fields.join(",")
+}
+
+/// Quote a CSV field per RFC 4180 when it contains a delimiter, quote or
+/// line break; embedded quotes are doubled.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{csv_field, to_layout, ExportFormat};
+    use easyocr_core::ocr::{OcrLine, OcrResult};
+    use easyocr_core::OcrError;
+    use std::path::{Path, PathBuf};
+
+    fn line(text: &str, confidence: f32) -> OcrLine {
+        OcrLine {
+            bbox: [[1.0, 2.0], [3.0, 2.0], [3.0, 4.0], [1.0, 4.0]],
+            text: text.to_string(),
+            confidence,
+        }
+    }
+
+    #[test]
+    fn csv_field_quotes_only_when_needed() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("two\nlines"), "\"two\nlines\"");
+    }
+
+    #[test]
+    fn csv_export_has_header_and_bbox_columns() {
+        let out = ExportFormat::Csv.render(Path::new("a.png"), &[line("hello, \"world\"", 0.5)]);
+        let mut rows = out.lines();
+        assert_eq!(rows.next(), Some("text,confidence,x1,y1,x2,y2,x3,y3,x4,y4"));
+        assert_eq!(rows.next(), Some("\"hello, \"\"world\"\"\",0.5,1,2,3,2,3,4,1,4"));
+        assert_eq!(rows.next(), None);
+    }
+
+    #[test]
+    fn text_export_is_one_line_per_detection() {
+        let out = ExportFormat::Text.render(Path::new("a.png"), &[line("one", 0.9), line("two", 0.8)]);
+        assert_eq!(out, "one\ntwo");
+    }
+
+    #[test]
+    fn json_export_round_trips() {
+        let out = ExportFormat::Json.render(Path::new("a.png"), &[line("it's", 0.25)]);
+        let value: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(value[0]["text"], "it's");
+        assert_eq!(value[0]["confidence"], 0.25);
+        assert_eq!(value[0]["bbox"][2][1], 4.0);
+        assert_eq!(value[0]["script"], "latin");
+    }
+
+    #[test]
+    fn hocr_export_has_line_boxes_and_escaped_text() {
+        let out = ExportFormat::Hocr.render(Path::new("missing.png"), &[line("a < b & c", 0.876)]);
+        // The image can't be read, so the page is sized to the boxes.
+        assert!(out.contains("title=\"image &quot;missing.png&quot;; bbox 0 0 3 4; ppageno 0\""));
+        assert!(out.contains("<span class=\"ocr_line\" id=\"line_1_1\" title=\"bbox 1 2 3 4\">"));
+        assert!(out.contains("title=\"bbox 1 2 3 4; x_wconf 88\">a &lt; b &amp; c</span>"));
+    }
+
+    fn boxed(text: &str, x0: f32, y0: f32, x1: f32, y1: f32) -> OcrLine {
+        OcrLine {
+            bbox: [[x0, y0], [x1, y0], [x1, y1], [x0, y1]],
+            text: text.to_string(),
+            confidence: 0.9,
+        }
+    }
+
+    #[test]
+    fn layout_places_lines_by_position() {
+        // 10 px per character, 20 px per row.
+        let lines = [
+            boxed("Total", 100.0, 40.0, 150.0, 60.0),
+            boxed("Name", 0.0, 0.0, 40.0, 20.0),
+            boxed("Bob", 100.0, 0.0, 130.0, 20.0),
+            boxed("42", 0.0, 40.0, 20.0, 60.0),
+        ];
+        assert_eq!(to_layout(&lines, 200, 100), "Name      Bob\n\n42        Total");
+    }
+
+    #[test]
+    fn layout_keeps_overlapping_lines_apart() {
+        let lines = [boxed("abcd", 0.0, 0.0, 40.0, 20.0), boxed("ef", 20.0, 0.0, 40.0, 20.0)];
+        assert_eq!(to_layout(&lines, 40, 20), "abcd ef");
+    }
+
+    #[test]
+    fn layout_appends_text_only_lines() {
+        let mut loose = line("no box", 0.0);
+        loose.bbox = [[0.0; 2]; 4];
+        let lines = [boxed("boxed", 0.0, 0.0, 50.0, 20.0), loose];
+        assert_eq!(to_layout(&lines, 50, 20), "boxed\nno box");
+    }
+
+    #[test]
+    fn batch_csv_keeps_failed_files() {
+        let results = vec![
+            (
+                PathBuf::from("a.png"),
+                OcrResult {
+                    lines: vec![line("x", 1.0)],
+                    unparsed: vec![],
+                    stderr_note: None,
+                    raw: String::new(),
+                    error: None,
+                },
+            ),
+            (
+                PathBuf::from("b.png"),
+                OcrResult {
+                    lines: vec![],
+                    unparsed: vec![],
+                    stderr_note: None,
+                    raw: String::new(),
+                    error: Some(OcrError::Cancelled),
+                },
+            ),
+        ];
+        let out = ExportFormat::Csv.render_batch(&results);
+        let rows: Vec<&str> = out.lines().collect();
+        assert_eq!(rows.len(), 3);
+        assert!(rows[1].starts_with("a.png,x,1,"));
+        assert_eq!(rows[2], "b.png,,,,,,,,,,");
+    }
+}
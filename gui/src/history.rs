@@ -0,0 +1,134 @@
+use easyocr_core::ocr::OcrLine;
+use easyocr_core::settings::Settings;
+use serde::{Deserialize, Serialize};
+use std::io;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// One completed OCR run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+    pub image_path: String,
+    pub lines: Vec<OcrLine>,
+}
+
+/// Past OCR runs, newest first, persisted to `history.json` next to the
+/// settings file.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct History {
+    pub entries: Vec<HistoryEntry>,
+}
+
+impl History {
+    pub fn path() -> Option<PathBuf> {
+        Settings::config_dir().map(|p| p.join("history.json"))
+    }
+
+    pub fn load() -> Self {
+        Self::path()
+            .and_then(|p| std::fs::read_to_string(p).ok())
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let path = Self::path()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "cannot determine config dir"))?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&path, serde_json::to_string(self)?)
+    }
+
+    /// Record a run at the front of the list, dropping the oldest entries
+    /// beyond `limit`.
+    pub fn push(&mut self, image_path: String, lines: Vec<OcrLine>, limit: usize) {
+        self.entries.insert(
+            0,
+            HistoryEntry {
+                timestamp: now_secs(),
+                image_path,
+                lines,
+            },
+        );
+        self.entries.truncate(limit);
+    }
+}
+
+/// Seconds since the Unix epoch, or 0 if the clock is before it.
+pub fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Format a Unix timestamp as `YYYY-MM-DD HH:MM` in UTC.
+pub fn format_timestamp(secs: u64) -> String {
+    let (year, month, day) = civil_date(secs);
+    let rem = secs % 86_400;
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}",
+        year,
+        month,
+        day,
+        rem / 3600,
+        rem % 3600 / 60
+    )
+}
+
+/// Format a Unix timestamp as `YYYYMMDD-HHMMSS` in UTC, for file names.
+pub fn file_stamp(secs: u64) -> String {
+    let (year, month, day) = civil_date(secs);
+    let rem = secs % 86_400;
+    format!(
+        "{:04}{:02}{:02}-{:02}{:02}{:02}",
+        year,
+        month,
+        day,
+        rem / 3600,
+        rem % 3600 / 60,
+        rem % 60
+    )
+}
+
+/// `(year, month, day)` of a Unix timestamp in UTC.
+fn civil_date(secs: u64) -> (i64, i64, i64) {
+    let days = (secs / 86_400) as i64;
+    // Civil-from-days (Howard Hinnant); valid for all dates after 1970.
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{file_stamp, format_timestamp, History};
+
+    #[test]
+    fn format_timestamp_is_utc_calendar_time() {
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00");
+        assert_eq!(format_timestamp(951_782_400), "2000-02-29 00:00");
+        assert_eq!(format_timestamp(1_700_000_000), "2023-11-14 22:13");
+        assert_eq!(file_stamp(1_700_000_000), "20231114-221320");
+    }
+
+    #[test]
+    fn push_keeps_newest_first_within_limit() {
+        let mut history = History::default();
+        for name in ["a", "b", "c"] {
+            history.push(name.to_string(), vec![], 2);
+        }
+        let paths: Vec<&str> = history.entries.iter().map(|e| e.image_path.as_str()).collect();
+        assert_eq!(paths, vec!["c", "b"]);
+    }
+}
@@ -0,0 +1,71 @@
+use global_hotkey::hotkey::HotKey;
+use global_hotkey::{GlobalHotKeyEvent, GlobalHotKeyManager, HotKeyState};
+use std::sync::mpsc::{self, Receiver};
+
+/// A system-wide shortcut that works while the window is unfocused.  Key
+/// presses wake the UI through `ctx`, since eframe may not be repainting.
+pub struct GlobalHotkey {
+    manager: GlobalHotKeyManager,
+    /// The spec that is registered, as typed in settings
+    registered: Option<(String, HotKey)>,
+    presses: Receiver<u32>,
+}
+
+impl GlobalHotkey {
+    pub fn new(ctx: &egui::Context) -> Result<Self, String> {
+        let manager = GlobalHotKeyManager::new().map_err(|e| e.to_string())?;
+        let (tx, presses) = mpsc::channel();
+        let ctx = ctx.clone();
+        GlobalHotKeyEvent::set_event_handler(Some(move |event: GlobalHotKeyEvent| {
+            if event.state() == HotKeyState::Pressed {
+                let _ = tx.send(event.id());
+                ctx.request_repaint();
+            }
+        }));
+        Ok(Self { manager, registered: None, presses })
+    }
+
+    /// Registers `spec` in place of the current shortcut, or just drops the
+    /// current one for `None`.  Does nothing when `spec` is already active.
+    pub fn set(&mut self, spec: Option<&str>) -> Result<(), String> {
+        if self.registered.as_ref().map(|(s, _)| s.as_str()) == spec {
+            return Ok(());
+        }
+        if let Some((_, hotkey)) = self.registered.take() {
+            let _ = self.manager.unregister(hotkey);
+        }
+        let Some(spec) = spec else {
+            return Ok(());
+        };
+        let hotkey = parse(spec)?;
+        self.manager.register(hotkey).map_err(|e| e.to_string())?;
+        self.registered = Some((spec.to_string(), hotkey));
+        Ok(())
+    }
+
+    /// Whether the shortcut was pressed since the last call.
+    pub fn pressed(&self) -> bool {
+        let id = self.registered.as_ref().map(|(_, hotkey)| hotkey.id());
+        // Drain every queued press so one burst starts one capture.
+        self.presses.try_iter().filter(|&pressed| Some(pressed) == id).count() > 0
+    }
+}
+
+/// Parses shortcuts like `Ctrl+Shift+O`; `CmdOrCtrl` is Cmd on macOS.
+pub fn parse(spec: &str) -> Result<HotKey, String> {
+    spec.trim().parse::<HotKey>().map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse;
+
+    #[test]
+    fn parses_modifier_combinations() {
+        assert!(parse("Ctrl+Shift+O").is_ok());
+        assert!(parse(" CmdOrCtrl+Alt+KeyX ").is_ok());
+        assert_eq!(parse("ctrl+shift+o"), parse("Ctrl+Shift+O"));
+        assert!(parse("Ctrl+Nope").is_err());
+        assert!(parse("").is_err());
+    }
+}
@@ -1,292 +0,0 @@
-use crate::settings::UiLanguage;
-
-/// All UI strings for one language.
-pub struct Strings {
-    // Tab bar
-    pub tab_ocr: &'static str,
-    pub tab_settings: &'static str,
-
-    // OCR tab — toolbar buttons
-    pub btn_open_image: &'static str,
-    pub btn_paste_image: &'static str,
-    pub btn_screenshot: &'static str,
-    pub btn_setup: &'static str,
-    pub btn_run_ocr: &'static str,
-    pub btn_copy: &'static str,
-    pub btn_copied: &'static str,
-    pub tooltip_setup: &'static str,
-
-    // OCR tab — content
-    pub lbl_results: &'static str,
-    pub placeholder_drop_image: &'static str,
-
-    // Status / error message templates (use with format!("{}", ...) substituting {})
-    pub status_load_image: &'static str,
-    pub status_image_loaded: &'static str,
-    pub status_running_ocr: &'static str,
-    pub status_ocr_complete: &'static str,
-    pub status_easyocr_missing: &'static str,
-    pub status_no_image_clipboard: &'static str,
-    pub status_clipboard_unavailable: &'static str,
-    pub status_no_screens: &'static str,
-    pub status_screenshot_failed: &'static str,
-    pub status_cant_enum_screens: &'static str,
-    pub status_failed_load_image: &'static str,
-    pub status_cant_save_tmp: &'static str,
-    pub status_ocr_failed: &'static str,
-
-    // Setup dialog
-    pub setup_title: &'static str,
-    pub setup_not_found: &'static str,
-    pub setup_must_install: &'static str,
-    pub setup_step1_title: &'static str,
-    pub setup_step1_desc: &'static str,
-    pub setup_step2_title: &'static str,
-    pub setup_step2_desc: &'static str,
-    pub setup_step3_title: &'static str,
-    pub setup_step3_desc: &'static str,
-    pub setup_tip: &'static str,
-    pub setup_btn_check: &'static str,
-    pub setup_btn_continue: &'static str,
-    pub setup_checking: &'static str,
-
-    // Settings — section headers
-    pub section_languages: &'static str,
-    pub section_hardware: &'static str,
-    pub section_decoder: &'static str,
-    pub section_recognition: &'static str,
-    pub section_thresholds: &'static str,
-    pub section_paths: &'static str,
-    pub section_ui: &'static str,
-
-    // Settings — labels & hints
-    pub lbl_language_codes: &'static str,
-    pub hint_language_codes: &'static str,
-    pub lbl_comma_separated: &'static str,
-    pub lbl_gpu: &'static str,
-    pub lbl_workers: &'static str,
-    pub hint_workers_auto: &'static str,
-    pub lbl_workers_suffix: &'static str,
-    pub lbl_quantize: &'static str,
-    pub lbl_beam_width: &'static str,
-    pub lbl_batch_size: &'static str,
-    pub lbl_min_size: &'static str,
-    pub lbl_paragraph: &'static str,
-    pub lbl_margin: &'static str,
-    pub lbl_text_threshold: &'static str,
-    pub hint_text_threshold: &'static str,
-    pub lbl_low_text: &'static str,
-    pub hint_low_text: &'static str,
-    pub lbl_link_threshold: &'static str,
-    pub hint_link_threshold: &'static str,
-    pub lbl_contrast_ths: &'static str,
-    pub hint_contrast_ths: &'static str,
-    pub lbl_adjust_contrast: &'static str,
-    pub hint_adjust_contrast: &'static str,
-    pub lbl_model_dir: &'static str,
-    pub hint_model_dir: &'static str,
-    pub lbl_easyocr_exe: &'static str,
-    pub hint_easyocr_exe: &'static str,
-    pub btn_browse: &'static str,
-    pub btn_save_settings: &'static str,
-    pub btn_reset: &'static str,
-    pub msg_settings_saved: &'static str,
-    pub msg_settings_failed: &'static str,
-    pub lbl_ui_language: &'static str,
-
-    // Decoder option labels
-    pub decoder_greedy: &'static str,
-    pub decoder_beam: &'static str,
-    pub decoder_word_beam: &'static str,
-}
-
-pub static EN: Strings = Strings {
-    tab_ocr: "🔍  OCR",
-    tab_settings: "⚙  Settings",
-
-    btn_open_image: "📂 Open Image",
-    btn_paste_image: "📋 Paste Image",
-    btn_screenshot: "📷 Screenshot",
-    btn_setup: "⚠ Setup",
-    btn_run_ocr: "▶  Run OCR",
-    btn_copy: "⎘ Copy",
-    btn_copied: "✔ Copied!",
-    tooltip_setup: "EasyOCR is not installed — click for setup instructions",
-
-    lbl_results: "Results",
-    placeholder_drop_image: "Drop an image here\nor use the buttons above",
-
-    status_load_image: "Load an image to start OCR.",
-    status_image_loaded: "{} loaded. Press 'Run OCR' to recognise text.",
-    status_running_ocr: "Running OCR…",
-    status_ocr_complete: "OCR complete — {} text region(s) detected.",
-    status_easyocr_missing: "⚠ EasyOCR not found — click the Setup button for instructions.",
-    status_no_image_clipboard: "No image found in clipboard. Copy an image first.",
-    status_clipboard_unavailable: "Clipboard unavailable: {}",
-    status_no_screens: "No screens found.",
-    status_screenshot_failed: "Screenshot failed: {}",
-    status_cant_enum_screens: "Cannot enumerate screens: {}",
-    status_failed_load_image: "Failed to load image: {}",
-    status_cant_save_tmp: "Could not save temporary image: {}",
-    status_ocr_failed: "OCR failed: {}",
-
-    setup_title: "⚙  EasyOCR Setup",
-    setup_not_found: "The easyocr command was not found on your system.",
-    setup_must_install: "EasyOCR must be installed before this application can recognise text.",
-    setup_step1_title: "Step 1 — Install Python 3.8 or newer",
-    setup_step1_desc: "Download and install Python from:",
-    setup_step2_title: "Step 2 — Install EasyOCR",
-    setup_step2_desc: "Open a terminal and run:",
-    setup_step3_title: "Step 3 — Language Models",
-    setup_step3_desc: "Models are downloaded automatically the first time you run OCR for a \
-                       language.\nThe initial download may take a few minutes depending on your \
-                       internet connection.",
-    setup_tip: "Tip: you can also point the app at a custom EasyOCR executable via the Settings tab.",
-    setup_btn_check: "✓  Check Again",
-    setup_btn_continue: "Continue Anyway",
-    setup_checking: "Checking…",
-
-    section_languages: "Languages",
-    section_hardware: "Hardware",
-    section_decoder: "Decoder",
-    section_recognition: "Recognition",
-    section_thresholds: "Detection Thresholds",
-    section_paths: "Paths (optional)",
-    section_ui: "Interface",
-
-    lbl_language_codes: "Language codes:",
-    hint_language_codes: "e.g. en,ch_sim,fr",
-    lbl_comma_separated: "(comma-separated)",
-    lbl_gpu: "Enable GPU acceleration",
-    lbl_workers: "Parallel CPU workers:",
-    hint_workers_auto: "(0 = auto)",
-    lbl_workers_suffix: " workers",
-    lbl_quantize: "Use dynamic quantization (reduces memory)",
-    lbl_beam_width: "Beam width:",
-    lbl_batch_size: "Batch size:",
-    lbl_min_size: "Min text box size (px):",
-    lbl_paragraph: "Merge results into paragraphs",
-    lbl_margin: "Bounding box margin:",
-    lbl_text_threshold: "Text confidence:",
-    hint_text_threshold: "Minimum confidence to accept a text region.",
-    lbl_low_text: "Low-text score:",
-    hint_low_text: "Lower bound for text score.",
-    lbl_link_threshold: "Link threshold:",
-    hint_link_threshold: "Threshold for linking text regions.",
-    lbl_contrast_ths: "Contrast threshold:",
-    hint_contrast_ths: "Boxes below this contrast are processed twice.",
-    lbl_adjust_contrast: "Adjust contrast to:",
-    hint_adjust_contrast: "Target for low-contrast boxes.",
-    lbl_model_dir: "Model storage directory:",
-    hint_model_dir: "Default: ~/.EasyOCR/model",
-    lbl_easyocr_exe: "EasyOCR executable path:",
-    hint_easyocr_exe: "Default: 'easyocr' (from PATH)",
-    btn_browse: "Browse…",
-    btn_save_settings: "💾  Save Settings",
-    btn_reset: "↺  Reset to Defaults",
-    msg_settings_saved: "Settings saved successfully.",
-    msg_settings_failed: "Failed to save: {}",
-    lbl_ui_language: "Interface language:",
-
-    decoder_greedy: "Greedy (Fast)",
-    decoder_beam: "Beam Search (Accurate)",
-    decoder_word_beam: "Word Beam Search (Most Accurate)",
-};
-
-pub static ZH: Strings = Strings {
-    tab_ocr: "🔍  文字识别",
-    tab_settings: "⚙  设置",
-
-    btn_open_image: "📂 打开图片",
-    btn_paste_image: "📋 粘贴图片",
-    btn_screenshot: "📷 截图",
-    btn_setup: "⚠ 安装向导",
-    btn_run_ocr: "▶  运行识别",
-    btn_copy: "⎘ 复制",
-    btn_copied: "✔ 已复制!",
-    tooltip_setup: "未找到 EasyOCR，点击查看安装说明",
-
-    lbl_results: "识别结果",
-    placeholder_drop_image: "将图片拖放到此处\n或使用上方按钮",
-
-    status_load_image: "请加载图片以开始文字识别。",
-    status_image_loaded: "{}已加载，请点击「运行识别」。",
-    status_running_ocr: "正在识别…",
-    status_ocr_complete: "识别完成 — 检测到 {} 个文字区域。",
-    status_easyocr_missing: "⚠ 未找到 EasyOCR — 请点击安装向导按钮查看说明。",
-    status_no_image_clipboard: "剪贴板中未找到图片，请先复制一张图片。",
-    status_clipboard_unavailable: "剪贴板不可用：{}",
-    status_no_screens: "未找到屏幕。",
-    status_screenshot_failed: "截图失败：{}",
-    status_cant_enum_screens: "无法枚举屏幕：{}",
-    status_failed_load_image: "加载图片失败：{}",
-    status_cant_save_tmp: "无法保存临时图片：{}",
-    status_ocr_failed: "识别失败：{}",
-
-    setup_title: "⚙  EasyOCR 安装向导",
-    setup_not_found: "在您的系统上未找到 easyocr 命令。",
-    setup_must_install: "请先安装 EasyOCR，应用程序才能进行文字识别。",
-    setup_step1_title: "第一步 — 安装 Python 3.8 或更高版本",
-    setup_step1_desc: "请从以下网址下载并安装 Python：",
-    setup_step2_title: "第二步 — 安装 EasyOCR",
-    setup_step2_desc: "打开终端并运行：",
-    setup_step3_title: "第三步 — 语言模型",
-    setup_step3_desc: "首次对某语言进行 OCR 时会自动下载模型。\n初次下载可能需要几分钟，具体取决于您的网络速度。",
-    setup_tip: "提示：您也可以在「设置」选项卡中指定自定义的 EasyOCR 可执行文件路径。",
-    setup_btn_check: "✓  重新检测",
-    setup_btn_continue: "忽略并继续",
-    setup_checking: "检测中…",
-
-    section_languages: "识别语言",
-    section_hardware: "硬件",
-    section_decoder: "解码器",
-    section_recognition: "识别参数",
-    section_thresholds: "检测阈值",
-    section_paths: "路径（可选）",
-    section_ui: "界面",
-
-    lbl_language_codes: "语言代码：",
-    hint_language_codes: "例：en,ch_sim,fr",
-    lbl_comma_separated: "（逗号分隔）",
-    lbl_gpu: "启用 GPU 加速",
-    lbl_workers: "并行 CPU 工作线程：",
-    hint_workers_auto: "（0 = 自动）",
-    lbl_workers_suffix: " 个线程",
-    lbl_quantize: "使用动态量化（减少内存占用）",
-    lbl_beam_width: "束宽：",
-    lbl_batch_size: "批量大小：",
-    lbl_min_size: "最小文字框大小（像素）：",
-    lbl_paragraph: "将结果合并为段落",
-    lbl_margin: "边界框边距：",
-    lbl_text_threshold: "文字置信度：",
-    hint_text_threshold: "接受文字区域的最低置信度。",
-    lbl_low_text: "低文字分数：",
-    hint_low_text: "文字分数的下限。",
-    lbl_link_threshold: "链接阈值：",
-    hint_link_threshold: "链接文字区域的阈值。",
-    lbl_contrast_ths: "对比度阈值：",
-    hint_contrast_ths: "低于此对比度的框将被处理两次。",
-    lbl_adjust_contrast: "调整对比度至：",
-    hint_adjust_contrast: "低对比度框的目标对比度。",
-    lbl_model_dir: "模型存储目录：",
-    hint_model_dir: "默认：~/.EasyOCR/model",
-    lbl_easyocr_exe: "EasyOCR 可执行文件路径：",
-    hint_easyocr_exe: "默认：'easyocr'（从 PATH 中查找）",
-    btn_browse: "浏览…",
-    btn_save_settings: "💾  保存设置",
-    btn_reset: "↺  恢复默认值",
-    msg_settings_saved: "设置保存成功。",
-    msg_settings_failed: "保存失败：{}",
-    lbl_ui_language: "界面语言：",
-
-    decoder_greedy: "贪婪（快速）",
-    decoder_beam: "束搜索（准确）",
-    decoder_word_beam: "词束搜索（最准确）",
-};
-
-pub fn get(lang: &UiLanguage) -> &'static Strings {
-    match lang {
-        UiLanguage::Chinese => &ZH,
-        UiLanguage::English => &EN,
-    }
-}
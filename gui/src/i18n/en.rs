@@ -0,0 +1,329 @@
+use super::Strings;
+
+pub static EN: Strings = Strings {
+    tab_ocr: "🔍  OCR",
+    tab_history: "🕘  History",
+    tab_settings: "⚙  Settings",
+
+    btn_open_image: "📂 Open Image",
+    btn_paste_image: "📋 Paste Image",
+    btn_open_folder: "🗁 Open Folder",
+    hint_open_recent: "Open recent",
+    btn_screenshot: "📷 Screenshot",
+    btn_screenshot_region: "✂ Region",
+    btn_from_url: "🌐 From URL",
+    btn_camera: "📷 Camera",
+    hint_region_select: "Drag to select the region to recognise — Esc to cancel",
+    lbl_screen: "Screen",
+    lbl_primary: "primary",
+    btn_setup: "⚠ Setup",
+    btn_run_ocr: "▶  Run OCR",
+    btn_copy: "⎘ Copy",
+    btn_copied: "✔ Copied!",
+    hint_copy_format: "Copy format",
+    copy_text_only: "Copy text only",
+    copy_with_confidence: "Copy with confidence",
+    copy_tsv: "Copy as TSV (text ⇥ confidence)",
+    btn_cancel: "✖ Cancel",
+    btn_export: "💾 Export",
+    export_txt: "Plain text (.txt)",
+    export_csv: "CSV (.csv)",
+    export_json: "JSON (.json)",
+    export_hocr: "hOCR (.hocr)",
+    export_layout: "Positioned layout (.txt)",
+    tooltip_setup: "EasyOCR is not installed — click for setup instructions",
+
+    lbl_results: "Results",
+    lbl_plain_text: "Plain text",
+    lbl_page: "Page {}/{}",
+    lbl_frame: "Frame {}/{}",
+    lbl_min_confidence: "Min confidence:",
+    lbl_confidence_filtered: "Showing {} of {} ({} below threshold)",
+    lbl_result_stats: "{} lines · {} words · {} characters",
+    lbl_result_confidence: " · mean {} · min {}",
+    lbl_reading_time: " · ~{} min read",
+    lbl_compare: "Compare",
+    hint_compare: "Run the image with the current settings and with a preset applied, and show both results side by side",
+    hint_compare_preset: "Preset applied to the current settings for the second column",
+    lbl_compare_current: "Current settings",
+    placeholder_drop_image: "Drop an image here\nor use the buttons above",
+    onboarding_title: "Getting started",
+    onboarding_drop: "Drop an image or PDF here, or use Open Image",
+    onboarding_paste: "Paste an image from the clipboard with {}",
+    onboarding_screenshot: "Capture the screen or a region with Screenshot",
+    onboarding_settings: "Pick languages and tune recognition on the Settings tab",
+    btn_onboarding_dismiss: "Got it",
+
+    status_load_image: "Load an image to start OCR.",
+    status_image_loaded: "{} loaded. Press 'Run OCR' to recognise text.",
+    status_image_reloaded: "Reloaded {} from disk.",
+    status_opened_in_editor: "Opened {} in the default image app. Save it there, then reload it here.",
+    status_cant_open_editor: "Couldn't open the image in another app: {}",
+    url_title: "Open image from URL",
+    btn_download: "Download",
+    status_downloading: "Downloading {}…",
+    status_reading_pages: "Reading the pages of {}…",
+    status_reading_documents: "Reading the pages of the dropped documents…",
+    status_url_failed: "Could not load the image from the URL: {}",
+    path_title: "Enter a path",
+    btn_use_path: "OK",
+    btn_type_path: "Type a path instead…",
+    hint_type_path: "For when the system file dialog doesn't open, e.g. without a desktop portal.",
+    hint_reload_image: "Reload the image from disk",
+    hint_open_in_editor: "Open the image in the default image editor",
+    hint_select_region: "Shift+drag on the image to select a region; right-click clears it.",
+    lbl_region_lines: "{} of {} lines in the selection",
+    lbl_lines_left_out: "{} line(s) left out of Copy and Export",
+    hint_result_keys: "↑/↓ pick a line, Enter copies it, Space leaves it out of Copy and Export or brings it back.",
+    hint_region_lines: "Copy and Export use only the lines in the selection.",
+    hint_clear_selection: "Clear the selection",
+    status_document_loaded: "{} loaded — {} pages. Press 'Run OCR' to recognise all of them.",
+    status_animation_loaded: "{} loaded — {} frames. Pick one and press 'Run OCR'.",
+    status_running_ocr: "Running OCR…",
+    status_ocr_complete: "OCR complete — {} text region(s) detected.",
+    status_no_text_stderr: "No text detected. EasyOCR said: {}",
+    status_compare_complete: "Comparison finished — {} vs {} text region(s).",
+    status_done_in: "Done in {}s.",
+    status_easyocr_missing: "⚠ EasyOCR not found — click the Setup button for instructions.",
+    status_no_image_clipboard: "No image found in clipboard. Copy an image first.",
+    status_clipboard_unavailable: "Clipboard unavailable: {}",
+    status_clipboard_unchanged: "The clipboard still holds the image already shown.",
+    status_large_image: "Large image ({} MP): OCR on CPU may take several minutes.",
+    btn_downscale: "Downscale",
+    status_no_screens: "No screens found.",
+    status_screenshot_failed: "Screenshot failed: {}",
+    status_camera_failed: "Camera unavailable: {}",
+    status_cant_enum_screens: "Cannot enumerate screens: {}",
+    status_failed_load_image: "Failed to load image: {}",
+    status_cant_save_tmp: "Could not save temporary image: {}",
+    status_ocr_failed: "OCR failed: {}",
+    status_cancelled: "Cancelled.",
+    status_no_images_in_folder: "No images found in the selected folder.",
+    status_no_images_dropped: "None of the dropped files is a supported image.",
+    status_busy: "OCR is already running — cancel it or wait for it to finish.",
+    status_lines_so_far: "— {} lines so far",
+    status_batch_progress: "Batch OCR {}/{}…",
+    status_batch_complete: "Batch complete — {} image(s), {} failed.",
+    lbl_queue: "Queue — {}/{} done",
+    lbl_queue_lines: "{} lines",
+    lbl_queue_failed: "failed",
+    hint_queue_drag: "Drag to change the order images are processed in.",
+    hint_queue_remove: "Remove from the queue",
+    status_exported: "Exported to {}",
+    status_export_failed: "Export failed: {}",
+    status_history_image_missing: "Image no longer exists: {} — showing saved results only.",
+    status_history_save_failed: "Could not save the history: {}",
+    status_recent_missing: "File moved or deleted: {}",
+    status_image_gone: "Image file no longer exists: {} — load it again.",
+
+    lbl_history_count: "{} past run(s)",
+    lbl_history_lines: "{} line(s)",
+    btn_clear_history: "🗑 Clear History",
+    placeholder_history: "No OCR runs yet.",
+
+    setup_title: "⚙  EasyOCR Setup",
+    setup_not_found: "The easyocr command was not found on your system.",
+    setup_module_missing: "Python was found at '{}', but the easyocr module is not installed for it.",
+    setup_must_install: "EasyOCR must be installed before this application can recognise text.",
+    setup_step1_title: "Step 1 — Install Python 3.8 or newer",
+    setup_step1_desc: "Download and install Python from:",
+    setup_step2_title: "Step 2 — Install EasyOCR",
+    setup_step2_desc: "Open a terminal and run:",
+    setup_step2_desc_interpreter: "Install it for that interpreter by running:",
+    setup_step3_title: "Step 3 — Language Models",
+    setup_step3_desc: "Models are downloaded automatically the first time you run OCR for a \
+                       language.\nThe initial download may take a few minutes depending on your \
+                       internet connection.",
+    setup_tip: "Tip: you can also point the app at a custom EasyOCR executable via the Settings tab.",
+    setup_btn_check: "✓  Check Again",
+    setup_btn_continue: "Continue Anyway",
+    setup_checking: "Checking…",
+
+    section_presets: "Presets",
+    section_languages: "Languages",
+    section_hardware: "Hardware",
+    section_decoder: "Decoder",
+    section_recognition: "Recognition",
+    section_thresholds: "Detection Thresholds",
+    section_preprocessing: "Preprocessing",
+    section_paths: "Paths (optional)",
+    section_ui: "Interface",
+
+    lbl_language_codes: "Language codes:",
+    hint_language_codes: "e.g. en,ch_sim,fr",
+    lbl_comma_separated: "(comma-separated)",
+    lbl_gpu: "Enable GPU acceleration",
+    hint_cuda_detected: "CUDA detected",
+    hint_cuda_missing: "No CUDA — GPU unavailable",
+    hint_cuda_checking: "Checking for CUDA…",
+    lbl_gpu_device: "GPU device:",
+    hint_gpu_device_placeholder: "default",
+    hint_gpu_device: "CUDA device index, e.g. 1 or cuda:1. Passed as CUDA_VISIBLE_DEVICES; leave empty for the default card.",
+    lbl_workers: "Parallel CPU workers:",
+    hint_workers_auto: "(0 = auto: one per CPU core, {} here)",
+    lbl_batch_concurrency: "Parallel images:",
+    hint_batch_concurrency: "Images of a batch OCRed at the same time. Each runs its own EasyOCR process with its own copy of the models (often 1 GB or more of memory), so raise this carefully.",
+    lbl_batch_serialized: "No effect while the model is kept loaded: its one process reads images one at a time.",
+    lbl_workers_suffix: " workers",
+    lbl_timeout: "OCR timeout:",
+    hint_timeout: "(0 = no timeout)",
+    lbl_seconds_suffix: " s",
+    lbl_quantize: "Use dynamic quantization (reduces memory)",
+    lbl_beam_width: "Beam width:",
+    lbl_batch_size: "Batch size:",
+    lbl_min_size: "Min text box size (px):",
+    lbl_canvas_size: "Canvas size (px):",
+    hint_canvas_size: "Images whose longest side exceeds this are scaled down before detection.",
+    lbl_mag_ratio: "Magnification:",
+    hint_mag_ratio: "Enlarge the image before detection; helps with small text.",
+    lbl_paragraph: "Merge results into paragraphs",
+    lbl_join_paragraph_lines: "Join lines within paragraphs",
+    hint_join_paragraph_lines: "When copying, line breaks inside a paragraph become spaces and paragraphs are separated by a blank line.",
+    lbl_x_ths: "Merge gap  x:",
+    hint_x_ths: "Paragraph mode joins boxes closer than this many box heights side by side (--x_ths, default 1.0).",
+    lbl_y_ths: "y:",
+    hint_y_ths: "Paragraph mode joins boxes closer than this many box heights above each other (--y_ths, default 0.5).",
+    lbl_margin: "Bounding box margin:",
+    lbl_json_output: "Use JSON output",
+    lbl_detail: "Include positions and confidence",
+    hint_detail: "Off = text only (--detail 0): faster and without boxes or confidence scores.",
+    lbl_detect_only: "Detect only (boxes, no text)",
+    hint_detect_only: "Finds text regions without recognising them, which is faster. Runs the EasyOCR Python API directly, so extra arguments are ignored.",
+    lbl_sort_by_position: "Sort by position",
+    hint_sort_by_position: "Reorder lines top-to-bottom, then left-to-right, so copied text reads in order.",
+    lbl_vertical_text: "Vertical text",
+    hint_vertical_text: "For text written in vertical columns, as in traditional Japanese or Chinese. Tries each box rotated by 90° and 270° and reads the columns right to left, top to bottom. The order is approximate.",
+    hint_row_tolerance: "Row tolerance: boxes whose tops differ by less than this fraction of a line height share a row.",
+    lbl_preprocess_grayscale: "Convert to grayscale",
+    lbl_preprocess_contrast: "Stretch contrast",
+    hint_preprocess_contrast: "Spread the brightness range to full black-to-white; helps faint scans.",
+    lbl_preprocess_upscale: "Upscale 2×",
+    hint_preprocess_upscale: "Enlarge the image before OCR; helps with small print but takes longer.",
+    lbl_preprocess_downscale: "Downscale ½",
+    hint_preprocess_downscale: "Shrink the image to half size before OCR; much faster on very large photos.",
+    lbl_show_processed: "Show preprocessed",
+    hint_show_processed: "Preview the image as it is sent to EasyOCR",
+    lbl_rotation_info: "Rotation angles:",
+    hint_rotation_info: "e.g. 90,180,270",
+    lbl_allowlist: "Allowed characters:",
+    hint_allowlist_example: "e.g. 0123456789",
+    hint_allowlist: "Only these characters can be recognised — every character counts, including spaces and commas. Leave empty for no restriction.",
+    lbl_blocklist: "Blocked characters:",
+    hint_blocklist: "These characters are never recognised. Ignored when allowed characters are set.",
+    hint_json_output: "More robust parsing; requires an EasyOCR version with --output_format.",
+    lbl_persistent_server: "Keep model loaded between runs",
+    hint_persistent_server: "Runs one background Python process that loads the model once, so repeated runs take a fraction of the time. Extra arguments and detect-only runs still use the CLI. Images are read one at a time.",
+    lbl_quiet: "Quiet EasyOCR output",
+    hint_quiet: "Passes --verbose False. Hides the progress bars EasyOCR prints, so first-run model downloads show no percentage.",
+    lbl_text_threshold: "Text confidence:",
+    hint_text_threshold: "Minimum confidence to accept a text region.",
+    lbl_low_text: "Low-text score:",
+    hint_low_text: "Lower bound for text score.",
+    lbl_link_threshold: "Link threshold:",
+    hint_link_threshold: "Threshold for linking text regions.",
+    lbl_contrast_ths: "Contrast threshold:",
+    hint_contrast_ths: "Boxes below this contrast are processed twice.",
+    lbl_adjust_contrast: "Adjust contrast to:",
+    hint_adjust_contrast: "Target for low-contrast boxes.",
+    hint_reset_default: "Reset to EasyOCR's default ({})",
+    lbl_model_dir: "Model storage directory:",
+    hint_model_dir: "Default: ~/.EasyOCR/model",
+    lbl_model_mirror: "Model download mirror:",
+    hint_model_mirror_field: "Default: GitHub releases",
+    hint_model_mirror: "Downloads models from this address instead of https://github.com/JaidedAI/EasyOCR/releases/download, for networks where GitHub is slow or blocked. The mirror must keep the same <release>/<file>.zip paths.",
+    lbl_model_usage: "Models are in {} — {} on disk.",
+    lbl_model_missing: "Models will be downloaded to {} on the first run (about 100 MB per language model).",
+    btn_show_model_folder: "Open folder",
+    lbl_captures_dir: "Keep captures in:",
+    hint_captures_dir: "Empty: pasted/captured images are not kept",
+    lbl_capture_format: "Capture format:",
+    hint_capture_format: "JPEG is much faster to write for large screenshots; PNG keeps every pixel exact.",
+    lbl_jpeg_quality: "Quality:",
+    lbl_easyocr_exe: "EasyOCR executable path:",
+    hint_easyocr_exe: "Default: 'easyocr' (from PATH)",
+    lbl_extra_args: "Extra arguments:",
+    hint_extra_args_example: "e.g. --width_ths 0.7",
+    hint_extra_args: "Passed to easyocr after the options above, split like a shell command line. Repeating an option set above may conflict with it; usually the last value wins.",
+    btn_browse: "Browse…",
+    btn_save_settings: "💾  Save Settings",
+    btn_reset: "↺  Reset to Defaults",
+    btn_copy_command: "Copy command",
+    hint_copy_command: "Copy the easyocr command line these settings produce, to reproduce a run in a terminal.",
+    msg_command_copied: "Command copied to clipboard.",
+    msg_settings_reset: "Settings reset to defaults.",
+    btn_undo_reset: "Undo",
+    msg_settings_saved: "Settings saved successfully.",
+    msg_settings_failed: "Failed to save: {}",
+    btn_export_settings: "Export settings…",
+    btn_import_settings: "Import settings…",
+    msg_settings_exported: "Settings exported to {}",
+    msg_settings_imported: "Settings imported from {}. Click Save to keep them.",
+    msg_settings_import_failed: "Failed to import: {}",
+    lbl_ui_language: "Interface language:",
+    lbl_theme: "Theme:",
+    theme_dark: "Dark",
+    theme_light: "Light",
+    theme_system: "Follow system",
+    lbl_history_limit: "History entries to keep:",
+    lbl_confidence_decimals: "Confidence decimals:",
+    lbl_script_tags: "Tag lines with their script",
+    hint_script_tags: "Shows whether each result line is mostly Latin, CJK, digits or another alphabet, guessed from its characters. JSON exports always include the tag.",
+    script_latin: "Latin",
+    script_cjk: "CJK",
+    script_digits: "Digits",
+    script_other: "Other",
+    btn_apply_preset: "☰ Apply Preset",
+    btn_save_preset: "Save as Preset",
+    btn_delete: "Delete",
+    hint_preset_name: "Preset name",
+    btn_add_language: "➕ Add language",
+    btn_add_shortcut: "➕ Shortcut",
+    hint_shortcut_name: "Label, e.g. EN",
+    btn_save_shortcut: "Save current languages",
+    hint_language_shortcut: "Click to use {}; right-click to delete",
+    lbl_auto_run: "Auto-run",
+    lbl_error_details: "Details",
+    err_gpu_oom: "The GPU ran out of memory. Try disabling GPU in Settings or lowering the batch size.",
+    err_gpu_unavailable: "No usable CUDA GPU was found. Disable GPU in Settings to run on the CPU.",
+    err_model_download: "Downloading the EasyOCR models failed. Check your network connection (or proxy) and try again.",
+    err_unsupported_language: "EasyOCR rejected the language list. Check the language codes in Settings.",
+    err_missing_module: "A Python package EasyOCR needs is missing. Reinstall it with: pip install easyocr",
+    lbl_from_clipboard: "from clipboard",
+    lbl_from_screen: "from screen {}",
+    lbl_from_camera: "from camera",
+    lbl_camera_starting: "Starting the camera…",
+    btn_capture_frame: "Capture",
+    btn_close_camera: "Close camera",
+    warn_unparsed_lines: "⚠ Got output but could not parse {} line(s).",
+    lbl_raw_output: "Show unparsed output",
+    lbl_cli_output: "EasyOCR output",
+    hint_cli_output: "Exactly what the easyocr command printed for the last run, to check the results against or attach to a bug report.",
+    hint_auto_run: "Run OCR as soon as an image is opened, pasted, dropped or captured",
+    lbl_auto_copy: "Auto-copy",
+    hint_auto_copy: "Copy the results to the clipboard as soon as OCR finishes, in the format picked next to Copy.",
+    lbl_global_hotkey: "Global hotkey:",
+    hint_global_hotkey: "Works while the window is in the background: pick a screen region, run OCR and copy the text to the clipboard.",
+    msg_hotkey_failed: "Hotkey unavailable: {}",
+    section_about: "About",
+    lbl_version_gui: "EasyOCR GUI:",
+    lbl_version_python: "Python:",
+    lbl_version_easyocr: "EasyOCR:",
+    lbl_version_checking: "checking…",
+    lbl_version_unknown: "not found",
+    btn_copy_versions: "Copy versions",
+    msg_versions_copied: "Versions copied to clipboard.",
+    status_hotkey_copied: "Text copied to the clipboard.",
+    hint_search_language: "Search by name or code",
+    lbl_advanced_codes: "Advanced: edit codes directly",
+    lang_unknown: "Unknown language code(s): {} — use codes such as en, ch_sim, ja.",
+    lang_incompatible: "'{}' cannot be combined with '{}': EasyOCR only pairs that model with English.",
+
+    preset_default: "Default",
+    preset_dense_document: "Dense document",
+    preset_scene_text: "Scene text",
+    preset_handwriting: "Handwriting-ish",
+
+    decoder_greedy: "Greedy (Fast)",
+    decoder_beam: "Beam Search (Accurate)",
+    decoder_word_beam: "Word Beam Search (Most Accurate)",
+};
@@ -0,0 +1,329 @@
+use super::Strings;
+
+pub static ES: Strings = Strings {
+    tab_ocr: "🔍  OCR",
+    tab_history: "🕘  Historial",
+    tab_settings: "⚙  Ajustes",
+
+    btn_open_image: "📂 Abrir imagen",
+    btn_paste_image: "📋 Pegar imagen",
+    btn_open_folder: "🗁 Abrir carpeta",
+    hint_open_recent: "Abrir reciente",
+    btn_screenshot: "📷 Captura",
+    btn_screenshot_region: "✂ Región",
+    btn_from_url: "🌐 Desde URL",
+    btn_camera: "📷 Cámara",
+    hint_region_select: "Arrastra para elegir la región a reconocer — Esc para cancelar",
+    lbl_screen: "Pantalla",
+    lbl_primary: "principal",
+    btn_setup: "⚠ Instalación",
+    btn_run_ocr: "▶  Ejecutar OCR",
+    btn_copy: "⎘ Copiar",
+    btn_copied: "✔ ¡Copiado!",
+    hint_copy_format: "Formato de copia",
+    copy_text_only: "Copiar solo texto",
+    copy_with_confidence: "Copiar con confianza",
+    copy_tsv: "Copiar como TSV (texto ⇥ confianza)",
+    btn_cancel: "✖ Cancelar",
+    btn_export: "💾 Exportar",
+    export_txt: "Texto plano (.txt)",
+    export_csv: "CSV (.csv)",
+    export_json: "JSON (.json)",
+    export_hocr: "hOCR (.hocr)",
+    export_layout: "Texto con posiciones (.txt)",
+    tooltip_setup: "EasyOCR no está instalado — haz clic para ver las instrucciones",
+
+    lbl_results: "Resultados",
+    lbl_plain_text: "Texto plano",
+    lbl_page: "Página {}/{}",
+    lbl_frame: "Fotograma {}/{}",
+    lbl_min_confidence: "Confianza mínima:",
+    lbl_confidence_filtered: "Mostrando {} de {} ({} por debajo del umbral)",
+    lbl_result_stats: "{} líneas · {} palabras · {} caracteres",
+    lbl_result_confidence: " · media {} · mínima {}",
+    lbl_reading_time: " · ~{} min de lectura",
+    lbl_compare: "Comparar",
+    hint_compare: "Reconoce la imagen con los ajustes actuales y con un perfil aplicado, y muestra ambos resultados lado a lado",
+    hint_compare_preset: "Perfil aplicado a los ajustes actuales para la segunda columna",
+    lbl_compare_current: "Ajustes actuales",
+    placeholder_drop_image: "Suelta una imagen aquí\no usa los botones de arriba",
+    onboarding_title: "Primeros pasos",
+    onboarding_drop: "Suelta aquí una imagen o un PDF, o usa Abrir imagen",
+    onboarding_paste: "Pega una imagen del portapapeles con {}",
+    onboarding_screenshot: "Captura la pantalla o una región con Captura",
+    onboarding_settings: "Elige idiomas y ajusta el reconocimiento en la pestaña Ajustes",
+    btn_onboarding_dismiss: "Entendido",
+
+    status_load_image: "Carga una imagen para empezar.",
+    status_image_loaded: "{} cargada. Pulsa «Ejecutar OCR» para reconocer el texto.",
+    status_image_reloaded: "{} recargado desde el disco.",
+    status_opened_in_editor: "Se abrió {} en la aplicación de imágenes predeterminada. Guárdalo allí y vuelve a cargarlo aquí.",
+    status_cant_open_editor: "No se pudo abrir la imagen en otra aplicación: {}",
+    url_title: "Abrir imagen desde URL",
+    btn_download: "Descargar",
+    status_downloading: "Descargando {}…",
+    status_reading_pages: "Leyendo las páginas de {}…",
+    status_reading_documents: "Leyendo las páginas de los documentos…",
+    status_url_failed: "No se pudo cargar la imagen desde la URL: {}",
+    path_title: "Introducir una ruta",
+    btn_use_path: "Aceptar",
+    btn_type_path: "Escribir una ruta…",
+    hint_type_path: "Por si el diálogo de archivos del sistema no se abre, p. ej. sin portal de escritorio.",
+    hint_reload_image: "Recargar la imagen desde el disco",
+    hint_open_in_editor: "Abrir la imagen en el editor de imágenes predeterminado",
+    hint_select_region: "Mayús+arrastrar sobre la imagen selecciona una región; el clic derecho la borra.",
+    lbl_region_lines: "{} de {} líneas en la selección",
+    lbl_lines_left_out: "{} línea(s) fuera de Copiar y Exportar",
+    hint_result_keys: "↑/↓ elige una línea, Intro la copia y Espacio la saca de Copiar y Exportar o la vuelve a incluir.",
+    hint_region_lines: "Copiar y Exportar usan solo las líneas de la selección.",
+    hint_clear_selection: "Borrar la selección",
+    status_document_loaded: "{} cargado — {} páginas. Pulsa «Ejecutar OCR» para reconocerlas todas.",
+    status_animation_loaded: "{} cargado — {} fotogramas. Elige uno y pulsa «Ejecutar OCR».",
+    status_running_ocr: "Ejecutando OCR…",
+    status_ocr_complete: "OCR terminado — {} región(es) de texto detectada(s).",
+    status_no_text_stderr: "No se detectó texto. EasyOCR dijo: {}",
+    status_compare_complete: "Comparación terminada — {} frente a {} región(es) de texto.",
+    status_done_in: "Terminado en {} s.",
+    status_easyocr_missing: "⚠ No se encontró EasyOCR — pulsa el botón Instalación para ver las instrucciones.",
+    status_no_image_clipboard: "No hay ninguna imagen en el portapapeles. Copia una imagen primero.",
+    status_clipboard_unavailable: "Portapapeles no disponible: {}",
+    status_clipboard_unchanged: "El portapapeles sigue conteniendo la imagen que ya se muestra.",
+    status_large_image: "Imagen grande ({} MP): el OCR en CPU puede tardar varios minutos.",
+    btn_downscale: "Reducir",
+    status_no_screens: "No se encontraron pantallas.",
+    status_screenshot_failed: "Falló la captura de pantalla: {}",
+    status_camera_failed: "Cámara no disponible: {}",
+    status_cant_enum_screens: "No se pueden enumerar las pantallas: {}",
+    status_failed_load_image: "No se pudo cargar la imagen: {}",
+    status_cant_save_tmp: "No se pudo guardar la imagen temporal: {}",
+    status_ocr_failed: "Falló el OCR: {}",
+    status_cancelled: "Cancelado.",
+    status_no_images_in_folder: "No hay imágenes en la carpeta seleccionada.",
+    status_no_images_dropped: "Ninguno de los archivos soltados es una imagen compatible.",
+    status_busy: "Ya hay un OCR en curso — cancélalo o espera a que termine.",
+    status_lines_so_far: "— {} líneas hasta ahora",
+    status_batch_progress: "OCR por lotes {}/{}…",
+    status_batch_complete: "Lote terminado — {} imagen(es), {} con error.",
+    lbl_queue: "Cola — {}/{} hechas",
+    lbl_queue_lines: "{} líneas",
+    lbl_queue_failed: "error",
+    hint_queue_drag: "Arrastra para cambiar el orden en que se procesan las imágenes.",
+    hint_queue_remove: "Quitar de la cola",
+    status_exported: "Exportado a {}",
+    status_export_failed: "Falló la exportación: {}",
+    status_history_image_missing: "La imagen ya no existe: {} — se muestran solo los resultados guardados.",
+    status_history_save_failed: "No se pudo guardar el historial: {}",
+    status_recent_missing: "El archivo se movió o se eliminó: {}",
+    status_image_gone: "El archivo de imagen ya no existe: {}. Vuelve a cargarlo.",
+
+    lbl_history_count: "{} ejecución(es) anteriores",
+    lbl_history_lines: "{} línea(s)",
+    btn_clear_history: "🗑 Borrar historial",
+    placeholder_history: "Todavía no hay ejecuciones de OCR.",
+
+    setup_title: "⚙  Instalación de EasyOCR",
+    setup_not_found: "No se encontró el comando easyocr en el sistema.",
+    setup_module_missing: "Se encontró Python en '{}', pero no tiene instalado el módulo easyocr.",
+    setup_must_install: "Hay que instalar EasyOCR antes de que esta aplicación pueda reconocer texto.",
+    setup_step1_title: "Paso 1 — Instala Python 3.8 o posterior",
+    setup_step1_desc: "Descarga e instala Python desde:",
+    setup_step2_title: "Paso 2 — Instala EasyOCR",
+    setup_step2_desc: "Abre una terminal y ejecuta:",
+    setup_step2_desc_interpreter: "Instálalo para ese intérprete ejecutando:",
+    setup_step3_title: "Paso 3 — Modelos de idioma",
+    setup_step3_desc: "Los modelos se descargan automáticamente la primera vez que ejecutas OCR \
+                       para un idioma.\nLa descarga inicial puede tardar unos minutos según tu \
+                       conexión a internet.",
+    setup_tip: "Consejo: también puedes indicar un ejecutable de EasyOCR propio en la pestaña Ajustes.",
+    setup_btn_check: "✓  Comprobar de nuevo",
+    setup_btn_continue: "Continuar de todos modos",
+    setup_checking: "Comprobando…",
+
+    section_presets: "Perfiles",
+    section_languages: "Idiomas",
+    section_hardware: "Hardware",
+    section_decoder: "Decodificador",
+    section_recognition: "Reconocimiento",
+    section_thresholds: "Umbrales de detección",
+    section_preprocessing: "Preprocesado",
+    section_paths: "Rutas (opcional)",
+    section_ui: "Interfaz",
+
+    lbl_language_codes: "Códigos de idioma:",
+    hint_language_codes: "p. ej. en,es,fr",
+    lbl_comma_separated: "(separados por comas)",
+    lbl_gpu: "Activar aceleración por GPU",
+    hint_cuda_detected: "CUDA detectado",
+    hint_cuda_missing: "Sin CUDA — GPU no disponible",
+    hint_cuda_checking: "Comprobando CUDA…",
+    lbl_gpu_device: "Dispositivo GPU:",
+    hint_gpu_device_placeholder: "predeterminado",
+    hint_gpu_device: "Índice del dispositivo CUDA, p. ej. 1 o cuda:1. Se pasa como CUDA_VISIBLE_DEVICES; déjelo vacío para la tarjeta predeterminada.",
+    lbl_workers: "Procesos de CPU en paralelo:",
+    hint_workers_auto: "(0 = automático: uno por núcleo, {} aquí)",
+    lbl_batch_concurrency: "Imágenes en paralelo:",
+    hint_batch_concurrency: "Imágenes de un lote procesadas a la vez. Cada una ejecuta su propio proceso de EasyOCR con su propia copia de los modelos (a menudo 1 GB de memoria o más), así que auméntalo con cuidado.",
+    lbl_batch_serialized: "Sin efecto mientras el modelo se mantiene cargado: su único proceso lee las imágenes de una en una.",
+    lbl_workers_suffix: " procesos",
+    lbl_timeout: "Tiempo límite del OCR:",
+    hint_timeout: "(0 = sin límite)",
+    lbl_seconds_suffix: " s",
+    lbl_quantize: "Usar cuantización dinámica (reduce la memoria)",
+    lbl_beam_width: "Ancho del haz:",
+    lbl_batch_size: "Tamaño de lote:",
+    lbl_min_size: "Tamaño mínimo de caja (px):",
+    lbl_canvas_size: "Tamaño del lienzo (px):",
+    hint_canvas_size: "Las imágenes cuyo lado mayor lo supere se reducen antes de la detección.",
+    lbl_mag_ratio: "Ampliación:",
+    hint_mag_ratio: "Amplía la imagen antes de la detección; ayuda con textos pequeños.",
+    lbl_paragraph: "Agrupar resultados en párrafos",
+    lbl_join_paragraph_lines: "Unir líneas dentro de párrafos",
+    hint_join_paragraph_lines: "Al copiar, los saltos de línea dentro de un párrafo se convierten en espacios y los párrafos se separan con una línea en blanco.",
+    lbl_x_ths: "Separación  x:",
+    hint_x_ths: "El modo párrafo une cajas separadas horizontalmente menos de estas alturas de caja (--x_ths, por defecto 1.0).",
+    lbl_y_ths: "y:",
+    hint_y_ths: "El modo párrafo une cajas separadas verticalmente menos de estas alturas de caja (--y_ths, por defecto 0.5).",
+    lbl_margin: "Margen de las cajas:",
+    lbl_json_output: "Usar salida JSON",
+    lbl_detail: "Incluir posiciones y confianza",
+    hint_detail: "Desactivado = solo texto (--detail 0): más rápido, sin cajas ni confianza.",
+    lbl_detect_only: "Solo detectar (cajas, sin texto)",
+    hint_detect_only: "Busca regiones de texto sin reconocerlas, lo que es más rápido. Usa directamente la API de Python de EasyOCR, así que se ignoran los argumentos extra.",
+    lbl_sort_by_position: "Ordenar por posición",
+    hint_sort_by_position: "Reordena las líneas de arriba abajo y de izquierda a derecha para que el texto copiado se lea en orden.",
+    lbl_vertical_text: "Texto vertical",
+    hint_vertical_text: "Para texto escrito en columnas verticales, como el japonés o chino tradicional. Prueba cada cuadro girado 90° y 270° y lee las columnas de derecha a izquierda y de arriba abajo. El orden es aproximado.",
+    hint_row_tolerance: "Tolerancia de fila: las cajas cuyo borde superior difiere menos de esta fracción de la altura de línea comparten fila.",
+    lbl_preprocess_grayscale: "Convertir a escala de grises",
+    lbl_preprocess_contrast: "Estirar el contraste",
+    hint_preprocess_contrast: "Extiende el rango de brillo de negro a blanco; ayuda con escaneos tenues.",
+    lbl_preprocess_upscale: "Ampliar 2×",
+    hint_preprocess_upscale: "Amplía la imagen antes del OCR; ayuda con letra pequeña pero tarda más.",
+    lbl_preprocess_downscale: "Reducir a ½",
+    hint_preprocess_downscale: "Reduce la imagen a la mitad antes del OCR; mucho más rápido con fotos muy grandes.",
+    lbl_show_processed: "Ver preprocesada",
+    hint_show_processed: "Vista previa de la imagen tal como se envía a EasyOCR",
+    lbl_rotation_info: "Ángulos de rotación:",
+    hint_rotation_info: "p. ej. 90,180,270",
+    lbl_allowlist: "Caracteres permitidos:",
+    hint_allowlist_example: "p. ej. 0123456789",
+    hint_allowlist: "Solo se reconocen estos caracteres; cuenta cada uno, incluidos espacios y comas. Déjalo vacío para no restringir.",
+    lbl_blocklist: "Caracteres bloqueados:",
+    hint_blocklist: "Estos caracteres nunca se reconocen. Se ignora si hay caracteres permitidos.",
+    hint_json_output: "Análisis más fiable; requiere una versión de EasyOCR con --output_format.",
+    lbl_persistent_server: "Mantener el modelo cargado entre ejecuciones",
+    hint_persistent_server: "Ejecuta un proceso de Python en segundo plano que carga el modelo una sola vez, así las ejecuciones repetidas tardan mucho menos. Los argumentos adicionales y las ejecuciones de solo detección siguen usando la CLI. Las imágenes se leen de una en una.",
+    lbl_quiet: "Salida de EasyOCR silenciosa",
+    hint_quiet: "Pasa --verbose False. Oculta las barras de progreso de EasyOCR, así que la descarga de modelos en la primera ejecución no muestra porcentaje.",
+    lbl_text_threshold: "Confianza del texto:",
+    hint_text_threshold: "Confianza mínima para aceptar una región de texto.",
+    lbl_low_text: "Puntuación mínima de texto:",
+    hint_low_text: "Límite inferior de la puntuación de texto.",
+    lbl_link_threshold: "Umbral de enlace:",
+    hint_link_threshold: "Umbral para unir regiones de texto.",
+    lbl_contrast_ths: "Umbral de contraste:",
+    hint_contrast_ths: "Las cajas con menos contraste se procesan dos veces.",
+    lbl_adjust_contrast: "Ajustar contraste a:",
+    hint_adjust_contrast: "Objetivo para las cajas de poco contraste.",
+    hint_reset_default: "Restablecer al valor predeterminado de EasyOCR ({})",
+    lbl_model_dir: "Carpeta de modelos:",
+    hint_model_dir: "Predeterminada: ~/.EasyOCR/model",
+    lbl_model_mirror: "Réplica de descarga de modelos:",
+    hint_model_mirror_field: "Predeterminada: GitHub Releases",
+    hint_model_mirror: "Descarga los modelos desde esta dirección en lugar de https://github.com/JaidedAI/EasyOCR/releases/download, para redes donde GitHub es lento o está bloqueado. La réplica debe mantener las mismas rutas <versión>/<archivo>.zip.",
+    lbl_model_usage: "Los modelos están en {}: {} en disco.",
+    lbl_model_missing: "Los modelos se descargarán en {} en la primera ejecución (unos 100 MB por modelo de idioma).",
+    btn_show_model_folder: "Abrir carpeta",
+    lbl_captures_dir: "Guardar capturas en:",
+    hint_captures_dir: "Vacío: no se guardan las imágenes pegadas o capturadas",
+    lbl_capture_format: "Formato de captura:",
+    hint_capture_format: "JPEG se guarda mucho más rápido en capturas grandes; PNG conserva cada píxel exacto.",
+    lbl_jpeg_quality: "Calidad:",
+    lbl_easyocr_exe: "Ruta del ejecutable de EasyOCR:",
+    hint_easyocr_exe: "Predeterminado: 'easyocr' (del PATH)",
+    lbl_extra_args: "Argumentos adicionales:",
+    hint_extra_args_example: "p. ej. --width_ths 0.7",
+    hint_extra_args: "Se pasan a easyocr después de las opciones anteriores, separados como en una línea de comandos. Repetir una opción ya configurada puede causar conflictos; normalmente gana el último valor.",
+    btn_browse: "Examinar…",
+    btn_save_settings: "💾  Guardar ajustes",
+    btn_reset: "↺  Restablecer",
+    btn_copy_command: "Copiar comando",
+    hint_copy_command: "Copia la línea de comandos de easyocr que generan estos ajustes, para reproducir una ejecución en una terminal.",
+    msg_command_copied: "Comando copiado al portapapeles.",
+    msg_settings_reset: "Ajustes restablecidos a los valores predeterminados.",
+    btn_undo_reset: "Deshacer",
+    msg_settings_saved: "Ajustes guardados.",
+    msg_settings_failed: "No se pudo guardar: {}",
+    btn_export_settings: "Exportar ajustes…",
+    btn_import_settings: "Importar ajustes…",
+    msg_settings_exported: "Ajustes exportados a {}",
+    msg_settings_imported: "Ajustes importados de {}. Pulsa Guardar para conservarlos.",
+    msg_settings_import_failed: "No se pudo importar: {}",
+    lbl_ui_language: "Idioma de la interfaz:",
+    lbl_theme: "Tema:",
+    theme_dark: "Oscuro",
+    theme_light: "Claro",
+    theme_system: "Seguir al sistema",
+    lbl_history_limit: "Entradas de historial a conservar:",
+    lbl_confidence_decimals: "Decimales de confianza:",
+    lbl_script_tags: "Etiquetar líneas con su escritura",
+    hint_script_tags: "Muestra si cada línea es mayormente latina, CJK, dígitos u otro alfabeto, según sus caracteres. Las exportaciones JSON siempre incluyen la etiqueta.",
+    script_latin: "Latino",
+    script_cjk: "CJK",
+    script_digits: "Dígitos",
+    script_other: "Otro",
+    btn_apply_preset: "☰ Aplicar perfil",
+    btn_save_preset: "Guardar como perfil",
+    btn_delete: "Eliminar",
+    hint_preset_name: "Nombre del perfil",
+    btn_add_language: "➕ Añadir idioma",
+    btn_add_shortcut: "➕ Atajo",
+    hint_shortcut_name: "Etiqueta, p. ej. EN",
+    btn_save_shortcut: "Guardar idiomas actuales",
+    hint_language_shortcut: "Clic para usar {}; clic derecho para eliminar",
+    lbl_auto_run: "Ejecución automática",
+    lbl_error_details: "Detalles",
+    err_gpu_oom: "La GPU se quedó sin memoria. Desactiva la GPU en Ajustes o reduce el tamaño de lote.",
+    err_gpu_unavailable: "No se encontró ninguna GPU CUDA utilizable. Desactiva la GPU en Ajustes para usar la CPU.",
+    err_model_download: "Falló la descarga de los modelos de EasyOCR. Revisa tu conexión (o proxy) e inténtalo de nuevo.",
+    err_unsupported_language: "EasyOCR rechazó la lista de idiomas. Revisa los códigos de idioma en Ajustes.",
+    err_missing_module: "Falta un paquete de Python que EasyOCR necesita. Reinstálalo con: pip install easyocr",
+    lbl_from_clipboard: "del portapapeles",
+    lbl_from_screen: "de la pantalla {}",
+    lbl_from_camera: "desde la cámara",
+    lbl_camera_starting: "Iniciando la cámara…",
+    btn_capture_frame: "Capturar",
+    btn_close_camera: "Cerrar cámara",
+    warn_unparsed_lines: "⚠ Hubo salida, pero no se pudieron interpretar {} línea(s).",
+    lbl_raw_output: "Mostrar salida sin interpretar",
+    lbl_cli_output: "Salida de EasyOCR",
+    hint_cli_output: "Exactamente lo que imprimió el comando easyocr en la última ejecución, para contrastar los resultados o adjuntarlo a un informe de error.",
+    hint_auto_run: "Ejecutar el OCR en cuanto se abra, pegue, suelte o capture una imagen",
+    lbl_auto_copy: "Copiar automáticamente",
+    hint_auto_copy: "Copia los resultados al portapapeles en cuanto termina el OCR, en el formato elegido junto a Copiar.",
+    lbl_global_hotkey: "Atajo global:",
+    hint_global_hotkey: "Funciona con la ventana en segundo plano: elige una región de la pantalla, ejecuta el OCR y copia el texto al portapapeles.",
+    msg_hotkey_failed: "Atajo no disponible: {}",
+    section_about: "Acerca de",
+    lbl_version_gui: "EasyOCR GUI:",
+    lbl_version_python: "Python:",
+    lbl_version_easyocr: "EasyOCR:",
+    lbl_version_checking: "comprobando…",
+    lbl_version_unknown: "no encontrado",
+    btn_copy_versions: "Copiar versiones",
+    msg_versions_copied: "Versiones copiadas al portapapeles.",
+    status_hotkey_copied: "Texto copiado al portapapeles.",
+    hint_search_language: "Buscar por nombre o código",
+    lbl_advanced_codes: "Avanzado: editar los códigos directamente",
+    lang_unknown: "Código(s) de idioma desconocido(s): {} — usa códigos como en, es, ch_sim.",
+    lang_incompatible: "'{}' no se puede combinar con '{}': EasyOCR solo empareja ese modelo con el inglés.",
+
+    preset_default: "Predeterminado",
+    preset_dense_document: "Documento denso",
+    preset_scene_text: "Texto en escenas",
+    preset_handwriting: "Casi manuscrito",
+
+    decoder_greedy: "Greedy (rápido)",
+    decoder_beam: "Beam Search (preciso)",
+    decoder_word_beam: "Word Beam Search (el más preciso)",
+};
@@ -0,0 +1,328 @@
+use super::Strings;
+
+pub static JA: Strings = Strings {
+    tab_ocr: "🔍  OCR",
+    tab_history: "🕘  履歴",
+    tab_settings: "⚙  設定",
+
+    btn_open_image: "📂 画像を開く",
+    btn_paste_image: "📋 画像を貼り付け",
+    btn_open_folder: "🗁 フォルダーを開く",
+    hint_open_recent: "最近使ったファイルを開く",
+    btn_screenshot: "📷 スクリーンショット",
+    btn_screenshot_region: "✂ 範囲指定",
+    btn_from_url: "🌐 URL から",
+    btn_camera: "📷 カメラ",
+    hint_region_select: "ドラッグで認識する範囲を選択 — Esc でキャンセル",
+    lbl_screen: "画面",
+    lbl_primary: "メイン",
+    btn_setup: "⚠ セットアップ",
+    btn_run_ocr: "▶  OCR 実行",
+    btn_copy: "⎘ コピー",
+    btn_copied: "✔ コピーしました",
+    hint_copy_format: "コピー形式",
+    copy_text_only: "テキストのみコピー",
+    copy_with_confidence: "信頼度付きでコピー",
+    copy_tsv: "TSV としてコピー（テキスト ⇥ 信頼度）",
+    btn_cancel: "✖ キャンセル",
+    btn_export: "💾 エクスポート",
+    export_txt: "テキスト (.txt)",
+    export_csv: "CSV (.csv)",
+    export_json: "JSON (.json)",
+    export_hocr: "hOCR (.hocr)",
+    export_layout: "配置を保ったテキスト (.txt)",
+    tooltip_setup: "EasyOCR がインストールされていません — クリックで手順を表示",
+
+    lbl_results: "認識結果",
+    lbl_plain_text: "テキスト",
+    lbl_page: "{}/{} ページ",
+    lbl_frame: "{}/{} フレーム",
+    lbl_min_confidence: "最低信頼度:",
+    lbl_confidence_filtered: "{} / {} 件を表示（{} 件がしきい値未満）",
+    lbl_result_stats: "{} 行 · {} 語 · {} 文字",
+    lbl_result_confidence: " · 平均 {} · 最低 {}",
+    lbl_reading_time: " · 約 {} 分で読めます",
+    lbl_compare: "比較",
+    hint_compare: "現在の設定とプリセットを適用した設定の両方で画像を認識し、結果を並べて表示します",
+    hint_compare_preset: "2 列目の設定: 現在の設定にこのプリセットを適用します",
+    lbl_compare_current: "現在の設定",
+    placeholder_drop_image: "ここに画像をドロップ\nまたは上のボタンを使用",
+    onboarding_title: "はじめに",
+    onboarding_drop: "画像や PDF をここにドロップするか、「画像を開く」を使用",
+    onboarding_paste: "{} でクリップボードから画像を貼り付け",
+    onboarding_screenshot: "「スクリーンショット」で画面全体または範囲をキャプチャ",
+    onboarding_settings: "「設定」タブで言語を選び認識を調整",
+    btn_onboarding_dismiss: "了解",
+
+    status_load_image: "画像を読み込むと OCR を開始できます。",
+    status_image_loaded: "{} を読み込みました。「OCR 実行」で文字を認識します。",
+    status_image_reloaded: "{} をディスクから再読み込みしました。",
+    status_opened_in_editor: "{} を既定の画像アプリで開きました。保存したら、ここで再読み込みしてください。",
+    status_cant_open_editor: "画像を他のアプリで開けませんでした: {}",
+    url_title: "URL から画像を開く",
+    btn_download: "ダウンロード",
+    status_downloading: "{} をダウンロード中…",
+    status_reading_pages: "{} のページを読み込み中…",
+    status_reading_documents: "文書のページを読み込み中…",
+    status_url_failed: "URL から画像を読み込めませんでした: {}",
+    path_title: "パスを入力",
+    btn_use_path: "OK",
+    btn_type_path: "パスを入力する…",
+    hint_type_path: "システムのファイルダイアログが開かない場合（デスクトップポータルがないなど）に使います。",
+    hint_reload_image: "ディスクから画像を再読み込み",
+    hint_open_in_editor: "既定の画像エディタで画像を開く",
+    hint_select_region: "画像上で Shift を押しながらドラッグすると範囲を選択できます。右クリックで解除します。",
+    lbl_region_lines: "選択範囲内の行: {} / {}",
+    lbl_lines_left_out: "{} 行をコピーとエクスポートから除外",
+    hint_result_keys: "↑/↓ で行を選び、Enter でコピー、Space でコピーとエクスポートから除外または復帰します。",
+    hint_region_lines: "コピーとエクスポートには選択範囲内の行だけが使われます。",
+    hint_clear_selection: "選択を解除",
+    status_document_loaded: "{} を読み込みました（{} ページ）。「OCR 実行」ですべてのページを認識します。",
+    status_animation_loaded: "{} を読み込みました（{} フレーム）。フレームを選んで「OCR 実行」を押してください。",
+    status_running_ocr: "OCR 実行中…",
+    status_ocr_complete: "OCR 完了 — {} 個のテキスト領域を検出しました。",
+    status_no_text_stderr: "テキストは検出されませんでした。EasyOCR の出力: {}",
+    status_compare_complete: "比較完了 — {} 対 {} 個のテキスト領域。",
+    status_done_in: "{} 秒で完了。",
+    status_easyocr_missing: "⚠ EasyOCR が見つかりません — セットアップボタンから手順を確認してください。",
+    status_no_image_clipboard: "クリップボードに画像がありません。先に画像をコピーしてください。",
+    status_clipboard_unavailable: "クリップボードを利用できません: {}",
+    status_clipboard_unchanged: "クリップボードの画像は表示中のものと同じです。",
+    status_large_image: "大きな画像です（{} MP）：CPU での OCR には数分かかる場合があります。",
+    btn_downscale: "縮小",
+    status_no_screens: "画面が見つかりません。",
+    status_screenshot_failed: "スクリーンショットに失敗しました: {}",
+    status_camera_failed: "カメラを使用できません: {}",
+    status_cant_enum_screens: "画面を列挙できません: {}",
+    status_failed_load_image: "画像の読み込みに失敗しました: {}",
+    status_cant_save_tmp: "一時画像を保存できません: {}",
+    status_ocr_failed: "OCR に失敗しました: {}",
+    status_cancelled: "キャンセルしました。",
+    status_no_images_in_folder: "選択したフォルダーに画像がありません。",
+    status_no_images_dropped: "ドロップされたファイルに対応する画像がありません。",
+    status_busy: "OCR を実行中です — キャンセルするか完了までお待ちください。",
+    status_lines_so_far: "— これまでに {} 行",
+    status_batch_progress: "一括 OCR {}/{}…",
+    status_batch_complete: "一括処理完了 — {} 枚、失敗 {} 枚。",
+    lbl_queue: "キュー — {}/{} 完了",
+    lbl_queue_lines: "{} 行",
+    lbl_queue_failed: "失敗",
+    hint_queue_drag: "ドラッグして画像を処理する順序を変更します。",
+    hint_queue_remove: "キューから削除",
+    status_exported: "{} にエクスポートしました",
+    status_export_failed: "エクスポートに失敗しました: {}",
+    status_history_image_missing: "画像が存在しません: {} — 保存済みの結果のみ表示します。",
+    status_history_save_failed: "履歴を保存できませんでした: {}",
+    status_recent_missing: "ファイルが移動または削除されています: {}",
+    status_image_gone: "画像ファイルが見つかりません: {} — もう一度読み込んでください。",
+
+    lbl_history_count: "{} 件の実行履歴",
+    lbl_history_lines: "{} 行",
+    btn_clear_history: "🗑 履歴を消去",
+    placeholder_history: "まだ OCR を実行していません。",
+
+    setup_title: "⚙  EasyOCR セットアップ",
+    setup_not_found: "easyocr コマンドがシステムに見つかりません。",
+    setup_module_missing: "Python（'{}'）は見つかりましたが、easyocr モジュールがインストールされていません。",
+    setup_must_install: "文字を認識するには、先に EasyOCR をインストールする必要があります。",
+    setup_step1_title: "ステップ 1 — Python 3.8 以降をインストール",
+    setup_step1_desc: "次のサイトから Python をダウンロードしてインストールします:",
+    setup_step2_title: "ステップ 2 — EasyOCR をインストール",
+    setup_step2_desc: "ターミナルを開いて次を実行します:",
+    setup_step2_desc_interpreter: "そのインタープリター用に次を実行してインストールします:",
+    setup_step3_title: "ステップ 3 — 言語モデル",
+    setup_step3_desc: "モデルは各言語で初めて OCR を実行したときに自動でダウンロードされます。\n\
+                       初回のダウンロードは回線速度によって数分かかることがあります。",
+    setup_tip: "ヒント: 設定タブでカスタムの EasyOCR 実行ファイルを指定することもできます。",
+    setup_btn_check: "✓  再確認",
+    setup_btn_continue: "このまま続行",
+    setup_checking: "確認中…",
+
+    section_presets: "プリセット",
+    section_languages: "言語",
+    section_hardware: "ハードウェア",
+    section_decoder: "デコーダー",
+    section_recognition: "認識",
+    section_thresholds: "検出しきい値",
+    section_preprocessing: "前処理",
+    section_paths: "パス（任意）",
+    section_ui: "インターフェース",
+
+    lbl_language_codes: "言語コード:",
+    hint_language_codes: "例: en,ch_sim,ja",
+    lbl_comma_separated: "（カンマ区切り）",
+    lbl_gpu: "GPU アクセラレーションを有効にする",
+    hint_cuda_detected: "CUDA を検出しました",
+    hint_cuda_missing: "CUDA なし — GPU は使用できません",
+    hint_cuda_checking: "CUDA を確認中…",
+    lbl_gpu_device: "GPU デバイス：",
+    hint_gpu_device_placeholder: "既定",
+    hint_gpu_device: "CUDA デバイス番号（例: 1 または cuda:1）。CUDA_VISIBLE_DEVICES として渡されます。空欄なら既定のカードを使います。",
+    lbl_workers: "並列 CPU ワーカー数:",
+    hint_workers_auto: "（0 = 自動: CPU コアごとに 1 つ、この環境では {}）",
+    lbl_batch_concurrency: "並列画像数:",
+    hint_batch_concurrency: "一括処理で同時に OCR する画像の数です。画像ごとに EasyOCR のプロセスが起動し、モデルをそれぞれ読み込みます（多くの場合 1 GB 以上のメモリ）。慎重に増やしてください。",
+    lbl_batch_serialized: "モデルを読み込んだままにしている間は無効です。その 1 つのプロセスが画像を 1 枚ずつ処理します。",
+    lbl_workers_suffix: " ワーカー",
+    lbl_timeout: "OCR タイムアウト:",
+    hint_timeout: "（0 = タイムアウトなし）",
+    lbl_seconds_suffix: " 秒",
+    lbl_quantize: "動的量子化を使用する（メモリ削減）",
+    lbl_beam_width: "ビーム幅:",
+    lbl_batch_size: "バッチサイズ:",
+    lbl_min_size: "最小テキストボックス（px）:",
+    lbl_canvas_size: "キャンバスサイズ（px）:",
+    hint_canvas_size: "長辺がこの値を超える画像は検出前に縮小されます。",
+    lbl_mag_ratio: "拡大率:",
+    hint_mag_ratio: "検出前に画像を拡大します。小さな文字に有効です。",
+    lbl_paragraph: "結果を段落にまとめる",
+    lbl_join_paragraph_lines: "段落内の改行を結合",
+    hint_join_paragraph_lines: "コピー時に段落内の改行をスペースに置き換え、段落の間は空行で区切ります。",
+    lbl_x_ths: "結合間隔  x:",
+    hint_x_ths: "段落モードで、左右の間隔がボックスの高さのこの倍数未満なら結合します（--x_ths、既定 1.0）。",
+    lbl_y_ths: "y:",
+    hint_y_ths: "段落モードで、上下の間隔がボックスの高さのこの倍数未満なら結合します（--y_ths、既定 0.5）。",
+    lbl_margin: "バウンディングボックスの余白:",
+    lbl_json_output: "JSON 出力を使用",
+    lbl_detail: "位置と信頼度を含める",
+    hint_detail: "オフ = テキストのみ（--detail 0）: 高速ですがボックスや信頼度はありません。",
+    lbl_detect_only: "検出のみ（ボックスのみ、文字認識なし）",
+    hint_detect_only: "文字を認識せずにテキスト領域だけを検出するため高速です。EasyOCR の Python API を直接呼び出すので、追加引数は無視されます。",
+    lbl_sort_by_position: "位置で並べ替え",
+    hint_sort_by_position: "上から下、左から右の順に並べ替え、コピーしたテキストを読む順にします。",
+    lbl_vertical_text: "縦書き",
+    hint_vertical_text: "伝統的な日本語や中国語のような縦書きの文字向けです。各ボックスを 90° と 270° に回転して認識を試み、列を右から左、上から下の順に並べます。順序は近似です。",
+    hint_row_tolerance: "行の許容差: 上端の差が行の高さのこの割合未満のボックスは同じ行とみなします。",
+    lbl_preprocess_grayscale: "グレースケールに変換",
+    lbl_preprocess_contrast: "コントラストを伸長",
+    hint_preprocess_contrast: "明るさの範囲を黒から白いっぱいに広げます。薄いスキャンに有効です。",
+    lbl_preprocess_upscale: "2 倍に拡大",
+    hint_preprocess_upscale: "OCR 前に画像を拡大します。小さな文字に有効ですが時間がかかります。",
+    lbl_preprocess_downscale: "½ に縮小",
+    hint_preprocess_downscale: "OCR の前に画像を半分のサイズに縮小します。非常に大きな写真で大幅に速くなります。",
+    lbl_show_processed: "前処理後を表示",
+    hint_show_processed: "EasyOCR に送られる画像をプレビュー",
+    lbl_rotation_info: "回転角度:",
+    hint_rotation_info: "例: 90,180,270",
+    lbl_allowlist: "許可する文字:",
+    hint_allowlist_example: "例: 0123456789",
+    hint_allowlist: "これらの文字だけを認識します。スペースやカンマを含むすべての文字が対象です。空欄なら制限しません。",
+    lbl_blocklist: "除外する文字:",
+    hint_blocklist: "これらの文字は認識しません。許可する文字を指定した場合は無視されます。",
+    hint_json_output: "より確実に解析できます。--output_format 対応の EasyOCR が必要です。",
+    lbl_persistent_server: "実行間でモデルを読み込んだままにする",
+    hint_persistent_server: "モデルを一度だけ読み込むバックグラウンドの Python プロセスを使うため、繰り返しの実行が大幅に速くなります。追加の引数や検出のみの実行は引き続き CLI を使います。画像は 1 枚ずつ処理されます。",
+    lbl_quiet: "EasyOCR の出力を抑える",
+    hint_quiet: "--verbose False を渡します。EasyOCR の進行状況バーが表示されなくなるため、初回のモデルのダウンロード中は進捗率が表示されません。",
+    lbl_text_threshold: "テキスト信頼度:",
+    hint_text_threshold: "テキスト領域として採用する最低信頼度。",
+    lbl_low_text: "低テキストスコア:",
+    hint_low_text: "テキストスコアの下限。",
+    lbl_link_threshold: "連結しきい値:",
+    hint_link_threshold: "テキスト領域を連結するしきい値。",
+    lbl_contrast_ths: "コントラストしきい値:",
+    hint_contrast_ths: "このコントラスト未満のボックスは 2 回処理されます。",
+    lbl_adjust_contrast: "コントラスト調整先:",
+    hint_adjust_contrast: "低コントラストのボックスの目標値。",
+    hint_reset_default: "EasyOCR の既定値に戻す（{}）",
+    lbl_model_dir: "モデル保存ディレクトリ:",
+    hint_model_dir: "既定: ~/.EasyOCR/model",
+    lbl_model_mirror: "モデルのダウンロード元:",
+    hint_model_mirror_field: "既定: GitHub Releases",
+    hint_model_mirror: "GitHub が遅い・使えないネットワーク向けに、https://github.com/JaidedAI/EasyOCR/releases/download の代わりにこのアドレスからモデルをダウンロードします。ミラーは同じ <リリース>/<ファイル>.zip のパス構成である必要があります。",
+    lbl_model_usage: "モデルの場所: {} — ディスク使用量 {}。",
+    lbl_model_missing: "初回実行時にモデルが {} にダウンロードされます（言語モデルごとに約 100 MB）。",
+    btn_show_model_folder: "フォルダーを開く",
+    lbl_captures_dir: "キャプチャの保存先:",
+    hint_captures_dir: "空欄: 貼り付け・キャプチャした画像は保存しません",
+    lbl_capture_format: "キャプチャの形式:",
+    hint_capture_format: "大きなスクリーンショットは JPEG の方がずっと速く保存できます。PNG はすべてのピクセルをそのまま保ちます。",
+    lbl_jpeg_quality: "品質:",
+    lbl_easyocr_exe: "EasyOCR 実行ファイルのパス:",
+    hint_easyocr_exe: "既定: 'easyocr'（PATH から）",
+    lbl_extra_args: "追加の引数:",
+    hint_extra_args_example: "例: --width_ths 0.7",
+    hint_extra_args: "シェルと同じ規則で分割し、上記のオプションの後ろに付けて easyocr に渡します。上で設定済みのオプションを重複指定すると競合することがあり、通常は最後の値が優先されます。",
+    btn_browse: "参照…",
+    btn_save_settings: "💾  設定を保存",
+    btn_reset: "↺  既定値に戻す",
+    btn_copy_command: "コマンドをコピー",
+    hint_copy_command: "この設定で実行される easyocr コマンドをコピーし、ターミナルで再現できるようにします。",
+    msg_command_copied: "コマンドをクリップボードにコピーしました。",
+    msg_settings_reset: "設定を既定値に戻しました。",
+    btn_undo_reset: "元に戻す",
+    msg_settings_saved: "設定を保存しました。",
+    msg_settings_failed: "保存に失敗しました: {}",
+    btn_export_settings: "設定をエクスポート…",
+    btn_import_settings: "設定をインポート…",
+    msg_settings_exported: "設定を {} にエクスポートしました",
+    msg_settings_imported: "{} から設定をインポートしました。保存をクリックすると保持されます。",
+    msg_settings_import_failed: "インポートに失敗しました: {}",
+    lbl_ui_language: "表示言語:",
+    lbl_theme: "テーマ:",
+    theme_dark: "ダーク",
+    theme_light: "ライト",
+    theme_system: "システムに従う",
+    lbl_history_limit: "保存する履歴の件数:",
+    lbl_confidence_decimals: "信頼度の小数桁:",
+    lbl_script_tags: "行に文字体系を表示",
+    hint_script_tags: "各行が主にラテン文字、CJK、数字、その他の文字のどれで書かれているかを文字から推定して表示します。JSON エクスポートには常に含まれます。",
+    script_latin: "ラテン",
+    script_cjk: "CJK",
+    script_digits: "数字",
+    script_other: "その他",
+    btn_apply_preset: "☰ プリセットを適用",
+    btn_save_preset: "プリセットとして保存",
+    btn_delete: "削除",
+    hint_preset_name: "プリセット名",
+    btn_add_language: "➕ 言語を追加",
+    btn_add_shortcut: "➕ ショートカット",
+    hint_shortcut_name: "ラベル（例: JP）",
+    btn_save_shortcut: "現在の言語を保存",
+    hint_language_shortcut: "クリックで {} を使用、右クリックで削除",
+    lbl_auto_run: "自動実行",
+    lbl_error_details: "詳細",
+    err_gpu_oom: "GPU のメモリが不足しました。設定で GPU を無効にするか、バッチサイズを下げてください。",
+    err_gpu_unavailable: "使用可能な CUDA GPU が見つかりません。設定で GPU を無効にすると CPU で実行できます。",
+    err_model_download: "EasyOCR モデルのダウンロードに失敗しました。ネットワーク（またはプロキシ）を確認して再試行してください。",
+    err_unsupported_language: "EasyOCR が言語リストを受け付けませんでした。設定の言語コードを確認してください。",
+    err_missing_module: "EasyOCR に必要な Python パッケージがありません。再インストールしてください: pip install easyocr",
+    lbl_from_clipboard: "クリップボードから",
+    lbl_from_screen: "画面 {} から",
+    lbl_from_camera: "カメラから",
+    lbl_camera_starting: "カメラを起動しています…",
+    btn_capture_frame: "撮影",
+    btn_close_camera: "カメラを閉じる",
+    warn_unparsed_lines: "⚠ 出力はありましたが {} 行を解析できませんでした。",
+    lbl_raw_output: "解析できなかった出力を表示",
+    lbl_cli_output: "EasyOCR の出力",
+    hint_cli_output: "前回の実行で easyocr コマンドが出力した内容そのままです。結果の確認やバグ報告に使えます。",
+    hint_auto_run: "画像を開く・貼り付ける・ドロップする・キャプチャすると自動で OCR を実行します",
+    lbl_auto_copy: "自動コピー",
+    hint_auto_copy: "OCR が終わるとすぐに、コピーの横で選んだ形式で結果をクリップボードにコピーします。",
+    lbl_global_hotkey: "グローバルホットキー：",
+    hint_global_hotkey: "ウィンドウが背面にあっても使えます：画面の範囲を選び、OCR を実行してテキストをクリップボードにコピーします。",
+    msg_hotkey_failed: "ホットキーを使用できません: {}",
+    section_about: "バージョン情報",
+    lbl_version_gui: "EasyOCR GUI:",
+    lbl_version_python: "Python:",
+    lbl_version_easyocr: "EasyOCR:",
+    lbl_version_checking: "確認中…",
+    lbl_version_unknown: "見つかりません",
+    btn_copy_versions: "バージョンをコピー",
+    msg_versions_copied: "バージョンをクリップボードにコピーしました。",
+    status_hotkey_copied: "テキストをクリップボードにコピーしました。",
+    hint_search_language: "名前またはコードで検索",
+    lbl_advanced_codes: "詳細: コードを直接編集",
+    lang_unknown: "不明な言語コード: {} — en、ch_sim、ja などのコードを使用してください。",
+    lang_incompatible: "'{}' は '{}' と併用できません: EasyOCR はそのモデルを英語としか組み合わせられません。",
+
+    preset_default: "既定",
+    preset_dense_document: "文字の多い文書",
+    preset_scene_text: "風景中の文字",
+    preset_handwriting: "手書き風",
+
+    decoder_greedy: "Greedy（高速）",
+    decoder_beam: "Beam Search（高精度）",
+    decoder_word_beam: "Word Beam Search（最高精度）",
+};
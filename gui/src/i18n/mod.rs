@@ -0,0 +1,392 @@
+//! UI string tables, one file per locale.
+//!
+//! To add a locale, copy `en.rs`, translate every value, add a variant to
+//! `UiLanguage` and an arm to `get` below.
+
+use easyocr_core::settings::UiLanguage;
+
+mod en;
+mod es;
+mod ja;
+mod zh;
+
+pub use en::EN;
+pub use es::ES;
+pub use ja::JA;
+pub use zh::ZH;
+
+/// All UI strings for one language.
+pub struct Strings {
+    // Tab bar
+    pub tab_ocr: &'static str,
+    pub tab_history: &'static str,
+    pub tab_settings: &'static str,
+
+    // OCR tab — toolbar buttons
+    pub btn_open_image: &'static str,
+    pub btn_paste_image: &'static str,
+    pub btn_open_folder: &'static str,
+    pub hint_open_recent: &'static str,
+    pub btn_screenshot: &'static str,
+    pub btn_screenshot_region: &'static str,
+    pub btn_from_url: &'static str,
+    pub btn_camera: &'static str,
+    pub hint_region_select: &'static str,
+    pub lbl_screen: &'static str,
+    pub lbl_primary: &'static str,
+    pub btn_setup: &'static str,
+    pub btn_run_ocr: &'static str,
+    pub btn_copy: &'static str,
+    pub btn_copied: &'static str,
+    pub hint_copy_format: &'static str,
+    pub copy_text_only: &'static str,
+    pub copy_with_confidence: &'static str,
+    pub copy_tsv: &'static str,
+    pub btn_cancel: &'static str,
+    pub btn_export: &'static str,
+    pub export_txt: &'static str,
+    pub export_csv: &'static str,
+    pub export_json: &'static str,
+    pub export_hocr: &'static str,
+    pub export_layout: &'static str,
+    pub tooltip_setup: &'static str,
+
+    // OCR tab — content
+    pub lbl_results: &'static str,
+    pub lbl_plain_text: &'static str,
+    pub lbl_page: &'static str,
+    pub lbl_frame: &'static str,
+    pub lbl_min_confidence: &'static str,
+    pub lbl_confidence_filtered: &'static str,
+    pub lbl_result_stats: &'static str,
+    pub lbl_result_confidence: &'static str,
+    pub lbl_reading_time: &'static str,
+    pub lbl_compare: &'static str,
+    pub hint_compare: &'static str,
+    pub hint_compare_preset: &'static str,
+    pub lbl_compare_current: &'static str,
+    pub placeholder_drop_image: &'static str,
+    pub onboarding_title: &'static str,
+    pub onboarding_drop: &'static str,
+    pub onboarding_paste: &'static str,
+    pub onboarding_screenshot: &'static str,
+    pub onboarding_settings: &'static str,
+    pub btn_onboarding_dismiss: &'static str,
+
+    // Status / error message templates (use with format!("{}", ...) substituting {})
+    pub status_load_image: &'static str,
+    pub status_image_loaded: &'static str,
+    pub status_image_reloaded: &'static str,
+    pub status_opened_in_editor: &'static str,
+    pub status_cant_open_editor: &'static str,
+    pub url_title: &'static str,
+    pub btn_download: &'static str,
+    pub status_downloading: &'static str,
+    pub status_reading_pages: &'static str,
+    pub status_reading_documents: &'static str,
+    pub status_url_failed: &'static str,
+    pub path_title: &'static str,
+    pub btn_use_path: &'static str,
+    pub btn_type_path: &'static str,
+    pub hint_type_path: &'static str,
+    pub hint_reload_image: &'static str,
+    pub hint_open_in_editor: &'static str,
+    pub hint_select_region: &'static str,
+    pub lbl_region_lines: &'static str,
+    pub lbl_lines_left_out: &'static str,
+    pub hint_result_keys: &'static str,
+    pub hint_region_lines: &'static str,
+    pub hint_clear_selection: &'static str,
+    pub status_document_loaded: &'static str,
+    pub status_animation_loaded: &'static str,
+    pub status_running_ocr: &'static str,
+    pub status_ocr_complete: &'static str,
+    pub status_no_text_stderr: &'static str,
+    pub status_compare_complete: &'static str,
+    pub status_done_in: &'static str,
+    pub status_easyocr_missing: &'static str,
+    pub status_no_image_clipboard: &'static str,
+    pub status_clipboard_unavailable: &'static str,
+    pub status_clipboard_unchanged: &'static str,
+    pub status_large_image: &'static str,
+    pub btn_downscale: &'static str,
+    pub status_no_screens: &'static str,
+    pub status_screenshot_failed: &'static str,
+    pub status_camera_failed: &'static str,
+    pub status_cant_enum_screens: &'static str,
+    pub status_failed_load_image: &'static str,
+    pub status_cant_save_tmp: &'static str,
+    pub status_ocr_failed: &'static str,
+    pub status_cancelled: &'static str,
+    pub status_no_images_in_folder: &'static str,
+    pub status_no_images_dropped: &'static str,
+    pub status_busy: &'static str,
+    pub status_lines_so_far: &'static str,
+    pub status_batch_progress: &'static str,
+    pub status_batch_complete: &'static str,
+    pub lbl_queue: &'static str,
+    pub lbl_queue_lines: &'static str,
+    pub lbl_queue_failed: &'static str,
+    pub hint_queue_drag: &'static str,
+    pub hint_queue_remove: &'static str,
+    pub status_exported: &'static str,
+    pub status_export_failed: &'static str,
+    pub status_history_image_missing: &'static str,
+    pub status_history_save_failed: &'static str,
+    pub status_recent_missing: &'static str,
+    pub status_image_gone: &'static str,
+
+    // History tab
+    pub lbl_history_count: &'static str,
+    pub lbl_history_lines: &'static str,
+    pub btn_clear_history: &'static str,
+    pub placeholder_history: &'static str,
+
+    // Setup dialog
+    pub setup_title: &'static str,
+    pub setup_not_found: &'static str,
+    pub setup_module_missing: &'static str,
+    pub setup_must_install: &'static str,
+    pub setup_step1_title: &'static str,
+    pub setup_step1_desc: &'static str,
+    pub setup_step2_title: &'static str,
+    pub setup_step2_desc: &'static str,
+    pub setup_step2_desc_interpreter: &'static str,
+    pub setup_step3_title: &'static str,
+    pub setup_step3_desc: &'static str,
+    pub setup_tip: &'static str,
+    pub setup_btn_check: &'static str,
+    pub setup_btn_continue: &'static str,
+    pub setup_checking: &'static str,
+
+    // Settings — section headers
+    pub section_presets: &'static str,
+    pub section_languages: &'static str,
+    pub section_hardware: &'static str,
+    pub section_decoder: &'static str,
+    pub section_recognition: &'static str,
+    pub section_thresholds: &'static str,
+    pub section_preprocessing: &'static str,
+    pub section_paths: &'static str,
+    pub section_ui: &'static str,
+
+    // Settings — labels & hints
+    pub lbl_language_codes: &'static str,
+    pub hint_language_codes: &'static str,
+    pub lbl_comma_separated: &'static str,
+    pub lbl_gpu: &'static str,
+    pub hint_cuda_detected: &'static str,
+    pub hint_cuda_missing: &'static str,
+    pub hint_cuda_checking: &'static str,
+    pub lbl_gpu_device: &'static str,
+    pub hint_gpu_device_placeholder: &'static str,
+    pub hint_gpu_device: &'static str,
+    pub lbl_workers: &'static str,
+    pub hint_workers_auto: &'static str,
+    pub lbl_batch_concurrency: &'static str,
+    pub hint_batch_concurrency: &'static str,
+    pub lbl_batch_serialized: &'static str,
+    pub lbl_workers_suffix: &'static str,
+    pub lbl_timeout: &'static str,
+    pub hint_timeout: &'static str,
+    pub lbl_seconds_suffix: &'static str,
+    pub lbl_quantize: &'static str,
+    pub lbl_beam_width: &'static str,
+    pub lbl_batch_size: &'static str,
+    pub lbl_min_size: &'static str,
+    pub lbl_canvas_size: &'static str,
+    pub hint_canvas_size: &'static str,
+    pub lbl_mag_ratio: &'static str,
+    pub hint_mag_ratio: &'static str,
+    pub lbl_paragraph: &'static str,
+    pub lbl_join_paragraph_lines: &'static str,
+    pub hint_join_paragraph_lines: &'static str,
+    pub lbl_x_ths: &'static str,
+    pub hint_x_ths: &'static str,
+    pub lbl_y_ths: &'static str,
+    pub hint_y_ths: &'static str,
+    pub lbl_margin: &'static str,
+    pub lbl_json_output: &'static str,
+    pub lbl_detail: &'static str,
+    pub hint_detail: &'static str,
+    pub lbl_detect_only: &'static str,
+    pub hint_detect_only: &'static str,
+    pub lbl_sort_by_position: &'static str,
+    pub hint_sort_by_position: &'static str,
+    pub lbl_vertical_text: &'static str,
+    pub hint_vertical_text: &'static str,
+    pub hint_row_tolerance: &'static str,
+    pub lbl_preprocess_grayscale: &'static str,
+    pub lbl_preprocess_contrast: &'static str,
+    pub hint_preprocess_contrast: &'static str,
+    pub lbl_preprocess_upscale: &'static str,
+    pub hint_preprocess_upscale: &'static str,
+    pub lbl_preprocess_downscale: &'static str,
+    pub hint_preprocess_downscale: &'static str,
+    pub lbl_show_processed: &'static str,
+    pub hint_show_processed: &'static str,
+    pub lbl_rotation_info: &'static str,
+    pub hint_rotation_info: &'static str,
+    pub lbl_allowlist: &'static str,
+    pub hint_allowlist_example: &'static str,
+    pub hint_allowlist: &'static str,
+    pub lbl_blocklist: &'static str,
+    pub hint_blocklist: &'static str,
+    pub hint_json_output: &'static str,
+    pub lbl_persistent_server: &'static str,
+    pub hint_persistent_server: &'static str,
+    pub lbl_quiet: &'static str,
+    pub hint_quiet: &'static str,
+    pub lbl_text_threshold: &'static str,
+    pub hint_text_threshold: &'static str,
+    pub lbl_low_text: &'static str,
+    pub hint_low_text: &'static str,
+    pub lbl_link_threshold: &'static str,
+    pub hint_link_threshold: &'static str,
+    pub lbl_contrast_ths: &'static str,
+    pub hint_contrast_ths: &'static str,
+    pub lbl_adjust_contrast: &'static str,
+    pub hint_adjust_contrast: &'static str,
+    pub hint_reset_default: &'static str,
+    pub lbl_model_dir: &'static str,
+    pub hint_model_dir: &'static str,
+    pub lbl_model_mirror: &'static str,
+    pub hint_model_mirror_field: &'static str,
+    pub hint_model_mirror: &'static str,
+    pub lbl_model_usage: &'static str,
+    pub lbl_model_missing: &'static str,
+    pub btn_show_model_folder: &'static str,
+    pub lbl_captures_dir: &'static str,
+    pub hint_captures_dir: &'static str,
+    pub lbl_capture_format: &'static str,
+    pub hint_capture_format: &'static str,
+    pub lbl_jpeg_quality: &'static str,
+    pub lbl_easyocr_exe: &'static str,
+    pub hint_easyocr_exe: &'static str,
+    pub lbl_extra_args: &'static str,
+    pub hint_extra_args_example: &'static str,
+    pub hint_extra_args: &'static str,
+    pub btn_browse: &'static str,
+    pub btn_save_settings: &'static str,
+    pub btn_reset: &'static str,
+    pub btn_copy_command: &'static str,
+    pub hint_copy_command: &'static str,
+    pub msg_command_copied: &'static str,
+    pub msg_settings_reset: &'static str,
+    pub btn_undo_reset: &'static str,
+    pub msg_settings_saved: &'static str,
+    pub msg_settings_failed: &'static str,
+    pub btn_export_settings: &'static str,
+    pub btn_import_settings: &'static str,
+    pub msg_settings_exported: &'static str,
+    pub msg_settings_imported: &'static str,
+    pub msg_settings_import_failed: &'static str,
+    pub lbl_ui_language: &'static str,
+    pub lbl_theme: &'static str,
+    pub theme_dark: &'static str,
+    pub theme_light: &'static str,
+    pub theme_system: &'static str,
+    pub lbl_history_limit: &'static str,
+    pub lbl_confidence_decimals: &'static str,
+    pub lbl_script_tags: &'static str,
+    pub hint_script_tags: &'static str,
+    pub script_latin: &'static str,
+    pub script_cjk: &'static str,
+    pub script_digits: &'static str,
+    pub script_other: &'static str,
+    pub btn_apply_preset: &'static str,
+    pub btn_save_preset: &'static str,
+    pub btn_delete: &'static str,
+    pub hint_preset_name: &'static str,
+    pub btn_add_language: &'static str,
+    pub btn_add_shortcut: &'static str,
+    pub hint_shortcut_name: &'static str,
+    pub btn_save_shortcut: &'static str,
+    pub hint_language_shortcut: &'static str,
+    pub lbl_auto_run: &'static str,
+    pub lbl_error_details: &'static str,
+    pub err_gpu_oom: &'static str,
+    pub err_gpu_unavailable: &'static str,
+    pub err_model_download: &'static str,
+    pub err_unsupported_language: &'static str,
+    pub err_missing_module: &'static str,
+    pub lbl_from_clipboard: &'static str,
+    pub lbl_from_screen: &'static str,
+    pub lbl_from_camera: &'static str,
+    pub lbl_camera_starting: &'static str,
+    pub btn_capture_frame: &'static str,
+    pub btn_close_camera: &'static str,
+    pub warn_unparsed_lines: &'static str,
+    pub lbl_raw_output: &'static str,
+    pub lbl_cli_output: &'static str,
+    pub hint_cli_output: &'static str,
+    pub hint_auto_run: &'static str,
+    pub lbl_auto_copy: &'static str,
+    pub hint_auto_copy: &'static str,
+    pub lbl_global_hotkey: &'static str,
+    pub hint_global_hotkey: &'static str,
+    pub msg_hotkey_failed: &'static str,
+    pub section_about: &'static str,
+    pub lbl_version_gui: &'static str,
+    pub lbl_version_python: &'static str,
+    pub lbl_version_easyocr: &'static str,
+    pub lbl_version_checking: &'static str,
+    pub lbl_version_unknown: &'static str,
+    pub btn_copy_versions: &'static str,
+    pub msg_versions_copied: &'static str,
+    pub status_hotkey_copied: &'static str,
+    pub hint_search_language: &'static str,
+    pub lbl_advanced_codes: &'static str,
+    pub lang_unknown: &'static str,
+    pub lang_incompatible: &'static str,
+
+    // Built-in preset names
+    pub preset_default: &'static str,
+    pub preset_dense_document: &'static str,
+    pub preset_scene_text: &'static str,
+    pub preset_handwriting: &'static str,
+
+    // Decoder option labels
+    pub decoder_greedy: &'static str,
+    pub decoder_beam: &'static str,
+    pub decoder_word_beam: &'static str,
+}
+
+pub fn get(lang: &UiLanguage) -> &'static Strings {
+    match lang {
+        UiLanguage::Chinese => &ZH,
+        UiLanguage::English => &EN,
+        UiLanguage::Japanese => &JA,
+        UiLanguage::Spanish => &ES,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    /// `key -> number of {} placeholders` for every string in a table source.
+    fn placeholders(table: &str) -> HashMap<&str, usize> {
+        table
+            .lines()
+            .filter_map(|l| l.trim().split_once(": \""))
+            .map(|(key, value)| (key, value.matches("{}").count()))
+            .collect()
+    }
+
+    /// Templates are filled with `replacen("{}", ..)`, so a translation that
+    /// drops or adds a placeholder silently garbles the message.
+    #[test]
+    fn translations_keep_placeholders() {
+        let en = placeholders(include_str!("en.rs"));
+        for (name, table) in [
+            ("zh", include_str!("zh.rs")),
+            ("ja", include_str!("ja.rs")),
+            ("es", include_str!("es.rs")),
+        ] {
+            for (key, count) in placeholders(table) {
+                assert_eq!(en.get(key), Some(&count), "{name}: {key}");
+            }
+        }
+    }
+}
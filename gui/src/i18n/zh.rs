@@ -0,0 +1,327 @@
+use super::Strings;
+
+pub static ZH: Strings = Strings {
+    tab_ocr: "🔍  文字识别",
+    tab_history: "🕘  历史",
+    tab_settings: "⚙  设置",
+
+    btn_open_image: "📂 打开图片",
+    btn_paste_image: "📋 粘贴图片",
+    btn_open_folder: "🗁 打开文件夹",
+    hint_open_recent: "打开最近的文件",
+    btn_screenshot: "📷 截图",
+    btn_screenshot_region: "✂ 区域截图",
+    btn_from_url: "🌐 从网址",
+    btn_camera: "📷 摄像头",
+    hint_region_select: "拖动鼠标选择要识别的区域 — 按 Esc 取消",
+    lbl_screen: "屏幕",
+    lbl_primary: "主屏",
+    btn_setup: "⚠ 安装向导",
+    btn_run_ocr: "▶  运行识别",
+    btn_copy: "⎘ 复制",
+    btn_copied: "✔ 已复制!",
+    hint_copy_format: "复制格式",
+    copy_text_only: "仅复制文本",
+    copy_with_confidence: "复制文本和置信度",
+    copy_tsv: "复制为 TSV（文本 ⇥ 置信度）",
+    btn_cancel: "✖ 取消",
+    btn_export: "💾 导出",
+    export_txt: "纯文本 (.txt)",
+    export_csv: "CSV (.csv)",
+    export_json: "JSON (.json)",
+    export_hocr: "hOCR (.hocr)",
+    export_layout: "保留位置的文本 (.txt)",
+    tooltip_setup: "未找到 EasyOCR，点击查看安装说明",
+
+    lbl_results: "识别结果",
+    lbl_plain_text: "纯文本",
+    lbl_page: "第 {}/{} 页",
+    lbl_frame: "第 {}/{} 帧",
+    lbl_min_confidence: "最低置信度：",
+    lbl_confidence_filtered: "显示 {} / {} 条（{} 条低于阈值）",
+    lbl_result_stats: "{} 行 · {} 个词 · {} 个字符",
+    lbl_result_confidence: " · 平均 {} · 最低 {}",
+    lbl_reading_time: " · 约 {} 分钟读完",
+    lbl_compare: "对比",
+    hint_compare: "分别用当前设置和应用预设后的设置识别图片，并排显示两份结果",
+    hint_compare_preset: "第二列使用的设置：在当前设置上应用此预设",
+    lbl_compare_current: "当前设置",
+    placeholder_drop_image: "将图片拖放到此处\n或使用上方按钮",
+    onboarding_title: "快速上手",
+    onboarding_drop: "将图片或 PDF 拖放到此处，或点击“打开图片”",
+    onboarding_paste: "使用 {} 从剪贴板粘贴图片",
+    onboarding_screenshot: "使用“截图”截取整个屏幕或选定区域",
+    onboarding_settings: "在“设置”页选择语言并调整识别参数",
+    btn_onboarding_dismiss: "知道了",
+
+    status_load_image: "请加载图片以开始文字识别。",
+    status_image_loaded: "{}已加载，请点击「运行识别」。",
+    status_image_reloaded: "已从磁盘重新加载 {}。",
+    status_opened_in_editor: "已在默认图片应用中打开 {}。在那里保存后，再在这里重新加载。",
+    status_cant_open_editor: "无法在其他应用中打开图片：{}",
+    url_title: "从网址打开图片",
+    btn_download: "下载",
+    status_downloading: "正在下载 {}…",
+    status_reading_pages: "正在读取 {} 的页面…",
+    status_reading_documents: "正在读取文档的页面…",
+    status_url_failed: "无法从网址加载图片：{}",
+    path_title: "输入路径",
+    btn_use_path: "确定",
+    btn_type_path: "改为输入路径…",
+    hint_type_path: "用于系统文件对话框无法打开的情况，例如缺少桌面门户时。",
+    hint_reload_image: "从磁盘重新加载图片",
+    hint_open_in_editor: "在默认图片编辑器中打开图片",
+    hint_select_region: "在图片上按住 Shift 拖动可选择区域；右键单击清除。",
+    lbl_region_lines: "选区内 {} 行（共 {} 行）",
+    lbl_lines_left_out: "已有 {} 行不参与复制和导出",
+    hint_result_keys: "↑/↓ 选择一行，Enter 复制该行，空格键将其排除在复制和导出之外或重新加入。",
+    hint_region_lines: "复制和导出只使用选区内的行。",
+    hint_clear_selection: "清除选区",
+    status_document_loaded: "已加载 {}，共 {} 页。点击「运行 OCR」识别全部页面。",
+    status_animation_loaded: "已加载 {}，共 {} 帧。选择一帧后点击「运行 OCR」。",
+    status_running_ocr: "正在识别…",
+    status_ocr_complete: "识别完成 — 检测到 {} 个文字区域。",
+    status_no_text_stderr: "未检测到文本。EasyOCR 输出：{}",
+    status_compare_complete: "对比完成 — {} 对 {} 个文本区域。",
+    status_done_in: "用时 {} 秒。",
+    status_easyocr_missing: "⚠ 未找到 EasyOCR — 请点击安装向导按钮查看说明。",
+    status_no_image_clipboard: "剪贴板中未找到图片，请先复制一张图片。",
+    status_clipboard_unavailable: "剪贴板不可用：{}",
+    status_clipboard_unchanged: "剪贴板中仍是当前显示的图片。",
+    status_large_image: "图像较大（{} MP）：CPU 上的 OCR 可能需要几分钟。",
+    btn_downscale: "缩小",
+    status_no_screens: "未找到屏幕。",
+    status_screenshot_failed: "截图失败：{}",
+    status_camera_failed: "摄像头不可用：{}",
+    status_cant_enum_screens: "无法枚举屏幕：{}",
+    status_failed_load_image: "加载图片失败：{}",
+    status_cant_save_tmp: "无法保存临时图片：{}",
+    status_ocr_failed: "识别失败：{}",
+    status_cancelled: "已取消。",
+    status_no_images_in_folder: "所选文件夹中没有图片。",
+    status_no_images_dropped: "拖入的文件中没有支持的图片格式。",
+    status_busy: "OCR 正在运行——请取消或等待完成。",
+    status_lines_so_far: "——已识别 {} 行",
+    status_batch_progress: "批量识别 {}/{}…",
+    status_batch_complete: "批量识别完成 — 共 {} 张图片，{} 张失败。",
+    lbl_queue: "队列 — 已完成 {}/{}",
+    lbl_queue_lines: "{} 行",
+    lbl_queue_failed: "失败",
+    hint_queue_drag: "拖动以调整图片的处理顺序。",
+    hint_queue_remove: "从队列中移除",
+    status_exported: "已导出到 {}",
+    status_export_failed: "导出失败：{}",
+    status_history_image_missing: "图片已不存在：{} — 仅显示保存的结果。",
+    status_history_save_failed: "无法保存历史记录：{}",
+    status_recent_missing: "文件已被移动或删除：{}",
+    status_image_gone: "图片文件已不存在：{}，请重新加载。",
+
+    lbl_history_count: "共 {} 条记录",
+    lbl_history_lines: "{} 行",
+    btn_clear_history: "🗑 清空历史",
+    placeholder_history: "暂无识别记录。",
+
+    setup_title: "⚙  EasyOCR 安装向导",
+    setup_not_found: "在您的系统上未找到 easyocr 命令。",
+    setup_module_missing: "找到了 Python（'{}'），但它没有安装 easyocr 模块。",
+    setup_must_install: "请先安装 EasyOCR，应用程序才能进行文字识别。",
+    setup_step1_title: "第一步 — 安装 Python 3.8 或更高版本",
+    setup_step1_desc: "请从以下网址下载并安装 Python：",
+    setup_step2_title: "第二步 — 安装 EasyOCR",
+    setup_step2_desc: "打开终端并运行：",
+    setup_step2_desc_interpreter: "请为该解释器安装，运行：",
+    setup_step3_title: "第三步 — 语言模型",
+    setup_step3_desc: "首次对某语言进行 OCR 时会自动下载模型。\n初次下载可能需要几分钟，具体取决于您的网络速度。",
+    setup_tip: "提示：您也可以在「设置」选项卡中指定自定义的 EasyOCR 可执行文件路径。",
+    setup_btn_check: "✓  重新检测",
+    setup_btn_continue: "忽略并继续",
+    setup_checking: "检测中…",
+
+    section_presets: "预设",
+    section_languages: "识别语言",
+    section_hardware: "硬件",
+    section_decoder: "解码器",
+    section_recognition: "识别参数",
+    section_thresholds: "检测阈值",
+    section_preprocessing: "预处理",
+    section_paths: "路径（可选）",
+    section_ui: "界面",
+
+    lbl_language_codes: "语言代码：",
+    hint_language_codes: "例：en,ch_sim,fr",
+    lbl_comma_separated: "（逗号分隔）",
+    lbl_gpu: "启用 GPU 加速",
+    hint_cuda_detected: "已检测到 CUDA",
+    hint_cuda_missing: "未检测到 CUDA — GPU 不可用",
+    hint_cuda_checking: "正在检测 CUDA…",
+    lbl_gpu_device: "GPU 设备：",
+    hint_gpu_device_placeholder: "默认",
+    hint_gpu_device: "CUDA 设备编号，例如 1 或 cuda:1。通过 CUDA_VISIBLE_DEVICES 传递；留空则使用默认显卡。",
+    lbl_workers: "并行 CPU 工作线程：",
+    hint_workers_auto: "（0 = 自动：每个 CPU 核心一个，本机为 {}）",
+    lbl_batch_concurrency: "并行图片数：",
+    hint_batch_concurrency: "批量识别时同时处理的图片数。每张图片都运行独立的 EasyOCR 进程并加载自己的模型副本（通常占用 1 GB 以上内存），请谨慎调高。",
+    lbl_batch_serialized: "保持模型加载时无效：唯一的后台进程逐张处理图像。",
+    lbl_workers_suffix: " 个线程",
+    lbl_timeout: "识别超时：",
+    hint_timeout: "（0 = 不限时）",
+    lbl_seconds_suffix: " 秒",
+    lbl_quantize: "使用动态量化（减少内存占用）",
+    lbl_beam_width: "束宽：",
+    lbl_batch_size: "批量大小：",
+    lbl_min_size: "最小文字框大小（像素）：",
+    lbl_canvas_size: "画布大小（像素）：",
+    hint_canvas_size: "最长边超过此值的图片会在检测前缩小。",
+    lbl_mag_ratio: "放大倍数：",
+    hint_mag_ratio: "检测前放大图片，有助于识别小字。",
+    lbl_paragraph: "将结果合并为段落",
+    lbl_join_paragraph_lines: "合并段落内的换行",
+    hint_join_paragraph_lines: "复制时，段落内的换行变为空格，段落之间以空行分隔。",
+    lbl_x_ths: "合并间距  x：",
+    hint_x_ths: "段落模式下，水平间距小于该倍数文本框高度的框会被合并（--x_ths，默认 1.0）。",
+    lbl_y_ths: "y：",
+    hint_y_ths: "段落模式下，垂直间距小于该倍数文本框高度的框会被合并（--y_ths，默认 0.5）。",
+    lbl_margin: "边界框边距：",
+    lbl_json_output: "使用 JSON 输出",
+    lbl_detail: "包含位置和置信度",
+    hint_detail: "关闭 = 仅文本（--detail 0）：更快，但没有文本框和置信度。",
+    lbl_detect_only: "仅检测（只有文本框，不识别文字）",
+    hint_detect_only: "只查找文本区域而不识别文字，速度更快。直接调用 EasyOCR 的 Python API，因此会忽略额外参数。",
+    lbl_sort_by_position: "按位置排序",
+    hint_sort_by_position: "按从上到下、从左到右的顺序重新排列，使复制的文字按阅读顺序排列。",
+    lbl_vertical_text: "竖排文字",
+    hint_vertical_text: "用于竖排书写的文字，如传统日文或中文。会将每个文本框旋转 90° 和 270° 重试，并按从右到左、从上到下的顺序排列各列。顺序为近似结果。",
+    hint_row_tolerance: "行容差：顶边相差小于该比例行高的文字框视为同一行。",
+    lbl_preprocess_grayscale: "转为灰度",
+    lbl_preprocess_contrast: "拉伸对比度",
+    hint_preprocess_contrast: "将亮度范围拉伸到纯黑至纯白，有助于识别浅淡的扫描件。",
+    lbl_preprocess_upscale: "放大 2 倍",
+    hint_preprocess_upscale: "识别前放大图片，有助于识别小字，但耗时更长。",
+    lbl_preprocess_downscale: "缩小一半",
+    hint_preprocess_downscale: "OCR 前将图像缩小为一半；对超大照片快得多。",
+    lbl_show_processed: "显示预处理结果",
+    hint_show_processed: "预览发送给 EasyOCR 的图片",
+    lbl_rotation_info: "旋转角度：",
+    hint_rotation_info: "例：90,180,270",
+    lbl_allowlist: "允许的字符：",
+    hint_allowlist_example: "例如 0123456789",
+    hint_allowlist: "只识别这些字符——每个字符都有效，包括空格和逗号。留空表示不限制。",
+    lbl_blocklist: "禁止的字符：",
+    hint_blocklist: "永远不会识别这些字符。设置了允许的字符时此项无效。",
+    hint_json_output: "解析更可靠；需要支持 --output_format 的 EasyOCR 版本。",
+    lbl_persistent_server: "在两次运行之间保持模型加载",
+    hint_persistent_server: "运行一个只加载一次模型的后台 Python 进程，重复运行会快得多。额外参数和仅检测运行仍使用命令行。图像逐张处理。",
+    lbl_quiet: "精简 EasyOCR 输出",
+    hint_quiet: "传递 --verbose False。隐藏 EasyOCR 打印的进度条，首次运行下载模型时将不显示百分比。",
+    lbl_text_threshold: "文字置信度：",
+    hint_text_threshold: "接受文字区域的最低置信度。",
+    lbl_low_text: "低文字分数：",
+    hint_low_text: "文字分数的下限。",
+    lbl_link_threshold: "链接阈值：",
+    hint_link_threshold: "链接文字区域的阈值。",
+    lbl_contrast_ths: "对比度阈值：",
+    hint_contrast_ths: "低于此对比度的框将被处理两次。",
+    lbl_adjust_contrast: "调整对比度至：",
+    hint_adjust_contrast: "低对比度框的目标对比度。",
+    hint_reset_default: "恢复为 EasyOCR 默认值（{}）",
+    lbl_model_dir: "模型存储目录：",
+    hint_model_dir: "默认：~/.EasyOCR/model",
+    lbl_model_mirror: "模型下载镜像：",
+    hint_model_mirror_field: "默认：GitHub Releases",
+    hint_model_mirror: "从此地址下载模型，代替 https://github.com/JaidedAI/EasyOCR/releases/download，适用于访问 GitHub 缓慢或受限的网络。镜像须保持相同的 <版本>/<文件>.zip 路径。",
+    lbl_model_usage: "模型位于 {}，占用 {}。",
+    lbl_model_missing: "首次运行时模型将下载到 {}（每个语言模型约 100 MB）。",
+    btn_show_model_folder: "打开文件夹",
+    lbl_captures_dir: "截图保存目录：",
+    hint_captures_dir: "留空：不保留粘贴或截取的图片",
+    lbl_capture_format: "截图格式：",
+    hint_capture_format: "大尺寸截图保存为 JPEG 要快得多；PNG 保留每个像素的原样。",
+    lbl_jpeg_quality: "质量：",
+    lbl_easyocr_exe: "EasyOCR 可执行文件路径：",
+    hint_easyocr_exe: "默认：'easyocr'（从 PATH 中查找）",
+    lbl_extra_args: "额外参数：",
+    hint_extra_args_example: "例如 --width_ths 0.7",
+    hint_extra_args: "按 shell 规则拆分后追加在上述选项之后传给 easyocr。重复上面已设置的选项可能产生冲突，通常以最后一个值为准。",
+    btn_browse: "浏览…",
+    btn_save_settings: "💾  保存设置",
+    btn_reset: "↺  恢复默认值",
+    btn_copy_command: "复制命令",
+    hint_copy_command: "复制当前设置对应的 easyocr 命令行，便于在终端中复现。",
+    msg_command_copied: "命令已复制到剪贴板。",
+    msg_settings_reset: "设置已恢复为默认值。",
+    btn_undo_reset: "撤销",
+    msg_settings_saved: "设置保存成功。",
+    msg_settings_failed: "保存失败：{}",
+    btn_export_settings: "导出设置…",
+    btn_import_settings: "导入设置…",
+    msg_settings_exported: "设置已导出到 {}",
+    msg_settings_imported: "已从 {} 导入设置。点击\"保存\"以保留。",
+    msg_settings_import_failed: "导入失败：{}",
+    lbl_ui_language: "界面语言：",
+    lbl_theme: "主题：",
+    theme_dark: "深色",
+    theme_light: "浅色",
+    theme_system: "跟随系统",
+    lbl_history_limit: "保留历史记录条数：",
+    lbl_confidence_decimals: "置信度小数位：",
+    lbl_script_tags: "为每行标注文字类型",
+    hint_script_tags: "根据字符推测每行结果主要是拉丁字母、中日韩文字、数字还是其他文字。JSON 导出始终包含该标注。",
+    script_latin: "拉丁",
+    script_cjk: "中日韩",
+    script_digits: "数字",
+    script_other: "其他",
+    btn_apply_preset: "☰ 应用预设",
+    btn_save_preset: "保存为预设",
+    btn_delete: "删除",
+    hint_preset_name: "预设名称",
+    btn_add_language: "➕ 添加语言",
+    btn_add_shortcut: "➕ 快捷方式",
+    hint_shortcut_name: "标签，例如 中英",
+    btn_save_shortcut: "保存当前语言",
+    hint_language_shortcut: "点击使用 {}；右键删除",
+    lbl_auto_run: "自动识别",
+    lbl_error_details: "详细信息",
+    err_gpu_oom: "GPU 显存不足。请在设置中关闭 GPU 或减小批大小。",
+    err_gpu_unavailable: "未找到可用的 CUDA GPU。请在设置中关闭 GPU 以使用 CPU 运行。",
+    err_model_download: "EasyOCR 模型下载失败。请检查网络连接（或代理）后重试。",
+    err_unsupported_language: "EasyOCR 不接受当前的语言列表。请检查设置中的语言代码。",
+    err_missing_module: "缺少 EasyOCR 所需的 Python 包。请重新安装：pip install easyocr",
+    lbl_from_clipboard: "来自剪贴板",
+    lbl_from_screen: "来自屏幕 {}",
+    lbl_from_camera: "来自摄像头",
+    lbl_camera_starting: "正在启动摄像头…",
+    btn_capture_frame: "拍摄",
+    btn_close_camera: "关闭摄像头",
+    warn_unparsed_lines: "⚠ 收到了输出，但有 {} 行无法解析。",
+    lbl_raw_output: "显示未解析的输出",
+    lbl_cli_output: "EasyOCR 输出",
+    hint_cli_output: "上次运行时 easyocr 命令的原始输出，可用于核对结果或附在问题报告中。",
+    hint_auto_run: "打开、粘贴、拖入或截取图片后立即开始识别",
+    lbl_auto_copy: "自动复制",
+    hint_auto_copy: "识别完成后立即按\"复制\"旁选定的格式将结果复制到剪贴板。",
+    lbl_global_hotkey: "全局快捷键：",
+    hint_global_hotkey: "窗口在后台时也可使用：选择屏幕区域、运行 OCR 并将文本复制到剪贴板。",
+    msg_hotkey_failed: "快捷键不可用：{}",
+    section_about: "关于",
+    lbl_version_gui: "EasyOCR GUI：",
+    lbl_version_python: "Python：",
+    lbl_version_easyocr: "EasyOCR：",
+    lbl_version_checking: "检查中…",
+    lbl_version_unknown: "未找到",
+    btn_copy_versions: "复制版本信息",
+    msg_versions_copied: "版本信息已复制到剪贴板。",
+    status_hotkey_copied: "文本已复制到剪贴板。",
+    hint_search_language: "按名称或代码搜索",
+    lbl_advanced_codes: "高级：直接编辑语言代码",
+    lang_unknown: "未知的语言代码：{}（请使用 en、ch_sim、ja 等代码）",
+    lang_incompatible: "'{}' 不能与 '{}' 同时使用：EasyOCR 的该模型只能与英语搭配。",
+
+    preset_default: "默认",
+    preset_dense_document: "密集文档",
+    preset_scene_text: "场景文字",
+    preset_handwriting: "类手写",
+
+    decoder_greedy: "贪婪（快速）",
+    decoder_beam: "束搜索（准确）",
+    decoder_word_beam: "词束搜索（最准确）",
+};
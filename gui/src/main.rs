@@ -1,15 +1,33 @@
 mod app;
+mod camera;
+mod cli;
+mod download;
+mod export;
+mod history;
+mod hotkey;
 mod i18n;
-mod ocr;
-mod settings;
+mod pages;
+mod theme;
 
 fn main() -> eframe::Result<()> {
+    if let Some(code) = cli::run_from_args() {
+        std::process::exit(code);
+    }
+    let mut viewport = egui::ViewportBuilder::default()
+        .with_title("EasyOCR")
+        .with_inner_size([1000.0, 700.0])
+        .with_min_inner_size([700.0, 500.0])
+        .with_drag_and_drop(true);
+    if let Some(window) = easyocr_core::Settings::load().window {
+        viewport = viewport.with_inner_size(window.size).with_maximized(window.maximized);
+        if let Some(position) = window.position {
+            viewport = viewport.with_position(position);
+        }
+    }
     let options = eframe::NativeOptions {
-        viewport: egui::ViewportBuilder::default()
-            .with_title("EasyOCR")
-            .with_inner_size([1000.0, 700.0])
-            .with_min_inner_size([700.0, 500.0])
-            .with_drag_and_drop(true),
+        viewport,
+        // The geometry lives in settings.json instead, next to everything else.
+        persist_window: false,
         ..Default::default()
     };
 
@@ -17,16 +35,35 @@ fn main() -> eframe::Result<()> {
         "EasyOCR",
         options,
         Box::new(|cc| {
-            setup_cjk_font(&cc.egui_ctx);
+            match setup_cjk_font(&cc.egui_ctx) {
+                FontSource::System(path) => eprintln!("CJK font: {path}"),
+                FontSource::Bundled => eprintln!("CJK font: bundled Noto Sans SC"),
+                FontSource::Missing => eprintln!("CJK font: none found, CJK text will not render"),
+            }
             Ok(Box::new(app::EasyOcrApp::new(cc)))
         }),
     )
 }
 
+/// Where the CJK fallback font was loaded from.
+enum FontSource {
+    System(&'static str),
+    Bundled,
+    Missing,
+}
+
+// Set by build.rs when the feature is on and the font file is present.
+#[cfg(bundled_cjk_font)]
+const BUNDLED_CJK_FONT: Option<&[u8]> =
+    Some(include_bytes!("../assets/fonts/NotoSansSC-Regular.otf"));
+#[cfg(not(bundled_cjk_font))]
+const BUNDLED_CJK_FONT: Option<&[u8]> = None;
+
 /// Attempt to load a CJK-capable system font so that Chinese UI text renders
-/// correctly.  If no suitable font is found the app still works — only CJK
-/// glyphs will be shown as replacement boxes.
-fn setup_cjk_font(ctx: &egui::Context) {
+/// correctly, falling back to the bundled font when it was embedded (the
+/// default `bundled-cjk-font` feature).  If no suitable font is found the app still
+/// works — only CJK glyphs will be shown as replacement boxes.
+fn setup_cjk_font(ctx: &egui::Context) -> FontSource {
     // Candidate paths ordered by preference (Linux, macOS, Windows).
     let candidates: &[&str] = &[
         // Linux — Noto CJK
@@ -50,26 +87,35 @@ fn setup_cjk_font(ctx: &egui::Context) {
 
     for path in candidates {
         if let Ok(font_bytes) = std::fs::read(path) {
-            let mut fonts = egui::FontDefinitions::default();
-            fonts.font_data.insert(
-                "cjk_font".to_owned(),
-                egui::FontData::from_owned(font_bytes),
-            );
-            // Add after the default proportional font so Latin glyphs keep
-            // their original rendering, but CJK characters fall through to
-            // this font.
-            fonts
-                .families
-                .entry(egui::FontFamily::Proportional)
-                .or_default()
-                .push("cjk_font".to_owned());
-            fonts
-                .families
-                .entry(egui::FontFamily::Monospace)
-                .or_default()
-                .push("cjk_font".to_owned());
-            ctx.set_fonts(fonts);
-            return;
+            install_cjk_font(ctx, egui::FontData::from_owned(font_bytes));
+            return FontSource::System(path);
         }
     }
+
+    match BUNDLED_CJK_FONT {
+        Some(font_bytes) => {
+            install_cjk_font(ctx, egui::FontData::from_static(font_bytes));
+            FontSource::Bundled
+        }
+        None => FontSource::Missing,
+    }
+}
+
+fn install_cjk_font(ctx: &egui::Context, font: egui::FontData) {
+    let mut fonts = egui::FontDefinitions::default();
+    fonts.font_data.insert("cjk_font".to_owned(), font);
+    // Add after the default proportional font so Latin glyphs keep
+    // their original rendering, but CJK characters fall through to
+    // this font.
+    fonts
+        .families
+        .entry(egui::FontFamily::Proportional)
+        .or_default()
+        .push("cjk_font".to_owned());
+    fonts
+        .families
+        .entry(egui::FontFamily::Monospace)
+        .or_default()
+        .push("cjk_font".to_owned());
+    ctx.set_fonts(fonts);
 }
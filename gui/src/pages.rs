@@ -0,0 +1,341 @@
+use image::{AnimationDecoder, DynamicImage, ImageBuffer};
+use std::fs::File;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use tiff::decoder::{Decoder, DecodingResult};
+use tiff::ColorType;
+
+/// Resolution PDF pages are rendered at; 200 dpi keeps body text well above
+/// EasyOCR's default minimum box size without producing huge images.
+const PDF_DPI: u32 = 200;
+
+/// Animations are split into at most this many frames; a longer one is
+/// cut short rather than filling memory and the temp dir.
+const MAX_FRAMES: usize = 300;
+
+/// Documents the open dialog accepts on top of the plain image formats.
+pub const DOCUMENT_EXTENSIONS: &[&str] = &["pdf"];
+
+/// What the pages of a split file are.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PageKind {
+    /// Pages of a document, all recognised by one run.
+    Document,
+    /// Frames of an animated GIF or WebP; only the one on show is
+    /// recognised.
+    Frame,
+}
+
+/// The pages of a split file, as `split_pages` returns them.
+pub type Split = Result<Option<(PageKind, Vec<PathBuf>)>, String>;
+
+/// Whether `split_pages` may find more than one page in `path`, judging by
+/// its extension alone.
+pub fn may_split(path: &Path) -> bool {
+    matches!(extension(path).as_str(), "pdf" | "tif" | "tiff" | "gif" | "webp")
+}
+
+/// Whether `path` is a document that may have several pages to recognise.
+pub fn is_document(path: &Path) -> bool {
+    matches!(extension(path).as_str(), "pdf" | "tif" | "tiff")
+}
+
+/// Runs `split_pages` on a background thread, since rendering a long
+/// document takes a while.  Pages nobody is waiting for any more are
+/// removed again.
+pub fn split_pages_async(path: PathBuf) -> mpsc::Receiver<Split> {
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        if let Err(mpsc::SendError(Ok(Some((_, pages))))) = tx.send(split_pages(&path)) {
+            remove_pages(&pages);
+        }
+    });
+    rx
+}
+
+/// Replaces each document in `paths` by its pages on a background thread,
+/// for queueing them all as one batch.  A document that can't be split is
+/// kept as it is, so its run reports why.
+pub fn expand_documents_async(paths: Vec<PathBuf>) -> mpsc::Receiver<Vec<PathBuf>> {
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        let mut expanded = Vec::new();
+        for path in paths {
+            match is_document(&path).then(|| split_pages(&path)) {
+                Some(Ok(Some((_, pages)))) => expanded.extend(pages),
+                _ => expanded.push(path),
+            }
+        }
+        let _ = tx.send(expanded);
+    });
+    rx
+}
+
+/// Splits a multi-page TIFF, a PDF or an animation into one PNG per page,
+/// written to a fresh temp directory.  Returns `Ok(None)` for anything that
+/// is a single image, which callers open as usual.
+pub fn split_pages(path: &Path) -> Split {
+    let ext = extension(path);
+    match ext.as_str() {
+        "pdf" => render_pdf(path, &pages_dir()?).map(|pages| Some((PageKind::Document, pages))),
+        "tif" | "tiff" => {
+            let mut decoder = open_tiff(path)?;
+            if !decoder.more_images() {
+                return Ok(None);
+            }
+            split_tiff(&mut decoder, &pages_dir()?).map(|pages| Some((PageKind::Document, pages)))
+        }
+        "gif" | "webp" => {
+            let Some(frames) = decode_frames(path, &ext)? else {
+                return Ok(None);
+            };
+            let dir = pages_dir()?;
+            match save_frames(frames, &dir) {
+                Ok(pages) if pages.len() > 1 => Ok(Some((PageKind::Frame, pages))),
+                other => {
+                    let _ = std::fs::remove_dir_all(&dir);
+                    other.map(|_| None)
+                }
+            }
+        }
+        _ => Ok(None),
+    }
+}
+
+fn extension(path: &Path) -> String {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_ascii_lowercase())
+        .unwrap_or_default()
+}
+
+/// The frames of an animated GIF or WebP, decoded one at a time as they
+/// are read and composited to the full canvas as they would be shown.
+/// `None` for a still WebP; a still GIF yields one frame.
+fn decode_frames(path: &Path, ext: &str) -> Result<Option<image::Frames<'static>>, String> {
+    let reader = BufReader::new(File::open(path).map_err(|e| e.to_string())?);
+    Ok(Some(if ext == "gif" {
+        image::codecs::gif::GifDecoder::new(reader).map_err(|e| e.to_string())?.into_frames()
+    } else {
+        let decoder = image::codecs::webp::WebPDecoder::new(reader).map_err(|e| e.to_string())?;
+        if !decoder.has_animation() {
+            return Ok(None);
+        }
+        decoder.into_frames()
+    }))
+}
+
+/// Writes up to `MAX_FRAMES` frames to `dir` as they are decoded, so only
+/// one is held in memory at a time.
+fn save_frames(frames: image::Frames, dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let mut pages = Vec::new();
+    for frame in frames.take(MAX_FRAMES) {
+        let page = dir.join(format!("frame-{:04}.png", pages.len() + 1));
+        let frame = frame.map_err(|e| e.to_string())?;
+        frame.into_buffer().save(&page).map_err(|e| e.to_string())?;
+        pages.push(page);
+    }
+    Ok(pages)
+}
+
+/// Removes a directory created by `split_pages`, given any of its pages.
+pub fn remove_pages(pages: &[PathBuf]) {
+    if let Some(dir) = pages.first().and_then(|p| p.parent()) {
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
+
+/// Start of the names of the temp directories pages are split into this
+/// session; they are all removed on exit.
+pub fn temp_prefix() -> String {
+    format!("easyocr_gui_pages_{}_", std::process::id())
+}
+
+fn pages_dir() -> Result<PathBuf, String> {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let dir = std::env::temp_dir()
+        .join(format!("{}{}", temp_prefix(), COUNTER.fetch_add(1, Ordering::Relaxed)));
+    std::fs::create_dir_all(&dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+    Ok(dir)
+}
+
+fn open_tiff(path: &Path) -> Result<Decoder<BufReader<File>>, String> {
+    let file = File::open(path).map_err(|e| e.to_string())?;
+    Decoder::new(BufReader::new(file)).map_err(|e| e.to_string())
+}
+
+fn split_tiff(decoder: &mut Decoder<BufReader<File>>, dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let mut pages = Vec::new();
+    loop {
+        let page = dir.join(format!("page-{:03}.png", pages.len() + 1));
+        tiff_page(decoder)?.save(&page).map_err(|e| e.to_string())?;
+        pages.push(page);
+        if !decoder.more_images() {
+            return Ok(pages);
+        }
+        decoder.next_image().map_err(|e| e.to_string())?;
+    }
+}
+
+/// Decodes the decoder's current page.  Covers the 8- and 16-bit gray and
+/// RGB layouts scanners produce; anything else is reported by name.
+fn tiff_page(decoder: &mut Decoder<BufReader<File>>) -> Result<DynamicImage, String> {
+    let (w, h) = decoder.dimensions().map_err(|e| e.to_string())?;
+    let color = decoder.colortype().map_err(|e| e.to_string())?;
+    let data = decoder.read_image().map_err(|e| e.to_string())?;
+    let bad_size = || format!("TIFF page data does not match its {}×{} size", w, h);
+    Ok(match (color, data) {
+        (ColorType::Gray(8), DecodingResult::U8(buf)) => {
+            DynamicImage::ImageLuma8(ImageBuffer::from_raw(w, h, buf).ok_or_else(bad_size)?)
+        }
+        (ColorType::GrayA(8), DecodingResult::U8(buf)) => {
+            DynamicImage::ImageLumaA8(ImageBuffer::from_raw(w, h, buf).ok_or_else(bad_size)?)
+        }
+        (ColorType::RGB(8), DecodingResult::U8(buf)) => {
+            DynamicImage::ImageRgb8(ImageBuffer::from_raw(w, h, buf).ok_or_else(bad_size)?)
+        }
+        (ColorType::RGBA(8), DecodingResult::U8(buf)) => {
+            DynamicImage::ImageRgba8(ImageBuffer::from_raw(w, h, buf).ok_or_else(bad_size)?)
+        }
+        (ColorType::Gray(16), DecodingResult::U16(buf)) => {
+            DynamicImage::ImageLuma16(ImageBuffer::from_raw(w, h, buf).ok_or_else(bad_size)?)
+        }
+        (ColorType::RGB(16), DecodingResult::U16(buf)) => {
+            DynamicImage::ImageRgb16(ImageBuffer::from_raw(w, h, buf).ok_or_else(bad_size)?)
+        }
+        (ColorType::RGBA(16), DecodingResult::U16(buf)) => {
+            DynamicImage::ImageRgba16(ImageBuffer::from_raw(w, h, buf).ok_or_else(bad_size)?)
+        }
+        (other, _) => return Err(format!("Unsupported TIFF page format: {:?}", other)),
+    })
+}
+
+/// Renders every page with poppler's `pdftoppm`, which is far lighter to
+/// depend on than linking a PDF engine into the GUI.
+fn render_pdf(path: &Path, dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let output = Command::new("pdftoppm")
+        .arg("-r")
+        .arg(PDF_DPI.to_string())
+        .arg("-png")
+        .arg(path)
+        .arg(dir.join("page"))
+        .output()
+        .map_err(|e| {
+            format!(
+                "PDF pages are rendered with pdftoppm, which could not be started ({}).\n\n\
+                 Install poppler, e.g.:\n  apt install poppler-utils\n  brew install poppler",
+                e
+            )
+        })?;
+    if !output.status.success() {
+        return Err(format!(
+            "pdftoppm failed:\n{}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    // pdftoppm zero-pads page numbers to the width of the page count, so a
+    // plain name sort keeps them in order.
+    let mut pages: Vec<PathBuf> = std::fs::read_dir(dir)
+        .map_err(|e| e.to_string())?
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|p| p.extension().is_some_and(|e| e == "png"))
+        .collect();
+    pages.sort();
+    if pages.is_empty() {
+        return Err("pdftoppm produced no pages".into());
+    }
+    Ok(pages)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{expand_documents_async, remove_pages, split_pages, PageKind, MAX_FRAMES};
+    use image::codecs::gif::GifEncoder;
+    use image::{Frame, RgbaImage};
+    use tiff::encoder::{colortype, TiffEncoder};
+
+    #[test]
+    fn splits_multi_page_tiff() {
+        let path = std::env::temp_dir().join(format!("pages_test_{}.tiff", std::process::id()));
+        {
+            let mut file = std::fs::File::create(&path).unwrap();
+            let mut encoder = TiffEncoder::new(&mut file).unwrap();
+            encoder.write_image::<colortype::Gray8>(4, 3, &[0u8; 12]).unwrap();
+            encoder.write_image::<colortype::RGB8>(2, 5, &[255u8; 30]).unwrap();
+        }
+        let (kind, pages) = split_pages(&path).unwrap().expect("two pages");
+        assert_eq!(kind, PageKind::Document);
+        assert_eq!(pages.len(), 2);
+        assert_eq!(image::image_dimensions(&pages[0]).unwrap(), (4, 3));
+        assert_eq!(image::image_dimensions(&pages[1]).unwrap(), (2, 5));
+        remove_pages(&pages);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn expands_documents_among_images() {
+        let path = std::env::temp_dir().join(format!("pages_expand_{}.tif", std::process::id()));
+        {
+            let mut file = std::fs::File::create(&path).unwrap();
+            let mut encoder = TiffEncoder::new(&mut file).unwrap();
+            encoder.write_image::<colortype::Gray8>(2, 2, &[0u8; 4]).unwrap();
+            encoder.write_image::<colortype::Gray8>(2, 2, &[9u8; 4]).unwrap();
+        }
+        let photo = std::path::PathBuf::from("photo.png");
+        let expanded = expand_documents_async(vec![photo.clone(), path.clone()]).recv().unwrap();
+        assert_eq!(expanded.len(), 3);
+        assert_eq!(expanded[0], photo);
+        assert!(expanded[1..].iter().all(|p| p.extension().is_some_and(|e| e == "png")));
+        remove_pages(&expanded[1..]);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn splits_animated_gif_into_frames() {
+        let path = std::env::temp_dir().join(format!("pages_test_{}.gif", std::process::id()));
+        let write_gif = |frames: &[[u8; 4]]| {
+            let mut encoder = GifEncoder::new(std::fs::File::create(&path).unwrap());
+            for &pixel in frames {
+                let frame = Frame::new(RgbaImage::from_pixel(3, 2, image::Rgba(pixel)));
+                encoder.encode_frame(frame).unwrap();
+            }
+        };
+        write_gif(&[[255, 0, 0, 255], [0, 0, 255, 255]]);
+        let (kind, frames) = split_pages(&path).unwrap().expect("two frames");
+        assert_eq!(kind, PageKind::Frame);
+        assert_eq!(frames.len(), 2);
+        let second = image::open(&frames[1]).unwrap().to_rgba8();
+        assert_eq!((second.width(), second.height()), (3, 2));
+        assert!(second.get_pixel(0, 0)[2] > 200);
+        remove_pages(&frames);
+
+        write_gif(&[[0, 255, 0, 255]]);
+        assert_eq!(split_pages(&path), Ok(None));
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn long_animations_are_cut_short() {
+        let path = std::env::temp_dir().join(format!("pages_long_{}.gif", std::process::id()));
+        {
+            let mut encoder = GifEncoder::new(std::fs::File::create(&path).unwrap());
+            for i in 0..MAX_FRAMES + 5 {
+                let pixel = image::Rgba([(i % 256) as u8, 0, 0, 255]);
+                encoder.encode_frame(Frame::new(RgbaImage::from_pixel(1, 1, pixel))).unwrap();
+            }
+        }
+        let (_, frames) = split_pages(&path).unwrap().expect("frames");
+        assert_eq!(frames.len(), MAX_FRAMES);
+        remove_pages(&frames);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn single_images_are_left_alone() {
+        assert_eq!(split_pages(std::path::Path::new("photo.png")), Ok(None));
+    }
+}
@@ -0,0 +1,67 @@
+use easyocr_core::settings::Theme;
+use egui::{Color32, ThemePreference};
+
+/// Colours the app paints on top of egui's own visuals.  Each theme gets its
+/// own set so accent text stays readable on both dark and light panels.
+pub struct Palette {
+    /// Links, the title and other highlighted text.
+    pub accent: Color32,
+    /// Background of primary buttons (Run OCR, the active tab).
+    pub primary_fill: Color32,
+    /// Background of the Setup button shown when EasyOCR is missing.
+    pub warning_fill: Color32,
+    /// Text drawn on `primary_fill` / `warning_fill`.
+    pub on_fill: Color32,
+    pub success: Color32,
+    pub warning: Color32,
+    pub error: Color32,
+    /// Hints and other secondary text.
+    pub muted: Color32,
+    /// Status bar text and inactive tabs.
+    pub subtle: Color32,
+}
+
+const DARK: Palette = Palette {
+    accent: Color32::from_rgb(96, 165, 250),
+    primary_fill: Color32::from_rgb(37, 99, 235),
+    warning_fill: Color32::from_rgb(202, 138, 4),
+    on_fill: Color32::WHITE,
+    success: Color32::from_rgb(74, 222, 128),
+    warning: Color32::from_rgb(251, 191, 36),
+    error: Color32::from_rgb(248, 113, 113),
+    muted: Color32::GRAY,
+    subtle: Color32::LIGHT_GRAY,
+};
+
+const LIGHT: Palette = Palette {
+    accent: Color32::from_rgb(29, 78, 216),
+    primary_fill: Color32::from_rgb(37, 99, 235),
+    warning_fill: Color32::from_rgb(202, 138, 4),
+    on_fill: Color32::WHITE,
+    success: Color32::from_rgb(21, 128, 61),
+    warning: Color32::from_rgb(180, 83, 9),
+    error: Color32::from_rgb(220, 38, 38),
+    muted: Color32::from_gray(110),
+    subtle: Color32::from_gray(70),
+};
+
+impl Palette {
+    /// The palette matching the visuals currently in use.
+    pub fn of(ctx: &egui::Context) -> &'static Palette {
+        if ctx.style().visuals.dark_mode {
+            &DARK
+        } else {
+            &LIGHT
+        }
+    }
+}
+
+/// Switches egui's visuals to the chosen theme.  `System` follows the OS
+/// setting where eframe can detect it.
+pub fn apply(ctx: &egui::Context, theme: Theme) {
+    ctx.set_theme(match theme {
+        Theme::Dark => ThemePreference::Dark,
+        Theme::Light => ThemePreference::Light,
+        Theme::System => ThemePreference::System,
+    });
+}